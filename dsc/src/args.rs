@@ -3,7 +3,7 @@
 
 use clap::{Parser, Subcommand, ValueEnum};
 use clap_complete::Shell;
-use dsc_lib::dscresources::command_resource::TraceLevel;
+use dsc_lib::dscresources::command_resource::{TraceFormat, TraceLevel};
 
 #[derive(Debug, Clone, PartialEq, Eq, ValueEnum)]
 pub enum OutputFormat {
@@ -12,15 +12,6 @@ pub enum OutputFormat {
     Yaml,
 }
 
-#[derive(Debug, Clone, PartialEq, Eq, ValueEnum)]
-pub enum TraceFormat {
-    Default,
-    Plaintext,
-    Json,
-    #[clap(hide = true)]
-    PassThrough,
-}
-
 #[derive(Debug, Parser)]
 #[clap(name = "dsc", version = env!("CARGO_PKG_VERSION"), about = "Apply configuration or invoke specific DSC resources", long_about = None)]
 pub struct Args {
@@ -29,8 +20,8 @@ pub struct Args {
     pub subcommand: SubCommand,
     #[clap(short = 'l', long, help = "Trace level to use", value_enum)]
     pub trace_level: Option<TraceLevel>,
-    #[clap(short = 'f', long, help = "Trace format to use", value_enum, default_value = "default")]
-    pub trace_format: TraceFormat,
+    #[clap(short = 'f', long, help = "Trace format to use", value_enum)]
+    pub trace_format: Option<TraceFormat>,
 }
 
 #[derive(Debug, PartialEq, Eq, Subcommand)]
@@ -44,10 +35,12 @@ pub enum SubCommand {
     Config {
         #[clap(subcommand)]
         subcommand: ConfigSubCommand,
-        #[clap(short, long, help = "Parameters to pass to the configuration as JSON or YAML", conflicts_with = "parameters_file")]
+        #[clap(short, long, help = "Parameters to pass to the configuration as JSON or YAML, the parameters files override them")]
         parameters: Option<String>,
-        #[clap(short = 'f', long, help = "Parameters to pass to the configuration as a JSON or YAML file", conflicts_with = "parameters")]
-        parameters_file: Option<String>,
+        #[clap(short = 'f', long, help = "Parameters to pass to the configuration as a JSON or YAML file, later files override earlier ones")]
+        parameters_file: Option<Vec<String>>,
+        #[clap(long, help = "A parameter value as `name=value` that overrides the parameters and `DSC_PARAM_<name>` environment variables")]
+        parameter: Option<Vec<String>>,
         // Used to inform when DSC is used as a group resource to modify it's output
         #[clap(long, hide = true)]
         as_group: bool,
@@ -55,6 +48,21 @@ pub enum SubCommand {
         #[clap(long, hide = true)]
         as_include: bool,
     },
+    #[clap(name = "expression", about = "Evaluate DSC expressions")]
+    Expression {
+        #[clap(subcommand)]
+        subcommand: ExpressionSubCommand,
+    },
+    #[clap(name = "function", about = "Operations on DSC expression functions")]
+    Function {
+        #[clap(subcommand)]
+        subcommand: FunctionSubCommand,
+    },
+    #[clap(name = "parameters", about = "Encrypt or decrypt values in parameter files")]
+    Parameters {
+        #[clap(subcommand)]
+        subcommand: ParametersSubCommand,
+    },
     #[clap(name = "resource", about = "Invoke a specific DSC resource")]
     Resource {
         #[clap(subcommand)]
@@ -62,8 +70,10 @@ pub enum SubCommand {
     },
     #[clap(name = "schema", about = "Get the JSON schema for a DSC type")]
     Schema {
-        #[clap(name = "type", short, long, help = "The type of DSC schema to get")]
-        dsc_type: DscType,
+        #[clap(name = "type", short, long, help = "The type of DSC schema to get", required_unless_present = "parameters_for")]
+        dsc_type: Option<DscType>,
+        #[clap(long, help = "The path to a configuration document to get the JSON schema of its parameters file", conflicts_with = "type")]
+        parameters_for: Option<String>,
         #[clap(short = 'f', long, help = "The output format to use")]
         format: Option<OutputFormat>,
     },
@@ -131,6 +141,79 @@ pub enum ConfigSubCommand {
     }
 }
 
+#[derive(Debug, PartialEq, Eq, Subcommand)]
+pub enum ExpressionSubCommand {
+    #[clap(name = "eval", about = "Evaluate an expression")]
+    Eval {
+        /// The expression to evaluate, read from STDIN if not specified
+        expression: Option<String>,
+        #[clap(short = 'd', long, help = "The configuration document that provides parameters, variables and user functions", conflicts_with = "path")]
+        document: Option<String>,
+        #[clap(short = 'p', long, help = "The path to the configuration document that provides parameters, variables and user functions", conflicts_with = "document")]
+        path: Option<String>,
+        #[clap(long, help = "Parameters to use as JSON or YAML in the same form as for `dsc config`")]
+        parameters: Option<String>,
+        #[clap(long, help = "Variables to use as a JSON or YAML object")]
+        variables: Option<String>,
+        #[clap(long, help = "Outputs returned by `reference()` as a JSON or YAML object keyed by resource id")]
+        references: Option<String>,
+        #[clap(short, long, help = "Evaluate expressions entered interactively until 'exit'", conflicts_with = "expression")]
+        interactive: bool,
+        #[clap(short = 'f', long, help = "The output format to use")]
+        format: Option<OutputFormat>,
+    },
+}
+
+#[derive(Debug, PartialEq, Eq, Subcommand)]
+pub enum FunctionSubCommand {
+    #[clap(name = "list", about = "List or find functions")]
+    List {
+        /// Optional filter to apply to the list of functions
+        function_name: Option<String>,
+        #[clap(short = 'f', long, help = "The output format to use")]
+        format: Option<OutputFormat>,
+    },
+}
+
+#[derive(Debug, PartialEq, Eq, Subcommand)]
+pub enum ParametersSubCommand {
+    #[clap(name = "encrypt", about = "Encrypt the values of parameters")]
+    Encrypt {
+        #[clap(short = 'd', long, help = "The parameters as JSON or YAML", conflicts_with = "path")]
+        document: Option<String>,
+        #[clap(short = 'p', long, help = "The path to the parameters file", conflicts_with = "document")]
+        path: Option<String>,
+        #[clap(short = 'r', long, help = "The public key to encrypt for, by default the public key of the key file")]
+        recipient: Option<String>,
+        #[clap(short = 'n', long, help = "The name of a parameter to encrypt, all parameters are encrypted if not specified")]
+        name: Option<Vec<String>>,
+        #[clap(short = 'f', long, help = "The output format to use")]
+        format: Option<OutputFormat>,
+    },
+    #[clap(name = "decrypt", about = "Decrypt the values of parameters with the key file")]
+    Decrypt {
+        #[clap(short = 'd', long, help = "The parameters as JSON or YAML", conflicts_with = "path")]
+        document: Option<String>,
+        #[clap(short = 'p', long, help = "The path to the parameters file", conflicts_with = "document")]
+        path: Option<String>,
+        #[clap(short = 'f', long, help = "The output format to use")]
+        format: Option<OutputFormat>,
+    },
+    #[clap(name = "new-key", about = "Create a key file and return its public key")]
+    NewKey {
+        #[clap(short = 'p', long, help = "The path of the key file, by default the path in `DSC_KEY_FILE` or `key.txt` in the user directory")]
+        path: Option<String>,
+    },
+}
+
+#[derive(Debug, PartialEq, Eq, Subcommand)]
+pub enum CacheSubCommand {
+    #[clap(name = "clear", about = "Delete the cached results of resource discovery")]
+    Clear,
+    #[clap(name = "rebuild", about = "Search for all resources again and cache the results")]
+    Rebuild,
+}
+
 #[derive(Debug, PartialEq, Eq, Subcommand)]
 pub enum ResourceSubCommand {
     #[clap(name = "list", about = "List or find resources")]
@@ -205,6 +288,11 @@ pub enum ResourceSubCommand {
         #[clap(short = 'f', long, help = "The output format to use")]
         format: Option<OutputFormat>,
     },
+    #[clap(name = "cache", about = "Manage the cache of discovered resources")]
+    Cache {
+        #[clap(subcommand)]
+        subcommand: CacheSubCommand,
+    },
 }
 
 #[derive(Debug, Clone, Copy, PartialEq, Eq, ValueEnum)]
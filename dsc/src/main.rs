@@ -4,6 +4,7 @@
 use args::{Args, SubCommand};
 use clap::{CommandFactory, Parser};
 use clap_complete::generate;
+use dsc_lib::settings::DscSettings;
 use std::io::{self, IsTerminal, Read};
 use std::process::exit;
 use sysinfo::{Process, RefreshKind, System, get_current_pid, ProcessRefreshKind};
@@ -34,7 +35,18 @@ fn main() {
 
     let args = Args::parse();
 
-    util::enable_tracing(&args.trace_level, &args.trace_format);
+    // the settings are loaded before tracing is enabled as they can set the trace level and format
+    // the settings are loaded once and passed to the subcommands that find resources
+    let settings = DscSettings::load();
+    let tracing_settings = settings.as_ref().map(|settings| settings.tracing.clone()).unwrap_or_default();
+    util::enable_tracing(&args.trace_level, &args.trace_format, &tracing_settings);
+    let settings = match settings {
+        Ok(settings) => settings,
+        Err(err) => {
+            error!("Error: {err}");
+            exit(util::EXIT_DSC_ERROR);
+        }
+    };
 
     debug!("Running dsc {}", env!("CARGO_PKG_VERSION"));
 
@@ -68,27 +80,31 @@ fn main() {
             let mut cmd = Args::command();
             generate(shell, &mut cmd, "dsc", &mut io::stdout());
         },
-        SubCommand::Config { subcommand, parameters, parameters_file, as_group, as_include } => {
-            if let Some(file_name) = parameters_file {
-                info!("Reading parameters from file {file_name}");
-                match std::fs::read_to_string(&file_name) {
-                    Ok(parameters) => subcommand::config(&subcommand, &Some(parameters), &input, &as_group, &as_include),
-                    Err(err) => {
-                        error!("Error: Failed to read parameters file '{file_name}': {err}");
-                        exit(util::EXIT_INVALID_INPUT);
-                    }
-                }
-            }
-            else {
-                subcommand::config(&subcommand, &parameters, &input, &as_group, &as_include);
-            }
+        SubCommand::Config { subcommand, parameters, parameters_file, parameter, as_group, as_include } => {
+            subcommand::config(&subcommand, &parameters, &parameters_file, &parameter, &input, &as_group, &as_include, &settings);
+        },
+        SubCommand::Expression { subcommand } => {
+            subcommand::expression(&subcommand, &input, &settings);
+        },
+        SubCommand::Function { subcommand } => {
+            subcommand::function(&subcommand, &settings);
+        },
+        SubCommand::Parameters { subcommand } => {
+            subcommand::parameters(&subcommand, &input);
         },
         SubCommand::Resource { subcommand } => {
-            subcommand::resource(&subcommand, &input);
+            subcommand::resource(&subcommand, &input, &settings);
         },
-        SubCommand::Schema { dsc_type , format } => {
-            let schema = util::get_schema(dsc_type);
-            let json = match serde_json::to_string(&schema) {
+        SubCommand::Schema { dsc_type , parameters_for, format } => {
+            let json = match (dsc_type, parameters_for) {
+                (_, Some(path)) => serde_json::to_string(&subcommand::parameters_schema(&path)),
+                (Some(dsc_type), None) => serde_json::to_string(&util::get_schema(dsc_type)),
+                (None, None) => {
+                    error!("Error: Either --type or --parameters-for must be specified");
+                    exit(util::EXIT_INVALID_ARGS);
+                }
+            };
+            let json = match json {
                 Ok(json) => json,
                 Err(err) => {
                     error!("JSON Error: {err}");
@@ -2,6 +2,7 @@
 // Licensed under the MIT License.
 
 use dsc_lib::configure::config_doc::Configuration;
+use dsc_lib::configure::context::DSC_CONFIG_ROOT;
 use dsc_lib::util::parse_input_to_json;
 use schemars::JsonSchema;
 use serde::{Deserialize, Serialize};
@@ -10,8 +11,6 @@ use std::fs::File;
 use std::path::{Path, PathBuf};
 use tracing::{debug, info};
 
-use crate::util::DSC_CONFIG_ROOT;
-
 #[derive(Debug, Clone, PartialEq, Deserialize, Serialize, JsonSchema)]
 pub struct Include {
     /// The path to the file to include.  Path is relative to the file containing the include
@@ -109,6 +108,8 @@ pub fn get_contents(input: &str) -> Result<(Option<String>, String), String> {
                         return Err(format!("Failed to parse parameters file '{parameters_file:?}' to JSON: {err}"));
                     }
                 };
+                // encrypted values are left as is so the resolved configuration doesn't contain them in plaintext,
+                // they are decrypted when the parameters are set on the configurator
                 Some(parameters_json)
             },
             Err(err) => {
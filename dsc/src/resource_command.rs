@@ -16,9 +16,9 @@ use dsc_lib::{
 use std::process::exit;
 
 pub fn get(dsc: &DscManager, resource_type: &str, mut input: String, format: &Option<OutputFormat>) {
-    let Some(mut resource) = get_resource(dsc, resource_type) else {
-        error!("{}", DscError::ResourceNotFound(resource_type.to_string()).to_string());
-        exit(EXIT_DSC_RESOURCE_NOT_FOUND);
+    let mut resource = match get_resource(dsc, resource_type) {
+        Ok(resource) => resource,
+        Err(err) => report_resource_error(&err),
     };
 
     debug!("resource.type_name - {} implemented_as - {:?}", resource.type_name, resource.implemented_as);
@@ -29,11 +29,13 @@ pub fn get(dsc: &DscManager, resource_type: &str, mut input: String, format: &Op
 
     if let Some(requires) = &resource.require_adapter {
         input = add_type_name_to_json(input, resource.type_name.clone());
-        if let Some(pr) = get_resource(dsc, requires) {
-            resource = pr;
-        } else {
-            error!("Adapter '{}' not found", requires);
-            return;
+        match get_resource(dsc, requires) {
+            Ok(pr) => resource = pr,
+            Err(DscError::ResourceNotFound(_)) => {
+                error!("Adapter '{}' not found", requires);
+                return;
+            },
+            Err(err) => report_resource_error(&err),
         };
     }
 
@@ -58,9 +60,9 @@ pub fn get(dsc: &DscManager, resource_type: &str, mut input: String, format: &Op
 
 pub fn get_all(dsc: &DscManager, resource_type: &str, format: &Option<OutputFormat>) {
     let mut input = String::new();
-    let Some(mut resource) = get_resource(dsc, resource_type) else {
-        error!("{}", DscError::ResourceNotFound(resource_type.to_string()).to_string());
-        exit(EXIT_DSC_RESOURCE_NOT_FOUND);
+    let mut resource = match get_resource(dsc, resource_type) {
+        Ok(resource) => resource,
+        Err(err) => report_resource_error(&err),
     };
 
     debug!("resource.type_name - {} implemented_as - {:?}", resource.type_name, resource.implemented_as);
@@ -71,11 +73,13 @@ pub fn get_all(dsc: &DscManager, resource_type: &str, format: &Option<OutputForm
 
     if let Some(requires) = &resource.require_adapter {
         input = add_type_name_to_json(input, resource.type_name.clone());
-        if let Some(pr) = get_resource(dsc, requires) {
-            resource = pr;
-        } else {
-            error!("Adapter '{}' not found", requires);
-            return;
+        match get_resource(dsc, requires) {
+            Ok(pr) => resource = pr,
+            Err(DscError::ResourceNotFound(_)) => {
+                error!("Adapter '{}' not found", requires);
+                return;
+            },
+            Err(err) => report_resource_error(&err),
         };
     }
 
@@ -110,9 +114,9 @@ pub fn set(dsc: &DscManager, resource_type: &str, mut input: String, format: &Op
         exit(EXIT_INVALID_ARGS);
     }
 
-    let Some(mut resource) = get_resource(dsc, resource_type) else {
-        error!("{}", DscError::ResourceNotFound(resource_type.to_string()).to_string());
-        exit(EXIT_DSC_RESOURCE_NOT_FOUND);
+    let mut resource = match get_resource(dsc, resource_type) {
+        Ok(resource) => resource,
+        Err(err) => report_resource_error(&err),
     };
 
     debug!("resource.type_name - {} implemented_as - {:?}", resource.type_name, resource.implemented_as);
@@ -123,11 +127,13 @@ pub fn set(dsc: &DscManager, resource_type: &str, mut input: String, format: &Op
 
     if let Some(requires) = &resource.require_adapter {
         input = add_type_name_to_json(input, resource.type_name.clone());
-        if let Some(pr) = get_resource(dsc, requires) {
-            resource = pr;
-        } else {
-            error!("Adapter '{}' not found", requires);
-            return;
+        match get_resource(dsc, requires) {
+            Ok(pr) => resource = pr,
+            Err(DscError::ResourceNotFound(_)) => {
+                error!("Adapter '{}' not found", requires);
+                return;
+            },
+            Err(err) => report_resource_error(&err),
         };
     }
 
@@ -156,9 +162,9 @@ pub fn test(dsc: &DscManager, resource_type: &str, mut input: String, format: &O
         exit(EXIT_INVALID_ARGS);
     }
 
-    let Some(mut resource) = get_resource(dsc, resource_type) else {
-        error!("{}", DscError::ResourceNotFound(resource_type.to_string()).to_string());
-        exit(EXIT_DSC_RESOURCE_NOT_FOUND);
+    let mut resource = match get_resource(dsc, resource_type) {
+        Ok(resource) => resource,
+        Err(err) => report_resource_error(&err),
     };
 
     debug!("resource.type_name - {} implemented_as - {:?}", resource.type_name, resource.implemented_as);
@@ -169,11 +175,13 @@ pub fn test(dsc: &DscManager, resource_type: &str, mut input: String, format: &O
 
     if let Some(requires) = &resource.require_adapter {
         input = add_type_name_to_json(input, resource.type_name.clone());
-        if let Some(pr) = get_resource(dsc, requires) {
-            resource = pr;
-        } else {
-            error!("Adapter '{}' not found", requires);
-            return;
+        match get_resource(dsc, requires) {
+            Ok(pr) => resource = pr,
+            Err(DscError::ResourceNotFound(_)) => {
+                error!("Adapter '{}' not found", requires);
+                return;
+            },
+            Err(err) => report_resource_error(&err),
         };
     }
 
@@ -197,9 +205,9 @@ pub fn test(dsc: &DscManager, resource_type: &str, mut input: String, format: &O
 }
 
 pub fn delete(dsc: &DscManager, resource_type: &str, mut input: String) {
-    let Some(mut resource) = get_resource(dsc, resource_type) else {
-        error!("{}", DscError::ResourceNotFound(resource_type.to_string()).to_string());
-        exit(EXIT_DSC_RESOURCE_NOT_FOUND);
+    let mut resource = match get_resource(dsc, resource_type) {
+        Ok(resource) => resource,
+        Err(err) => report_resource_error(&err),
     };
 
     debug!("resource.type_name - {} implemented_as - {:?}", resource.type_name, resource.implemented_as);
@@ -210,11 +218,13 @@ pub fn delete(dsc: &DscManager, resource_type: &str, mut input: String) {
 
     if let Some(requires) = &resource.require_adapter {
         input = add_type_name_to_json(input, resource.type_name.clone());
-        if let Some(pr) = get_resource(dsc, requires) {
-            resource = pr;
-        } else {
-            error!("Adapter '{}' not found", requires);
-            return;
+        match get_resource(dsc, requires) {
+            Ok(pr) => resource = pr,
+            Err(DscError::ResourceNotFound(_)) => {
+                error!("Adapter '{}' not found", requires);
+                return;
+            },
+            Err(err) => report_resource_error(&err),
         };
     }
 
@@ -228,9 +238,9 @@ pub fn delete(dsc: &DscManager, resource_type: &str, mut input: String) {
 }
 
 pub fn schema(dsc: &DscManager, resource_type: &str, format: &Option<OutputFormat>) {
-    let Some(resource) = get_resource(dsc, resource_type) else {
-        error!("{}", DscError::ResourceNotFound(resource_type.to_string()).to_string());
-        exit(EXIT_DSC_RESOURCE_NOT_FOUND);
+    let resource = match get_resource(dsc, resource_type) {
+        Ok(resource) => resource,
+        Err(err) => report_resource_error(&err),
     };
     if resource.kind == Kind::Adapter {
         error!("Can not perform this operation on the adapter {} itself", resource.type_name);
@@ -258,9 +268,9 @@ pub fn schema(dsc: &DscManager, resource_type: &str, format: &Option<OutputForma
 
 pub fn export(dsc: &mut DscManager, resource_type: &str, format: &Option<OutputFormat>) {
     let mut input = String::new();
-    let Some(dsc_resource) = get_resource(dsc, resource_type) else {
-        error!("{}", DscError::ResourceNotFound(resource_type.to_string()).to_string());
-        exit(EXIT_DSC_RESOURCE_NOT_FOUND);
+    let dsc_resource = match get_resource(dsc, resource_type) {
+        Ok(resource) => resource,
+        Err(err) => report_resource_error(&err),
     };
 
     if dsc_resource.kind == Kind::Adapter {
@@ -271,11 +281,13 @@ pub fn export(dsc: &mut DscManager, resource_type: &str, format: &Option<OutputF
     let mut adapter_resource: Option<&DscResource> = None;
     if let Some(requires) = &dsc_resource.require_adapter {
         input = add_type_name_to_json(input, dsc_resource.type_name.clone());
-        if let Some(pr) = get_resource(dsc, requires) {
-            adapter_resource = Some(pr);
-        } else {
-            error!("Adapter '{}' not found", requires);
-            return;
+        match get_resource(dsc, requires) {
+            Ok(pr) => adapter_resource = Some(pr),
+            Err(DscError::ResourceNotFound(_)) => {
+                error!("Adapter '{}' not found", requires);
+                return;
+            },
+            Err(err) => report_resource_error(&err),
         };
     }
 
@@ -296,8 +308,21 @@ pub fn export(dsc: &mut DscManager, resource_type: &str, format: &Option<OutputF
     write_output(&json, format);
 }
 
-#[must_use]
-pub fn get_resource<'a>(dsc: &'a DscManager, resource: &str) -> Option<&'a DscResource> {
+/// Get a resource that was found by `DscManager::find_resources()`.
+///
+/// # Errors
+///
+/// This function will return an error if the resource wasn't found or isn't allowed by the policy.
+pub fn get_resource<'a>(dsc: &'a DscManager, resource: &str) -> Result<&'a DscResource, DscError> {
     //TODO: add dynamically generated resource to dsc
-    dsc.find_resource(resource)
+    dsc.get_resource(resource)
+}
+
+// a resource that isn't allowed by the policy is reported as such instead of as not found
+fn report_resource_error(err: &DscError) -> ! {
+    error!("{err}");
+    if matches!(err, DscError::ResourceNotFound(_) | DscError::ResourceVersionNotFound(..)) {
+        exit(EXIT_DSC_RESOURCE_NOT_FOUND);
+    }
+    exit(EXIT_DSC_ERROR);
 }
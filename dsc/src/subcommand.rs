@@ -1,24 +1,30 @@
 // Copyright (c) Microsoft Corporation.
 // Licensed under the MIT License.
 
-use crate::args::{ConfigSubCommand, DscType, OutputFormat, ResourceSubCommand};
+use crate::args::{CacheSubCommand, ConfigSubCommand, DscType, ExpressionSubCommand, FunctionSubCommand, OutputFormat, ParametersSubCommand, ResourceSubCommand};
 use crate::resolve::{get_contents, Include};
 use crate::resource_command::{get_resource, self};
 use crate::tablewriter::Table;
-use crate::util::{DSC_CONFIG_ROOT, EXIT_DSC_ERROR, EXIT_INVALID_INPUT, EXIT_JSON_ERROR, get_schema, write_output, get_input, set_dscconfigroot, validate_json};
-use dsc_lib::configure::{Configurator, config_doc::{Configuration, ExecutionKind}, config_result::ResourceGetResult};
+use crate::util::{EXIT_DSC_ERROR, EXIT_INVALID_ARGS, EXIT_INVALID_INPUT, EXIT_JSON_ERROR, get_schema, write_output, get_input, set_dscconfigroot, validate_json};
+use dsc_lib::configure::{Configurator, config_doc::{Configuration, DataType, ExecutionKind}, config_result::ResourceGetResult, context::DSC_CONFIG_ROOT, parameters::{decrypt_parameters, get_parameters_schema, ParameterLayers, ParameterSource}};
+use dsc_lib::discovery::{get_resource_filter, Discovery};
 use dsc_lib::dscerror::DscError;
 use dsc_lib::dscresources::invoke_result::ResolveResult;
+use dsc_lib::functions::AcceptedArgKind;
+use dsc_lib::encryption::{encrypt_value, get_key_file_path, is_encrypted_value, load_identity, new_key_file, parse_recipient};
 use dsc_lib::{
     DscManager,
     dscresources::invoke_result::ValidateResult,
     dscresources::dscresource::{Capability, ImplementedAs, Invoke},
     dscresources::resource_manifest::{import_manifest, ResourceManifest},
+    settings::DscSettings,
+    util::parse_input_to_json,
 };
+use serde_json::{json, Map, Value};
 use std::collections::HashMap;
-use std::io::{self, IsTerminal};
+use std::io::{self, IsTerminal, Write};
 use std::process::exit;
-use tracing::{debug, error, trace};
+use tracing::{debug, error, info, trace};
 
 pub fn config_get(configurator: &mut Configurator, format: &Option<OutputFormat>, as_group: &bool)
 {
@@ -185,8 +191,9 @@ fn initialize_config_root(path: &Option<String>) -> Option<String> {
     }
 }
 
+#[allow(clippy::too_many_arguments)]
 #[allow(clippy::too_many_lines)]
-pub fn config(subcommand: &ConfigSubCommand, parameters: &Option<String>, stdin: &Option<String>, as_group: &bool, as_include: &bool) {
+pub fn config(subcommand: &ConfigSubCommand, parameters: &Option<String>, parameters_file: &Option<Vec<String>>, parameter: &Option<Vec<String>>, stdin: &Option<String>, as_group: &bool, as_include: &bool, settings: &DscSettings) {
     let (new_parameters, json_string) = match subcommand {
         ConfigSubCommand::Get { document, path, .. } |
         ConfigSubCommand::Set { document, path, .. } |
@@ -222,7 +229,7 @@ pub fn config(subcommand: &ConfigSubCommand, parameters: &Option<String>, stdin:
         }
     };
 
-    let mut configurator = match Configurator::new(&json_string) {
+    let mut configurator = match Configurator::new(&json_string, settings) {
         Ok(configurator) => configurator,
         Err(err) => {
             error!("Error: {err}");
@@ -236,39 +243,21 @@ pub fn config(subcommand: &ConfigSubCommand, parameters: &Option<String>, stdin:
         }
     };
 
-    let parameters: Option<serde_json::Value> = match if new_parameters.is_some() {
-        &new_parameters
-    } else {
-        parameters
-    } {
-        None => {
-            debug!("No parameters specified");
-            None
-        },
-        Some(parameters) => {
-            debug!("Parameters specified");
-            match serde_json::from_str(parameters) {
-                Ok(json) => Some(json),
-                Err(_) => {
-                    match serde_yaml::from_str::<serde_yaml::Value>(parameters) {
-                        Ok(yaml) => {
-                            match serde_json::to_value(yaml) {
-                                Ok(json) => Some(json),
-                                Err(err) => {
-                                    error!("Error: Failed to convert YAML to JSON: {err}");
-                                    exit(EXIT_DSC_ERROR);
-                                }
-                            }
-                        },
-                        Err(err) => {
-                            error!("Error: Parameters are not valid JSON or YAML: {err}");
-                            exit(EXIT_INVALID_INPUT);
-                        }
-                    }
-                }
-            }
+    let layers = match get_parameter_layers(configurator.get_config(), &new_parameters, parameters, parameters_file, parameter) {
+        Ok(layers) => layers,
+        Err(err) => {
+            error!("Error: Parameter input failure: {err}");
+            exit(EXIT_INVALID_INPUT);
         }
     };
+    configurator.set_parameter_sources(layers.sources());
+    let parameters = if layers.is_empty() {
+        debug!("No parameters specified");
+        None
+    } else {
+        debug!("Parameters specified");
+        Some(layers.to_input())
+    };
 
     if let Err(err) = configurator.set_context(&parameters) {
         error!("Error: Parameter input failure: {err}");
@@ -303,7 +292,7 @@ pub fn config(subcommand: &ConfigSubCommand, parameters: &Option<String>, stdin:
                     }
                 }
             } else {
-                match validate_config(configurator.get_config()) {
+                match validate_config(configurator.get_config(), settings) {
                     Ok(()) => {
                         // valid, so do nothing
                     },
@@ -312,6 +301,12 @@ pub fn config(subcommand: &ConfigSubCommand, parameters: &Option<String>, stdin:
                         result.valid = false;
                     }
                 };
+
+                // expressions are checked before any resource is invoked
+                for err in configurator.validate_expressions() {
+                    error!("{err}");
+                    result.valid = false;
+                }
             }
 
             let Ok(json) = serde_json::to_string(&result) else {
@@ -358,11 +353,66 @@ pub fn config(subcommand: &ConfigSubCommand, parameters: &Option<String>, stdin:
     }
 }
 
+fn parse_parameters(parameters: &str) -> Value {
+    match serde_json::from_str(parameters) {
+        Ok(json) => json,
+        Err(_) => {
+            match serde_yaml::from_str::<serde_yaml::Value>(parameters) {
+                Ok(yaml) => {
+                    match serde_json::to_value(yaml) {
+                        Ok(json) => json,
+                        Err(err) => {
+                            error!("Error: Failed to convert YAML to JSON: {err}");
+                            exit(EXIT_DSC_ERROR);
+                        }
+                    }
+                },
+                Err(err) => {
+                    error!("Error: Parameters are not valid JSON or YAML: {err}");
+                    exit(EXIT_INVALID_INPUT);
+                }
+            }
+        }
+    }
+}
+
+// the parameters of an include and the parameters input are merged first, then the parameters files in order,
+// then `DSC_PARAM_<name>` environment variables and then `--parameter` overrides
+fn get_parameter_layers(config: &Configuration, included: &Option<String>, parameters: &Option<String>, parameters_file: &Option<Vec<String>>, parameter: &Option<Vec<String>>) -> Result<ParameterLayers, DscError> {
+    let mut layers = ParameterLayers::new();
+    let no_parameters = HashMap::new();
+    let declared = config.parameters.as_ref().unwrap_or(&no_parameters);
+    if let Some(included) = included {
+        debug!("Parameters specified by include");
+        layers.add_input(&parse_parameters(included), &ParameterSource::Input)?;
+    }
+    if let Some(parameters) = parameters {
+        layers.add_input(&parse_parameters(parameters), &ParameterSource::Input)?;
+    }
+    for file_name in parameters_file.iter().flatten() {
+        info!("Reading parameters from file {file_name}");
+        let parameters = match std::fs::read_to_string(file_name) {
+            Ok(parameters) => parameters,
+            Err(err) => return Err(DscError::Validation(format!("Failed to read parameters file '{file_name}': {err}"))),
+        };
+        layers.add_input(&parse_parameters(&parameters), &ParameterSource::File(file_name.clone()))?;
+    }
+    layers.add_environment(declared)?;
+    for name_value in parameter.iter().flatten() {
+        let Some((name, value)) = name_value.split_once('=') else {
+            return Err(DscError::Validation(format!("Parameter '{name_value}' must be specified as name=value")));
+        };
+        layers.add_text(name.trim(), value, declared, ParameterSource::CommandLine)?;
+    }
+    Ok(layers)
+}
+
 /// Validate configuration.
 ///
 /// # Arguments
 ///
 /// * `config` - The configuration to validate.
+/// * `settings` - The settings used to find the resources of the configuration.
 ///
 /// # Returns
 ///
@@ -371,13 +421,13 @@ pub fn config(subcommand: &ConfigSubCommand, parameters: &Option<String>, stdin:
 /// # Errors
 ///
 /// * `DscError` - The error that occurred.
-pub fn validate_config(config: &Configuration) -> Result<(), DscError> {
+pub fn validate_config(config: &Configuration, settings: &DscSettings) -> Result<(), DscError> {
     // first validate against the config schema
     debug!("Validating configuration against schema");
     let schema = serde_json::to_value(get_schema(DscType::Configuration))?;
     let config_value = serde_json::to_value(config)?;
     validate_json("Configuration", &schema, &config_value)?;
-    let mut dsc = DscManager::new()?;
+    let mut dsc = DscManager::new(settings);
 
     // then validate each resource
     let Some(resources) = config_value["resources"].as_array() else {
@@ -391,11 +441,12 @@ pub fn validate_config(config: &Configuration) -> Result<(), DscError> {
             return Err(DscError::Validation("Error: Resource type not specified".to_string()));
         };
 
-        if resource_types.contains(&type_name.to_lowercase()) {
+        let resource_filter = get_resource_filter(type_name, resource_block["requireVersion"].as_str()).to_lowercase();
+        if resource_types.contains(&resource_filter) {
             continue;
         }
 
-        resource_types.push(type_name.to_lowercase().to_string());
+        resource_types.push(resource_filter);
     }
     dsc.find_resources(&resource_types);
 
@@ -407,8 +458,11 @@ pub fn validate_config(config: &Configuration) -> Result<(), DscError> {
         trace!("Validating resource named '{}'", resource_block["name"].as_str().unwrap_or_default());
 
         // get the actual resource
-        let Some(resource) = get_resource(&dsc, type_name) else {
-            return Err(DscError::Validation(format!("Error: Resource type '{type_name}' not found")));
+        let resource_filter = get_resource_filter(type_name, resource_block["requireVersion"].as_str());
+        let resource = match get_resource(&dsc, &resource_filter) {
+            Ok(resource) => resource,
+            Err(DscError::ResourceNotFound(_)) => return Err(DscError::Validation(format!("Error: Resource type '{resource_filter}' not found"))),
+            Err(err) => return Err(err),
         };
 
         // see if the resource is command based
@@ -447,16 +501,366 @@ pub fn validate_config(config: &Configuration) -> Result<(), DscError> {
     Ok(())
 }
 
-#[allow(clippy::too_many_lines)]
-pub fn resource(subcommand: &ResourceSubCommand, stdin: &Option<String>) {
-    let mut dsc = match DscManager::new() {
-        Ok(dsc) => dsc,
+pub fn expression(subcommand: &ExpressionSubCommand, stdin: &Option<String>, settings: &DscSettings) {
+    match subcommand {
+        ExpressionSubCommand::Eval { expression, document, path, parameters, variables, references, interactive, format } => {
+            let mut configurator = get_expression_configurator(document, path, parameters, variables, references, settings);
+            if *interactive {
+                evaluate_interactive(&mut configurator, stdin, format);
+                return;
+            }
+
+            let Some(statement) = expression.as_ref().or(stdin.as_ref()) else {
+                error!("Error: No expression specified");
+                exit(EXIT_INVALID_ARGS);
+            };
+            if let Err(err) = evaluate_expression(&mut configurator, statement.trim_end(), format) {
+                error!("Error: {err}");
+                exit(EXIT_DSC_ERROR);
+            }
+        },
+    }
+}
+
+fn get_expression_configurator(document: &Option<String>, path: &Option<String>, parameters: &Option<String>, variables: &Option<String>, references: &Option<String>, settings: &DscSettings) -> Configurator {
+    let new_path = initialize_config_root(path);
+    let has_document = document.is_some() || new_path.is_some();
+    let json_string = if has_document {
+        get_input(document, &None, &new_path)
+    } else {
+        match serde_json::to_string(&Configuration::new()) {
+            Ok(json) => json,
+            Err(err) => {
+                error!("JSON Error: {err}");
+                exit(EXIT_JSON_ERROR);
+            }
+        }
+    };
+
+    let mut configurator = match Configurator::new(&json_string, settings) {
+        Ok(configurator) => configurator,
         Err(err) => {
             error!("Error: {err}");
             exit(EXIT_DSC_ERROR);
         }
     };
 
+    let parameters = parse_expression_input("parameters", parameters);
+    if has_document {
+        if let Err(err) = configurator.set_context(&parameters) {
+            error!("Error: Parameter input failure: {err}");
+            exit(EXIT_INVALID_INPUT);
+        }
+    } else if let Some(parameters) = parameters {
+        // without a document the parameter types are taken from the values
+        let Some(parameters) = parameters.get("parameters").and_then(Value::as_object) else {
+            error!("Error: Parameters must be an object with a 'parameters' property");
+            exit(EXIT_INVALID_INPUT);
+        };
+        for (name, value) in parameters {
+            let data_type = match value {
+                Value::Array(_) => DataType::Array,
+                Value::Bool(_) => DataType::Bool,
+                // parameters have no decimal type, like in a configuration document
+                Value::Number(number) if number.is_i64() => DataType::Int,
+                Value::Number(_) => {
+                    error!("Error: Parameter '{name}' is not an integer");
+                    exit(EXIT_INVALID_INPUT);
+                },
+                Value::Object(_) => DataType::Object,
+                Value::String(_) => DataType::String,
+                Value::Null => {
+                    error!("Error: Parameter '{name}' can't be null");
+                    exit(EXIT_INVALID_INPUT);
+                },
+            };
+            configurator.context.parameters.insert(name.clone(), (value.clone(), data_type));
+        }
+    }
+
+    if let Some(variables) = parse_expression_input("variables", variables) {
+        let Value::Object(variables) = variables else {
+            error!("Error: Variables must be an object");
+            exit(EXIT_INVALID_INPUT);
+        };
+        configurator.context.variables.extend(variables);
+    }
+    if let Some(references) = parse_expression_input("references", references) {
+        let Value::Object(references) = references else {
+            error!("Error: References must be an object keyed by resource id");
+            exit(EXIT_INVALID_INPUT);
+        };
+        configurator.context.outputs.extend(references);
+    }
+
+    configurator
+}
+
+fn parse_expression_input(name: &str, input: &Option<String>) -> Option<Value> {
+    let input = input.as_ref()?;
+    let json = match parse_input_to_json(input) {
+        Ok(json) => json,
+        Err(err) => {
+            error!("Error: {name} are not valid JSON or YAML: {err}");
+            exit(EXIT_INVALID_INPUT);
+        }
+    };
+    match serde_json::from_str(&json) {
+        Ok(value) => Some(value),
+        Err(err) => {
+            error!("JSON Error: {err}");
+            exit(EXIT_JSON_ERROR);
+        }
+    }
+}
+
+fn evaluate_expression(configurator: &mut Configurator, statement: &str, format: &Option<OutputFormat>) -> Result<(), DscError> {
+    let result = configurator.evaluate(statement)?;
+    let json = serde_json::to_string(&json!({
+        "type": AcceptedArgKind::from_value(&result),
+        "value": result,
+    }))?;
+    write_output(&json, format);
+    Ok(())
+}
+
+fn evaluate_interactive(configurator: &mut Configurator, stdin: &Option<String>, format: &Option<OutputFormat>) {
+    // piped input has already been read, so its lines are evaluated as if entered at the prompt
+    if let Some(input) = stdin {
+        for line in input.lines() {
+            if !evaluate_line(configurator, line, format) {
+                break;
+            }
+        }
+        return;
+    }
+
+    loop {
+        print!("dsc> ");
+        // the prompt is still shown if flushing fails
+        let _ = io::stdout().flush();
+        let mut line = String::new();
+        match io::stdin().read_line(&mut line) {
+            // end of input
+            Ok(0) => break,
+            Ok(_) => {},
+            Err(err) => {
+                error!("Error: Failed to read input: {err}");
+                exit(EXIT_INVALID_INPUT);
+            }
+        }
+        if !evaluate_line(configurator, &line, format) {
+            break;
+        }
+    }
+}
+
+/// Evaluate a line entered interactively, returning `false` if the session should end.
+fn evaluate_line(configurator: &mut Configurator, line: &str, format: &Option<OutputFormat>) -> bool {
+    let statement = line.trim();
+    if statement == "exit" || statement == "quit" {
+        return false;
+    }
+    // errors are reported and the session continues
+    if !statement.is_empty() {
+        if let Err(err) = evaluate_expression(configurator, statement, format) {
+            error!("Error: {err}");
+        }
+    }
+    true
+}
+
+/// Get the JSON schema of a parameters file for a configuration document.
+///
+/// # Arguments
+///
+/// * `path` - The path to the configuration document.
+#[must_use]
+pub fn parameters_schema(path: &str) -> Value {
+    let json = get_input(&None, &None, &Some(path.to_string()));
+    let configuration = match serde_json::from_str::<Configuration>(&json) {
+        Ok(configuration) => configuration,
+        Err(err) => {
+            error!("Error: Invalid configuration document '{path}': {err}");
+            exit(EXIT_INVALID_INPUT);
+        }
+    };
+    get_parameters_schema(&configuration.parameters.unwrap_or_default())
+}
+
+pub fn function(subcommand: &FunctionSubCommand, settings: &DscSettings) {
+    let mut dsc = DscManager::new(settings);
+
+    match subcommand {
+        FunctionSubCommand::List { function_name, format } => {
+            list_functions(&mut dsc, function_name, format);
+        },
+    }
+}
+
+fn list_functions(dsc: &mut DscManager, function_name: &Option<String>, format: &Option<OutputFormat>) {
+    let functions = match dsc.list_functions(&function_name.clone().unwrap_or("*".to_string())) {
+        Ok(functions) => functions,
+        Err(err) => {
+            error!("Error: {err}");
+            exit(EXIT_DSC_ERROR);
+        }
+    };
+
+    // write as table if format is not specified and interactive
+    let write_table = format.is_none() && io::stdout().is_terminal();
+    let mut table = Table::new(&["Name", "Category", "Parameters", "ReturnTypes", "Description"]);
+    for function in functions {
+        if write_table {
+            // optional parameters are shown in brackets and a trailing ellipsis means the last one can be repeated
+            let mut parameters = function.parameters.iter().enumerate()
+                .map(|(index, parameter)| {
+                    let types = parameter.types.iter().map(|kind| format!("{kind:?}")).collect::<Vec<String>>().join("|");
+                    let parameter = format!("{0}: {types}", parameter.name);
+                    if index < function.min_args { parameter } else { format!("[{parameter}]") }
+                })
+                .collect::<Vec<String>>();
+            if function.max_args > function.parameters.len() {
+                parameters.push("...".to_string());
+            }
+            table.add_row(vec![
+                function.name,
+                format!("{:?}", function.category),
+                parameters.join(", "),
+                function.return_types.iter().map(|kind| format!("{kind:?}")).collect::<Vec<String>>().join(", "),
+                function.description,
+            ]);
+        }
+        else {
+            let json = match serde_json::to_string(&function) {
+                Ok(json) => json,
+                Err(err) => {
+                    error!("JSON Error: {err}");
+                    exit(EXIT_JSON_ERROR);
+                }
+            };
+            write_output(&json, format);
+            // insert newline separating instances if writing to console
+            if io::stdout().is_terminal() { println!(); }
+        }
+    }
+
+    if write_table {
+        table.print();
+    }
+}
+
+pub fn parameters(subcommand: &ParametersSubCommand, stdin: &Option<String>) {
+    match subcommand {
+        ParametersSubCommand::Encrypt { document, path, recipient, name, format } => {
+            encrypt_parameters_input(document, stdin, path, recipient, name, format);
+        },
+        ParametersSubCommand::Decrypt { document, path, format } => {
+            decrypt_parameters_input(document, stdin, path, format);
+        },
+        ParametersSubCommand::NewKey { path } => {
+            new_parameters_key(path);
+        },
+    }
+}
+
+fn encrypt_parameters_input(document: &Option<String>, stdin: &Option<String>, path: &Option<String>, recipient: &Option<String>, name: &Option<Vec<String>>, format: &Option<OutputFormat>) {
+    let recipient = match recipient {
+        Some(recipient) => parse_recipient(recipient),
+        None => get_key_file_path().and_then(|key_path| load_identity(&key_path)).map(|identity| identity.to_public()),
+    };
+    let recipient = match recipient {
+        Ok(recipient) => recipient,
+        Err(err) => {
+            error!("Error: {err}");
+            exit(EXIT_DSC_ERROR);
+        }
+    };
+    let mut input = get_parameters_input(document, stdin, path);
+    let Some(parameters) = input.get_mut("parameters").and_then(Value::as_object_mut) else {
+        error!("Error: Input does not contain 'parameters'");
+        exit(EXIT_INVALID_INPUT);
+    };
+    if let Some(names) = name {
+        for name in names {
+            if !parameters.contains_key(name) {
+                error!("Error: Parameter '{name}' not found");
+                exit(EXIT_INVALID_ARGS);
+            }
+        }
+    }
+    for (parameter_name, value) in parameters.iter_mut() {
+        if name.as_ref().is_some_and(|names| !names.contains(parameter_name)) {
+            continue;
+        }
+        if value.as_str().is_some_and(is_encrypted_value) {
+            debug!("Parameter '{parameter_name}' is already encrypted");
+            continue;
+        }
+        match encrypt_value(value, &recipient) {
+            Ok(encrypted) => *value = Value::String(encrypted),
+            Err(err) => {
+                error!("Error: {err}");
+                exit(EXIT_DSC_ERROR);
+            }
+        }
+    }
+    write_parameters_output(&input, format);
+}
+
+fn decrypt_parameters_input(document: &Option<String>, stdin: &Option<String>, path: &Option<String>, format: &Option<OutputFormat>) {
+    let mut input = get_parameters_input(document, stdin, path);
+    let Some(parameters) = input.get_mut("parameters").and_then(Value::as_object_mut) else {
+        error!("Error: Input does not contain 'parameters'");
+        exit(EXIT_INVALID_INPUT);
+    };
+    if let Err(err) = decrypt_parameters(parameters.iter_mut()) {
+        error!("Error: {err}");
+        exit(EXIT_DSC_ERROR);
+    }
+    write_parameters_output(&input, format);
+}
+
+fn new_parameters_key(path: &Option<String>) {
+    let key_path = match path {
+        Some(path) => Ok(std::path::PathBuf::from(path)),
+        None => get_key_file_path(),
+    };
+    match key_path.and_then(|key_path| new_key_file(&key_path)) {
+        Ok(recipient) => println!("{recipient}"),
+        Err(err) => {
+            error!("Error: {err}");
+            exit(EXIT_DSC_ERROR);
+        }
+    }
+}
+
+fn get_parameters_input(document: &Option<String>, stdin: &Option<String>, path: &Option<String>) -> Map<String, Value> {
+    let json = get_input(document, stdin, path);
+    match serde_json::from_str::<Map<String, Value>>(&json) {
+        Ok(input) => input,
+        Err(err) => {
+            error!("Error: Invalid parameters: {err}");
+            exit(EXIT_INVALID_INPUT);
+        }
+    }
+}
+
+fn write_parameters_output(input: &Map<String, Value>, format: &Option<OutputFormat>) {
+    let json = match serde_json::to_string(input) {
+        Ok(json) => json,
+        Err(err) => {
+            error!("JSON Error: {err}");
+            exit(EXIT_JSON_ERROR);
+        }
+    };
+    write_output(&json, format);
+}
+
+#[allow(clippy::too_many_lines)]
+pub fn resource(subcommand: &ResourceSubCommand, stdin: &Option<String>, settings: &DscSettings) {
+    let mut dsc = DscManager::new(settings);
+
     match subcommand {
         ResourceSubCommand::List { resource_name, adapter_name, description, tags, format } => {
             list_resources(&mut dsc, resource_name, adapter_name, description, tags, format);
@@ -492,6 +896,18 @@ pub fn resource(subcommand: &ResourceSubCommand, stdin: &Option<String>) {
             let parsed_input = get_input(input, stdin, path);
             resource_command::delete(&dsc, resource, parsed_input);
         },
+        ResourceSubCommand::Cache { subcommand } => {
+            let result = match subcommand {
+                CacheSubCommand::Clear => Discovery::clear_cache(settings),
+                CacheSubCommand::Rebuild => Discovery::rebuild_cache(settings).map(|count| {
+                    info!("Discovery index has {count} resource manifests");
+                }),
+            };
+            if let Err(err) = result {
+                error!("Error: {err}");
+                exit(EXIT_DSC_ERROR);
+            }
+        },
     }
 }
 
@@ -1,11 +1,13 @@
 // Copyright (c) Microsoft Corporation.
 // Licensed under the MIT License.
 
-use crate::args::{DscType, OutputFormat, TraceFormat};
+use crate::args::{DscType, OutputFormat};
 use crate::resolve::Include;
 use dsc_lib::{
     configure::{
         config_doc::Configuration,
+        context::DSC_CONFIG_ROOT,
+        redaction::redact,
         config_result::{
             ConfigurationGetResult,
             ConfigurationSetResult,
@@ -14,7 +16,7 @@ use dsc_lib::{
     },
     dscerror::DscError,
     dscresources::{
-        command_resource::TraceLevel,
+        command_resource::{TraceFormat, TraceLevel},
         dscresource::DscResource, invoke_result::{
             GetResult,
             SetResult,
@@ -22,6 +24,7 @@ use dsc_lib::{
             ResolveResult,
         }, resource_manifest::ResourceManifest
     },
+    settings::TracingSettings,
     util::parse_input_to_json,
 };
 use jsonschema::Validator;
@@ -30,7 +33,7 @@ use schemars::{schema_for, schema::RootSchema};
 use serde_json::Value;
 use std::collections::HashMap;
 use std::env;
-use std::io::IsTerminal;
+use std::io::{self, IsTerminal, Write};
 use std::path::Path;
 use std::process::exit;
 use syntect::{
@@ -40,7 +43,7 @@ use syntect::{
     util::{as_24_bit_terminal_escaped, LinesWithEndings}
 };
 use tracing::{Level, debug, error, warn, trace};
-use tracing_subscriber::{filter::EnvFilter, layer::SubscriberExt, Layer};
+use tracing_subscriber::{filter::EnvFilter, fmt::MakeWriter, layer::SubscriberExt, Layer};
 use tracing_indicatif::IndicatifLayer;
 
 pub const EXIT_SUCCESS: i32 = 0;
@@ -52,7 +55,6 @@ pub const EXIT_VALIDATION_FAILED: i32 = 5;
 pub const EXIT_CTRL_C: i32 = 6;
 pub const EXIT_DSC_RESOURCE_NOT_FOUND: i32 = 7;
 
-pub const DSC_CONFIG_ROOT: &str = "DSC_CONFIG_ROOT";
 pub const DSC_TRACE_LEVEL: &str = "DSC_TRACE_LEVEL";
 
 /// Get string representation of JSON value.
@@ -268,7 +270,32 @@ pub fn write_output(json: &str, format: &Option<OutputFormat>) {
     }
 }
 
-pub fn enable_tracing(trace_level: &Option<TraceLevel>, trace_format: &TraceFormat) {
+/// Writes traces with secure values masked.
+struct RedactingWriter<W: Write>(W);
+
+impl<W: Write> Write for RedactingWriter<W> {
+    fn write(&mut self, buf: &[u8]) -> io::Result<usize> {
+        // each trace is formatted before it's written, so a secure value isn't split across writes
+        self.0.write_all(redact(&String::from_utf8_lossy(buf)).as_bytes())?;
+        Ok(buf.len())
+    }
+
+    fn flush(&mut self) -> io::Result<()> {
+        self.0.flush()
+    }
+}
+
+struct RedactingMakeWriter<M>(M);
+
+impl<'a, M: MakeWriter<'a>> MakeWriter<'a> for RedactingMakeWriter<M> {
+    type Writer = RedactingWriter<M::Writer>;
+
+    fn make_writer(&'a self) -> Self::Writer {
+        RedactingWriter(self.0.make_writer())
+    }
+}
+
+pub fn enable_tracing(trace_level: &Option<TraceLevel>, trace_format: &Option<TraceFormat>, settings: &TracingSettings) {
     let tracing_level = match trace_level {
         Some(level) => level,
         None => {
@@ -287,10 +314,12 @@ pub fn enable_tracing(trace_level: &Option<TraceLevel>, trace_format: &TraceForm
                         },
                     }
                 },
-                Err(_) => &TraceLevel::Warn,
+                // otherwise use the level from the settings
+                Err(_) => settings.level.as_ref().unwrap_or(&TraceLevel::Warn),
             }
         }
     };
+    let trace_format = trace_format.as_ref().or(settings.format.as_ref()).unwrap_or(&TraceFormat::Default);
 
     let tracing_level = match tracing_level {
         TraceLevel::Error => Level::ERROR,
@@ -305,7 +334,7 @@ pub fn enable_tracing(trace_level: &Option<TraceLevel>, trace_format: &TraceForm
         .unwrap_or_default()
         .add_directive(tracing_level.into());
     let indicatif_layer = IndicatifLayer::new();
-    let layer = tracing_subscriber::fmt::Layer::default().with_writer(indicatif_layer.get_stderr_writer());
+    let layer = tracing_subscriber::fmt::Layer::default().with_writer(RedactingMakeWriter(indicatif_layer.get_stderr_writer()));
     let with_source = tracing_level == Level::DEBUG || tracing_level == Level::TRACE;
     let fmt = match trace_format {
         TraceFormat::Default => {
@@ -10,6 +10,12 @@ Describe 'tests for resource discovery' {
         } else {
             Join-Path $env:HOME ".dsc" "AdaptedResourcesLookupTable.json"
         }
+
+        $script:discoveryIndexFilePath = if ($IsWindows) {
+            Join-Path $env:LocalAppData "dsc\DiscoveryIndex.json"
+        } else {
+            Join-Path $env:HOME ".dsc" "DiscoveryIndex.json"
+        }
     }
 
     AfterEach {
@@ -183,4 +189,144 @@ Describe 'tests for resource discovery' {
         $out = dsc resource schema -r abc/def
         $LASTEXITCODE | Should -Be 7
     }
+
+    It 'discovery index is refreshed when manifests change' {
+        $manifest = @'
+        {
+            "$schema": "https://raw.githubusercontent.com/PowerShell/DSC/main/schemas/2024/04/bundled/resource/manifest.json",
+            "type": "DSC/TestIndexResource",
+            "version": "VERSION",
+            "get": {
+              "executable": "dsc"
+            }
+          }
+'@
+
+        dsc resource cache rebuild
+        $LASTEXITCODE | Should -Be 0
+        Test-Path $script:discoveryIndexFilePath | Should -BeTrue
+
+        Set-Content -Path "$testdrive/test.dsc.resource.json" -Value $manifest.Replace('VERSION', '0.1.0')
+        $resources = dsc resource list 'DSC/TestIndexResource' | ConvertFrom-Json
+        $resources.version | Should -BeExactly '0.1.0'
+
+        Set-Content -Path "$testdrive/test.dsc.resource.json" -Value $manifest.Replace('VERSION', '0.10.0')
+        $resources = dsc resource list 'DSC/TestIndexResource' | ConvertFrom-Json
+        $resources.version | Should -BeExactly '0.10.0'
+
+        Remove-Item -Path "$testdrive/test.dsc.resource.json"
+        $resources = dsc resource list 'DSC/TestIndexResource' | ConvertFrom-Json
+        $resources | Should -BeNullOrEmpty
+
+        dsc resource cache clear
+        $LASTEXITCODE | Should -Be 0
+        Test-Path $script:discoveryIndexFilePath | Should -BeFalse
+        Test-Path $script:lookupTableFilePath | Should -BeFalse
+    }
+}
+
+Describe 'tests for resource version requirements' {
+    BeforeAll {
+        $oldPath = $env:DSC_RESOURCE_PATH
+        $env:DSC_RESOURCE_PATH = $testdrive
+
+        $manifest = @'
+        {
+            "$schema": "https://raw.githubusercontent.com/PowerShell/DSC/main/schemas/2024/04/bundled/resource/manifest.json",
+            "type": "Test/Version",
+            "version": "VERSION",
+            "get": {
+                "executable": "pwsh",
+                "args": [
+                    "-NoLogo",
+                    "-NonInteractive",
+                    "-NoProfile",
+                    "-Command",
+                    "'{ \"version\": \"VERSION\" }'"
+                ]
+            },
+            "schema": {
+                "embedded": {
+                    "$schema": "http://json-schema.org/draft-07/schema#",
+                    "type": "object",
+                    "additionalProperties": false,
+                    "properties": {
+                        "version": {
+                            "type": "string"
+                        }
+                    }
+                }
+            }
+        }
+'@
+
+        foreach ($version in '1.0.0', '1.3.0', '2.0.0') {
+            Set-Content -Path "$testdrive/$version.dsc.resource.json" -Value $manifest.Replace('VERSION', $version)
+        }
+    }
+
+    AfterAll {
+        $env:DSC_RESOURCE_PATH = $oldPath
+    }
+
+    It 'resource get uses the newest version that satisfies <filter>' -TestCases @(
+        @{ filter = 'Test/Version'; expected = '2.0.0' }
+        @{ filter = 'Test/Version@^1'; expected = '1.3.0' }
+        @{ filter = 'test/version@=1.0.0'; expected = '1.0.0' }
+        @{ filter = 'Test/Version@>=1.1, <2'; expected = '1.3.0' }
+    ) {
+        param($filter, $expected)
+
+        $out = '{}' | dsc resource get -r $filter | ConvertFrom-Json
+        $LASTEXITCODE | Should -Be 0
+        $out.actualState.version | Should -BeExactly $expected
+    }
+
+    It 'resource get fails when no version satisfies the requirement' {
+        $out = '{}' | dsc resource get -r 'Test/Version@^3' 2> $TestDrive/error.txt
+        $LASTEXITCODE | Should -Be 7
+        "$TestDrive/error.txt" | Should -FileContentMatch 'available versions: 2.0.0, 1.3.0, 1.0.0'
+    }
+
+    It 'config uses requireVersion of each resource' {
+        $config_yaml = @'
+            $schema: https://raw.githubusercontent.com/PowerShell/DSC/main/schemas/2024/04/config/document.json
+            resources:
+            - name: Latest
+              type: Test/Version
+            - name: Pinned
+              type: Test/Version
+              requireVersion: ~1.0
+'@
+        $out = $config_yaml | dsc config get | ConvertFrom-Json
+        $LASTEXITCODE | Should -Be 0
+        $out.results[0].result.actualState.version | Should -BeExactly '2.0.0'
+        $out.results[1].result.actualState.version | Should -BeExactly '1.0.0'
+    }
+
+    It 'config fails when no version satisfies requireVersion' {
+        $config_yaml = @'
+            $schema: https://raw.githubusercontent.com/PowerShell/DSC/main/schemas/2024/04/config/document.json
+            resources:
+            - name: Missing
+              type: Test/Version
+              requireVersion: ^3
+'@
+        $out = $config_yaml | dsc config get 2> $TestDrive/error.txt
+        $LASTEXITCODE | Should -Be 2
+        "$TestDrive/error.txt" | Should -FileContentMatch "No version satisfies 'Test/Version@\^3', available versions: 2.0.0, 1.3.0, 1.0.0"
+    }
+
+    It 'config fails when requireVersion is not a valid version requirement' {
+        $config_yaml = @'
+            $schema: https://raw.githubusercontent.com/PowerShell/DSC/main/schemas/2024/04/config/document.json
+            resources:
+            - name: Invalid
+              type: Test/Version
+              requireVersion: not a version
+'@
+        $out = $config_yaml | dsc config get 2> $TestDrive/error.txt
+        $LASTEXITCODE | Should -Be 2
+        "$TestDrive/error.txt" | Should -FileContentMatch "Invalid version requirement 'not a version'"
+    }
 }
@@ -0,0 +1,80 @@
+# Copyright (c) Microsoft Corporation.
+# Licensed under the MIT License.
+
+Describe 'dsc expression eval tests' {
+    It 'Evaluates an expression' {
+        $out = dsc expression eval "[concat('a', 'b')]" | ConvertFrom-Json
+        $LASTEXITCODE | Should -Be 0
+        $out.value | Should -BeExactly 'ab'
+        $out.type | Should -BeExactly 'String'
+    }
+
+    It 'Reads the expression from STDIN' {
+        $out = "[add(1, 2)]" | dsc expression eval | ConvertFrom-Json
+        $LASTEXITCODE | Should -Be 0
+        $out.value | Should -Be 3
+        $out.type | Should -BeExactly 'Number'
+    }
+
+    It 'Returns typed results' {
+        $out = dsc expression eval "[createArray(1, 2)]" | ConvertFrom-Json
+        $LASTEXITCODE | Should -Be 0
+        $out.value | Should -Be @(1, 2)
+        $out.type | Should -BeExactly 'Array'
+    }
+
+    It 'Uses inline parameters and variables' {
+        $out = dsc expression eval "[concat(parameters('a'), variables('b'))]" --parameters '{"parameters":{"a":"x"}}' --variables '{"b":"y"}' | ConvertFrom-Json
+        $LASTEXITCODE | Should -Be 0
+        $out.value | Should -BeExactly 'xy'
+    }
+
+    It 'Uses mocked reference outputs' {
+        $out = dsc expression eval "[reference(resourceId('Microsoft.DSC.Debug/Echo', 'echo')).output]" --references '{"Microsoft.DSC.Debug/Echo:echo":{"output":"hello"}}' | ConvertFrom-Json
+        $LASTEXITCODE | Should -Be 0
+        $out.value | Should -BeExactly 'hello'
+    }
+
+    It 'Uses parameters and user functions of a configuration document' {
+        $yaml = @'
+$schema: https://raw.githubusercontent.com/PowerShell/DSC/main/schemas/2024/04/config/document.json
+parameters:
+  name:
+    type: string
+    defaultValue: world
+functions:
+- namespace: test
+  members:
+    greet:
+      parameters:
+      - name: who
+        type: string
+      output:
+        type: string
+        value: "[concat('hello ', parameters('who'))]"
+resources: []
+'@
+        $out = dsc expression eval "[test.greet(parameters('name'))]" -d $yaml | ConvertFrom-Json
+        $LASTEXITCODE | Should -Be 0
+        $out.value | Should -BeExactly 'hello world'
+    }
+
+    It 'Rejects decimal parameters without a document' {
+        $null = dsc expression eval "[parameters('a')]" --parameters '{"parameters":{"a":1.5}}' 2>$TestDrive/error.log
+        $LASTEXITCODE | Should -Be 4
+        Get-Content $TestDrive/error.log -Raw | Should -BeLike "*Parameter 'a' is not an integer*"
+    }
+
+    It 'Evaluates expressions interactively' {
+        $out = "[concat('a', 'b')]`n[badFunction()]`n[add(1, 2)]`nexit`n[concat('c', 'd')]" | dsc expression eval -i 2>$TestDrive/error.log
+        $LASTEXITCODE | Should -Be 0
+        ($out -join '') | Should -BeLike '*"ab"*3*'
+        ($out -join '') | Should -Not -BeLike '*"cd"*'
+        Get-Content $TestDrive/error.log -Raw | Should -BeLike "*Unknown function 'badFunction'*"
+    }
+
+    It 'Fails for an invalid expression' {
+        $null = dsc expression eval "[concat('a'" 2>$TestDrive/error.log
+        $LASTEXITCODE | Should -Be 2
+    }
+}
@@ -79,4 +79,69 @@ string.
 "@.Replace("`r", "")
       $out.results[1].result.actualState.output | Should -BeExactly "This is a single-quote: '"
     }
+
+    It 'Expression errors show the resource, property and position' {
+        $yaml = @'
+$schema: https://raw.githubusercontent.com/PowerShell/DSC/main/schemas/2024/04/config/document.json
+resources:
+- name: echo
+  type: Microsoft.DSC.Debug/Echo
+  properties:
+    output:
+      nested: "[concat('a', toUpperr('b'))]"
+'@
+        $null = dsc config get -d $yaml 2>$TestDrive/error.log
+        $LASTEXITCODE | Should -Be 2
+        $log = Get-Content $TestDrive/error.log -Raw
+        $log | Should -BeLike "*Unknown function 'toUpperr' in resource 'echo' property 'output.nested'*"
+        $log.Contains("[concat('a', toUpperr('b'))]") | Should -BeTrue
+        $log | Should -BeLike '*             ^^^^^^^^^^^^^*'
+    }
+
+    It 'Config validate reports invalid expressions without invoking resources' {
+        $yaml = @'
+$schema: https://raw.githubusercontent.com/PowerShell/DSC/main/schemas/2024/04/config/document.json
+parameters:
+  name:
+    type: string
+    defaultValue: test
+resources:
+- name: first
+  type: Microsoft.DSC.Debug/Echo
+  properties:
+    output: "[toUpperr(parameters('name'))]"
+- name: second
+  type: Microsoft.DSC.Debug/Echo
+  properties:
+    output:
+    - "[parameters('nmae')]"
+    - "[add('a', 1)]"
+'@
+        $out = dsc config validate -d $yaml 2>$TestDrive/error.log | ConvertFrom-Json
+        $out.valid | Should -BeFalse
+        $log = Get-Content $TestDrive/error.log -Raw
+        $log | Should -BeLike "*Unknown function 'toUpperr' in resource 'first' property 'output'*"
+        $log.Contains("Parameter 'nmae' is not declared in resource 'second' property 'output[0]'") | Should -BeTrue
+        $log | Should -BeLike "*Function 'add' does not accept String arguments*"
+    }
+
+    It 'Config validate accepts valid expressions' {
+        $yaml = @'
+$schema: https://raw.githubusercontent.com/PowerShell/DSC/main/schemas/2024/04/config/document.json
+parameters:
+  name:
+    type: string
+    defaultValue: test
+variables:
+  greeting: "[concat('hello ', parameters('name'))]"
+resources:
+- name: echo
+  type: Microsoft.DSC.Debug/Echo
+  properties:
+    output: "[toUpper(variables('greeting'))]"
+'@
+        $out = dsc config validate -d $yaml | ConvertFrom-Json
+        $LASTEXITCODE | Should -Be 0
+        $out.valid | Should -BeTrue
+    }
 }
@@ -24,4 +24,148 @@ Describe 'tests for function expressions' {
         $out = $config_yaml | dsc config get | ConvertFrom-Json
         $out.results[0].result.actualState.output | Should -Be $expected
     }
+
+    It 'user function in namespace can be called' {
+        $config_yaml = @'
+            $schema: https://raw.githubusercontent.com/PowerShell/DSC/main/schemas/2024/04/config/document.json
+            functions:
+            - namespace: contoso
+              members:
+                path:
+                  parameters:
+                  - name: app
+                    type: string
+                  output:
+                    type: string
+                    value: "[concat('/opt/', parameters('app'), '/app.conf')]"
+            resources:
+            - name: Echo
+              type: Microsoft.DSC.Debug/Echo
+              properties:
+                output: "[contoso.path('web')]"
+'@
+        $out = $config_yaml | dsc config get | ConvertFrom-Json
+        $LASTEXITCODE | Should -Be 0
+        $out.results[0].result.actualState.output | Should -BeExactly '/opt/web/app.conf'
+    }
+
+    It 'recursive user function returns an error' {
+        $config_yaml = @'
+            $schema: https://raw.githubusercontent.com/PowerShell/DSC/main/schemas/2024/04/config/document.json
+            functions:
+            - namespace: contoso
+              members:
+                loop:
+                  output:
+                    type: string
+                    value: "[contoso.loop()]"
+            resources:
+            - name: Echo
+              type: Microsoft.DSC.Debug/Echo
+              properties:
+                output: "[contoso.loop()]"
+'@
+        $null = $config_yaml | dsc config get 2>$TestDrive/error.log
+        $LASTEXITCODE | Should -Be 2
+        (Get-Content $TestDrive/error.log -Raw) | Should -BeLike '*Recursive call*'
+    }
+
+    It 'function provider can supply functions' {
+        $script = @'
+param($operation)
+if ($operation -eq 'list') {
+    @{ name = 'cmdb.owner'; minArgs = 1; maxArgs = 1; acceptedArgTypes = @('String') } | ConvertTo-Json -Compress
+}
+else {
+    $request = [Console]::In.ReadToEnd() | ConvertFrom-Json
+    "owner of $($request.args[0])" | ConvertTo-Json -Compress
+}
+'@
+        $manifest = @'
+{
+    "$schema": "https://raw.githubusercontent.com/PowerShell/DSC/main/schemas/2024/04/bundled/resource/manifest.json",
+    "type": "Test/Cmdb",
+    "kind": "FunctionProvider",
+    "version": "1.0.0",
+    "functions": {
+        "list": {
+            "executable": "pwsh",
+            "args": ["-NoLogo", "-NonInteractive", "-NoProfile", "-File", "./cmdb.ps1", "list"]
+        },
+        "invoke": {
+            "executable": "pwsh",
+            "args": ["-NoLogo", "-NonInteractive", "-NoProfile", "-File", "./cmdb.ps1", "invoke"]
+        }
+    }
+}
+'@
+        $config_yaml = @'
+            $schema: https://raw.githubusercontent.com/PowerShell/DSC/main/schemas/2024/04/config/document.json
+            resources:
+            - name: Echo
+              type: Microsoft.DSC.Debug/Echo
+              properties:
+                output: "[cmdb.owner('web01')]"
+'@
+        Set-Content -Path $TestDrive/cmdb.ps1 -Value $script
+        Set-Content -Path $TestDrive/Cmdb.dsc.resource.json -Value $manifest
+        $oldPath = $env:DSC_RESOURCE_PATH
+        try {
+            $env:DSC_RESOURCE_PATH = $env:PATH + [System.IO.Path]::PathSeparator + $TestDrive
+            $out = $config_yaml | dsc config get | ConvertFrom-Json
+            $LASTEXITCODE | Should -Be 0
+            $out.results[0].result.actualState.output | Should -BeExactly 'owner of web01'
+        }
+        finally {
+            $env:DSC_RESOURCE_PATH = $oldPath
+        }
+    }
+
+    It 'function list returns metadata' {
+        $out = dsc function list 'concat' | ConvertFrom-Json
+        $LASTEXITCODE | Should -Be 0
+        $out.name | Should -BeExactly 'concat'
+        $out.category | Should -BeExactly 'String'
+        $out.minArgs | Should -Be 2
+        $out.parameters.name | Should -Be @('arg1', 'arg2')
+        $out.parameters[0].types | Should -Be @('String', 'Array')
+        $out.description | Should -Not -BeNullOrEmpty
+    }
+
+    It 'function list supports wildcards' {
+        $out = dsc function list 'date*' | ConvertFrom-Json
+        $LASTEXITCODE | Should -Be 0
+        $out.name | Should -Be @('dateTimeAdd', 'dateTimeFromEpoch', 'dateTimeToEpoch')
+    }
+
+    It 'secret function resolves secrets from the environment as secure values' {
+        $config_yaml = @'
+            $schema: https://raw.githubusercontent.com/PowerShell/DSC/main/schemas/2024/04/config/document.json
+            resources:
+            - name: Echo
+              type: Microsoft.DSC.Debug/Echo
+              properties:
+                output:
+                  value: "[secret('env', 'DSC_TEST_SECRET')]"
+                  length: "[length(secret('env', 'DSC_TEST_SECRET'))]"
+'@
+        $oldSecret = $env:DSC_TEST_SECRET
+        try {
+            $env:DSC_TEST_SECRET = 'hunter2'
+            $out = $config_yaml | dsc -l trace config get 2>$TestDrive/trace.log | ConvertFrom-Json
+            $LASTEXITCODE | Should -Be 0
+            $out.results[0].result.actualState.output.value | Should -BeExactly '<redacted>'
+            $out.results[0].result.actualState.output.length | Should -Be 7
+            Get-Content $TestDrive/trace.log -Raw | Should -Not -BeLike '*hunter2*'
+        }
+        finally {
+            $env:DSC_TEST_SECRET = $oldSecret
+        }
+    }
+
+    It 'secret function fails for an unknown vault' {
+        $null = dsc expression eval "[secret('missing', 'name')]" 2>$TestDrive/error.log
+        $LASTEXITCODE | Should -Be 2
+        Get-Content $TestDrive/error.log -Raw | Should -BeLike "*Unknown vault 'missing'*"
+    }
 }
@@ -238,4 +238,28 @@ resources:
         $out.results[0].result[0].result.inDesiredState | Should -Be $expectedState
         $out.results[0].result[0].result.differingProperties.Count | Should -Be $expectedDiff
     }
+
+    It 'Environment variables override the parameters file of an include' {
+        $config = @"
+            `$schema: https://raw.githubusercontent.com/PowerShell/DSC/main/schemas/2024/04/config/document.json
+            resources:
+            - name: osinfo
+              type: Microsoft.DSC/Include
+              properties:
+                configurationFile: include/osinfo_parameters.dsc.yaml
+                parametersFile: include/osinfo.parameters.yaml
+"@
+        $configPath = Join-Path $TestDrive 'config.dsc.yaml'
+        $config | Set-Content -Path $configPath
+        $oldFamily = $env:DSC_PARAM_osFamily
+        try {
+            $env:DSC_PARAM_osFamily = 'Linux'
+            $out = dsc config test -p $configPath | ConvertFrom-Json
+            $LASTEXITCODE | Should -Be 0
+        }
+        finally {
+            $env:DSC_PARAM_osFamily = $oldFamily
+        }
+        $out.results[0].result[0].result.desiredState.family | Should -BeExactly 'Linux'
+    }
 }
@@ -166,6 +166,95 @@ Describe 'Parameters tests' {
         $LASTEXITCODE | Should -Be 2
     }
 
+    It 'Input does not match the pattern for <value>' -TestCases @(
+        @{ value = 'prod-01'; valid = $true }
+        @{ value = 'production'; valid = $false }
+    ) {
+        param($value, $valid)
+
+        $config_yaml = @"
+            `$schema: https://raw.githubusercontent.com/PowerShell/DSC/main/schemas/2024/04/config/document.json
+            parameters:
+              param1:
+                type: string
+                pattern: '^[a-z]+-\d{2}$'
+            resources:
+            - name: Echo
+              type: Microsoft.DSC.Debug/Echo
+              properties:
+                output: '[parameters(''param1'')]'
+"@
+        $params_json = @{ parameters = @{ param1 = $value }} | ConvertTo-Json
+
+        $out = $config_yaml | dsc config -p $params_json get 2>$TestDrive/error.log | ConvertFrom-Json
+        if ($valid) {
+            $LASTEXITCODE | Should -Be 0
+            $out.results[0].result.actualState.output | Should -BeExactly $value
+        }
+        else {
+            $LASTEXITCODE | Should -Be 4
+            (Get-Content $TestDrive/error.log -Raw).Contains("Parameter 'param1' does not match pattern") | Should -BeTrue
+        }
+    }
+
+    It 'Object input is validated against the schema' {
+        $config_yaml = @"
+            `$schema: https://raw.githubusercontent.com/PowerShell/DSC/main/schemas/2024/04/config/document.json
+            parameters:
+              param1:
+                type: object
+                schema:
+                  type: object
+                  required: [name]
+                  properties:
+                    name: { type: string }
+                    port: { type: integer, minimum: 1 }
+            resources:
+            - name: Echo
+              type: Microsoft.DSC.Debug/Echo
+              properties:
+                output: '[parameters(''param1'')]'
+"@
+        $params_json = @{ parameters = @{ param1 = @{ name = 'web'; port = 80 } }} | ConvertTo-Json -Depth 3
+        $out = $config_yaml | dsc config -p $params_json get | ConvertFrom-Json
+        $LASTEXITCODE | Should -Be 0
+        $out.results[0].result.actualState.output.name | Should -BeExactly 'web'
+
+        $params_json = @{ parameters = @{ param1 = @{ port = 0 } }} | ConvertTo-Json -Depth 3
+        $null = $config_yaml | dsc config -p $params_json get 2>$TestDrive/error.log
+        $LASTEXITCODE | Should -Be 4
+        (Get-Content $TestDrive/error.log -Raw).Contains("Parameter 'param1' is not valid against its schema") | Should -BeTrue
+    }
+
+    It 'All missing required parameters are reported' {
+        $config_yaml = @"
+            `$schema: https://raw.githubusercontent.com/PowerShell/DSC/main/schemas/2024/04/config/document.json
+            parameters:
+              zone:
+                type: string
+              name:
+                type: string
+              port:
+                type: int
+                defaultValue: 80
+              user:
+                type: string
+            resources:
+            - name: Echo
+              type: Microsoft.DSC.Debug/Echo
+              properties:
+                output: '[parameters(''name'')]'
+"@
+        $params_json = @{ parameters = @{ user = 'admin' }} | ConvertTo-Json
+        $null = $config_yaml | dsc config -p $params_json get 2>$TestDrive/error.log
+        $LASTEXITCODE | Should -Be 4
+        (Get-Content $TestDrive/error.log -Raw).Contains('Missing value for required parameters: name, zone') | Should -BeTrue
+
+        $null = $config_yaml | dsc config get 2>$TestDrive/error.log
+        $LASTEXITCODE | Should -Be 4
+        (Get-Content $TestDrive/error.log -Raw).Contains('Missing value for required parameters: name, user, zone') | Should -BeTrue
+    }
+
     It 'Length constraint is incorrectly applied to <type> with <constraint>' -TestCases @(
         @{ type = 'int'; value = 42; constraint = 'minLength' }
         @{ type = 'int'; value = 42; constraint = 'maxLength' }
@@ -277,8 +366,204 @@ Describe 'Parameters tests' {
     It 'secure types can be passed as objects to resources' {
       $out = dsc config -f $PSScriptRoot/../examples/secure_parameters.parameters.yaml get -p $PSScriptRoot/../examples/secure_parameters.dsc.yaml | ConvertFrom-Json
       $LASTEXITCODE | Should -Be 0
-      $out.results[0].result.actualState.output | Should -BeExactly 'mySecret'
-      $out.results[1].result.actualState.output | Should -BeExactly 'mySecretProperty'
+      # the values are returned by the resource, but masked in the results
+      $out.results[0].result.actualState.output | Should -BeExactly '<redacted>'
+      $out.results[1].result.actualState.output | Should -BeExactly '<redacted>'
+    }
+
+    It 'secure values and values derived from them are masked in traces and results' {
+      $config_yaml = @'
+        $schema: https://raw.githubusercontent.com/PowerShell/DSC/main/schemas/2024/04/config/document.json
+        parameters:
+          password:
+            type: secureString
+          user:
+            type: string
+        variables:
+          encoded: "[base64(parameters('password'))]"
+        resources:
+        - name: Echo
+          type: Microsoft.DSC.Debug/Echo
+          properties:
+            output:
+              user: "[parameters('user')]"
+              login: "[concat(parameters('user'), ':', parameters('password'))]"
+              encoded: "[variables('encoded')]"
+              matches: "[if(equals(parameters('password'), 'admin'), 'matched password', 'no')]"
+'@
+      $params = '{"parameters":{"password":"hunter2secret","user":"admin"}}'
+      $out = dsc -l trace config -p $params get -d $config_yaml 2>$TestDrive/trace.log | ConvertFrom-Json
+      $LASTEXITCODE | Should -Be 0
+      $out.results[0].result.actualState.output.user | Should -BeExactly 'admin'
+      $out.results[0].result.actualState.output.login | Should -BeExactly '<redacted>'
+      $out.results[0].result.actualState.output.encoded | Should -BeExactly '<redacted>'
+      # short values derived from a secure value aren't masked
+      $out.results[0].result.actualState.output.matches | Should -BeExactly 'no'
+      $log = Get-Content $TestDrive/trace.log -Raw
+      $log | Should -Not -BeLike '*hunter2secret*'
+      $log | Should -Not -BeLike '*aHVudGVyMnNlY3JldA==*'
+      $log | Should -BeLike '*<redacted>*'
+    }
+
+    It 'encrypted values in parameter files are decrypted and treated as secure' {
+      $oldKeyFile = $env:DSC_KEY_FILE
+      try {
+        $env:DSC_KEY_FILE = "$TestDrive/key.txt"
+        $publicKey = dsc parameters new-key
+        $LASTEXITCODE | Should -Be 0
+        $publicKey | Should -BeLike 'age1*'
+
+        $params = '{"parameters":{"password":"hunter2encrypted","user":"admin"}}'
+        dsc parameters encrypt -d $params -n password -f json > $TestDrive/params.json
+        $LASTEXITCODE | Should -Be 0
+        $encrypted = Get-Content $TestDrive/params.json -Raw | ConvertFrom-Json
+        $encrypted.parameters.user | Should -BeExactly 'admin'
+        $encrypted.parameters.password | Should -BeLike 'ENC`[AGE,*'
+
+        $decrypted = dsc parameters decrypt -p $TestDrive/params.json -f json | ConvertFrom-Json
+        $decrypted.parameters.password | Should -BeExactly 'hunter2encrypted'
+
+        $config_yaml = @'
+          $schema: https://raw.githubusercontent.com/PowerShell/DSC/main/schemas/2024/04/config/document.json
+          parameters:
+            password:
+              type: string
+            user:
+              type: string
+          resources:
+          - name: Echo
+            type: Microsoft.DSC.Debug/Echo
+            properties:
+              output:
+                user: "[parameters('user')]"
+                password: "[parameters('password')]"
+'@
+        $out = dsc -l trace config -f $TestDrive/params.json get -d $config_yaml 2>$TestDrive/trace.log | ConvertFrom-Json
+        $LASTEXITCODE | Should -Be 0
+        $out.results[0].result.actualState.output.user | Should -BeExactly 'admin'
+        $out.results[0].result.actualState.output.password | Should -BeExactly '<redacted>'
+        Get-Content $TestDrive/trace.log -Raw | Should -Not -BeLike '*hunter2encrypted*'
+      }
+      finally {
+        $env:DSC_KEY_FILE = $oldKeyFile
+      }
+    }
+
+    It 'encrypted values fail without the key' {
+      $oldKeyFile = $env:DSC_KEY_FILE
+      try {
+        $env:DSC_KEY_FILE = "$TestDrive/other-key.txt"
+        $publicKey = dsc parameters new-key
+        $env:DSC_KEY_FILE = "$TestDrive/missing-key.txt"
+        $params = dsc parameters encrypt -d '{"parameters":{"user":"admin"}}' -r $publicKey -f json
+        $LASTEXITCODE | Should -Be 0
+        $null = dsc parameters decrypt -d ($params | Out-String) 2>$TestDrive/error.log
+        $LASTEXITCODE | Should -Be 2
+        Get-Content $TestDrive/error.log -Raw | Should -BeLike '*missing-key.txt*'
+      }
+      finally {
+        $env:DSC_KEY_FILE = $oldKeyFile
+      }
+    }
+
+    It 'parameters are layered from files, environment variables and overrides' {
+      $config_yaml = @'
+        $schema: https://raw.githubusercontent.com/PowerShell/DSC/main/schemas/2024/04/config/document.json
+        parameters:
+          name:
+            type: string
+          port:
+            type: int
+          enabled:
+            type: bool
+          tags:
+            type: array
+          zone:
+            type: string
+            defaultValue: west
+        resources:
+        - name: Echo
+          type: Microsoft.DSC.Debug/Echo
+          properties:
+            output:
+              name: "[parameters('name')]"
+              port: "[parameters('port')]"
+              enabled: "[parameters('enabled')]"
+              tags: "[parameters('tags')]"
+              zone: "[parameters('zone')]"
+'@
+      Set-Content -Path $TestDrive/base.yaml -Value "parameters: { name: base, port: 80, enabled: false, tags: [a] }"
+      Set-Content -Path $TestDrive/host.yaml -Value "parameters: { port: 8080 }"
+      $oldEnabled = $env:DSC_PARAM_enabled
+      try {
+        $env:DSC_PARAM_enabled = 'true'
+        $out = dsc config -f $TestDrive/base.yaml -f $TestDrive/host.yaml --parameter 'tags=["b","c"]' get -d $config_yaml | ConvertFrom-Json
+        $LASTEXITCODE | Should -Be 0
+      }
+      finally {
+        $env:DSC_PARAM_enabled = $oldEnabled
+      }
+      $output = $out.results[0].result.actualState.output
+      $output.name | Should -BeExactly 'base'
+      $output.port | Should -Be 8080
+      $output.enabled | Should -BeTrue
+      $output.tags | Should -Be @('b', 'c')
+      $output.zone | Should -BeExactly 'west'
+      $sources = $out.metadata.'Microsoft.DSC'.parameterSources
+      $sources.name.file | Should -BeExactly "$TestDrive/base.yaml"
+      $sources.port.file | Should -BeExactly "$TestDrive/host.yaml"
+      $sources.enabled.environment | Should -BeExactly 'DSC_PARAM_enabled'
+      $sources.tags | Should -BeExactly 'commandLine'
+      $sources.zone | Should -BeExactly 'defaultValue'
+    }
+
+    It 'parameters files override the parameters input' {
+      $config_yaml = @'
+        $schema: https://raw.githubusercontent.com/PowerShell/DSC/main/schemas/2024/04/config/document.json
+        parameters:
+          name:
+            type: string
+          port:
+            type: int
+        resources:
+        - name: Echo
+          type: Microsoft.DSC.Debug/Echo
+          properties:
+            output:
+              name: "[parameters('name')]"
+              port: "[parameters('port')]"
+'@
+      Set-Content -Path $TestDrive/host.yaml -Value "parameters: { port: 8080 }"
+      $out = dsc config -p '{"parameters":{"name":"input","port":80}}' -f $TestDrive/host.yaml get -d $config_yaml | ConvertFrom-Json
+      $LASTEXITCODE | Should -Be 0
+      $output = $out.results[0].result.actualState.output
+      $output.name | Should -BeExactly 'input'
+      $output.port | Should -Be 8080
+      $sources = $out.metadata.'Microsoft.DSC'.parameterSources
+      $sources.name | Should -BeExactly 'input'
+      $sources.port.file | Should -BeExactly "$TestDrive/host.yaml"
+    }
+
+    It 'parameter override for <value> fails' -TestCases @(
+      @{ value = 'port' }
+      @{ value = 'port=eighty' }
+      @{ value = 'other=1' }
+    ) {
+      param($value)
+
+      $config_yaml = @'
+        $schema: https://raw.githubusercontent.com/PowerShell/DSC/main/schemas/2024/04/config/document.json
+        parameters:
+          port:
+            type: int
+        resources:
+        - name: Echo
+          type: Microsoft.DSC.Debug/Echo
+          properties:
+            output: "[parameters('port')]"
+'@
+      $null = dsc config --parameter $value get -d $config_yaml 2>$null
+      $LASTEXITCODE | Should -Be 4
     }
 
     It 'parameter types are validated for <type>' -TestCases @(
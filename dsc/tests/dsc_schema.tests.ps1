@@ -36,4 +36,45 @@ Describe 'config schema tests' {
         $schema = $schema | ConvertFrom-Json
         $schema.'$schema' | Should -BeExactly 'http://json-schema.org/draft-07/schema#'
     }
+
+    It 'returns the parameters schema of a configuration' {
+        $config_yaml = @'
+            $schema: https://raw.githubusercontent.com/PowerShell/DSC/main/schemas/2024/04/config/document.json
+            parameters:
+              name:
+                type: string
+                description: The name of the site
+                pattern: '^[a-z]+$'
+              port:
+                type: int
+                minValue: 1
+                maxValue: 65535
+                defaultValue: 80
+              mode:
+                type: string
+                allowedValues: [fast, safe]
+                defaultValue: "[concat('sa', 'fe')]"
+              tags:
+                type: array
+                maxLength: 2
+            resources: []
+'@
+        Set-Content -Path $TestDrive/config.dsc.yaml -Value $config_yaml
+        $schema = dsc schema --parameters-for $TestDrive/config.dsc.yaml
+        $LASTEXITCODE | Should -Be 0
+        $out = $schema | ConvertFrom-Json
+        $out.properties.parameters.required | Should -Be @('name', 'tags')
+        $out.properties.parameters.properties.name.description | Should -BeExactly 'The name of the site'
+        $out.properties.parameters.properties.name.anyOf[0].pattern | Should -BeExactly '^[a-z]+$'
+        $out.properties.parameters.properties.port.default | Should -Be 80
+        $out.properties.parameters.properties.port.anyOf[0].maximum | Should -Be 65535
+        $out.properties.parameters.properties.mode.anyOf[0].enum | Should -Be @('fast', 'safe')
+        $out.properties.parameters.properties.mode.PSObject.Properties.Name | Should -Not -Contain 'default'
+        $out.properties.parameters.properties.tags.anyOf[0].maxItems | Should -Be 2
+
+        $schemaJson = $schema | Out-String
+        '{"parameters":{"name":"web","tags":["a"]}}' | Test-Json -Schema $schemaJson | Should -BeTrue
+        '{"parameters":{"name":"Web","tags":["a"]}}' | Test-Json -Schema $schemaJson -ErrorAction SilentlyContinue | Should -BeFalse
+        '{"parameters":{"name":"web"}}' | Test-Json -Schema $schemaJson -ErrorAction SilentlyContinue | Should -BeFalse
+    }
 }
@@ -0,0 +1,139 @@
+# Copyright (c) Microsoft Corporation.
+# Licensed under the MIT License.
+
+Describe 'tests for the settings file' {
+    BeforeAll {
+        $oldSettingsPath = $env:DSC_SETTINGS_PATH
+        $oldResourcePath = $env:DSC_RESOURCE_PATH
+        $oldTraceLevel = $env:DSC_TRACE_LEVEL
+        $env:DSC_SETTINGS_PATH = Join-Path $TestDrive 'dsc.settings.json'
+
+        $resourceDirectory = Join-Path $TestDrive 'resources'
+        New-Item -ItemType Directory -Path $resourceDirectory | Out-Null
+        $manifest = @'
+        {
+            "$schema": "https://raw.githubusercontent.com/PowerShell/DSC/main/schemas/2024/04/bundled/resource/manifest.json",
+            "type": "Test/SettingsResource",
+            "version": "0.1.0",
+            "get": {
+              "executable": "dsc"
+            }
+        }
+'@
+        Set-Content -Path (Join-Path $resourceDirectory 'test.dsc.resource.json') -Value $manifest
+    }
+
+    BeforeEach {
+        $env:DSC_RESOURCE_PATH = $null
+        $env:DSC_TRACE_LEVEL = $null
+    }
+
+    AfterEach {
+        Remove-Item -Path $env:DSC_SETTINGS_PATH -ErrorAction SilentlyContinue
+    }
+
+    AfterAll {
+        $env:DSC_SETTINGS_PATH = $oldSettingsPath
+        $env:DSC_RESOURCE_PATH = $oldResourcePath
+        $env:DSC_TRACE_LEVEL = $oldTraceLevel
+    }
+
+    It 'resources are found in the directories from the settings' {
+        @{ resourcePath = @{ directories = @($resourceDirectory) } } | ConvertTo-Json -Depth 3 | Set-Content -Path $env:DSC_SETTINGS_PATH
+        $resources = dsc resource list 'Test/SettingsResource' | ConvertFrom-Json
+        $LASTEXITCODE | Should -Be 0
+        $resources.type | Should -BeExactly 'Test/SettingsResource'
+    }
+
+    It 'PATH is not searched when disabled by the settings' {
+        @{ resourcePath = @{ usePath = $false } } | ConvertTo-Json -Depth 3 | Set-Content -Path $env:DSC_SETTINGS_PATH
+        $oldPath = $env:PATH
+        try {
+            $env:PATH = $resourceDirectory + [System.IO.Path]::PathSeparator + $env:PATH
+            $resources = dsc resource list 'Test/SettingsResource' | ConvertFrom-Json
+            $LASTEXITCODE | Should -Be 0
+            $resources | Should -BeNullOrEmpty
+        }
+        finally {
+            $env:PATH = $oldPath
+        }
+    }
+
+    It 'DSC_RESOURCE_PATH is ignored when not allowed by the settings' {
+        @{ resourcePath = @{ directories = @($resourceDirectory); allowEnvOverride = $false } } | ConvertTo-Json -Depth 3 | Set-Content -Path $env:DSC_SETTINGS_PATH
+        $env:DSC_RESOURCE_PATH = Join-Path $TestDrive 'empty'
+        $resources = dsc resource list 'Test/SettingsResource' 2> $TestDrive/error.txt | ConvertFrom-Json
+        $LASTEXITCODE | Should -Be 0
+        $resources.type | Should -BeExactly 'Test/SettingsResource'
+        "$TestDrive/error.txt" | Should -FileContentMatch 'DSC_RESOURCE_PATH is ignored'
+    }
+
+    It 'trace level and format are used from the settings' {
+        @{ tracing = @{ level = 'debug'; format = 'json' } } | ConvertTo-Json -Depth 3 | Set-Content -Path $env:DSC_SETTINGS_PATH
+        $null = dsc resource list 'Test/SettingsResource' 2> $TestDrive/tracing.txt
+        $trace = Get-Content -Path $TestDrive/tracing.txt | Select-Object -First 1 | ConvertFrom-Json
+        $trace.level | Should -BeExactly 'DEBUG'
+    }
+
+    It 'trace level on the command line takes precedence over the settings' {
+        @{ tracing = @{ level = 'debug' } } | ConvertTo-Json -Depth 3 | Set-Content -Path $env:DSC_SETTINGS_PATH
+        $null = dsc -l error resource list 'Test/SettingsResource' 2> $TestDrive/tracing.txt
+        "$TestDrive/tracing.txt" | Should -Not -FileContentMatch 'DEBUG'
+    }
+
+    It 'adapter lookup table is saved to the location from the settings' {
+        $lookupTablePath = Join-Path $TestDrive 'lookup.json'
+        @{ adapterLookupTable = $lookupTablePath } | ConvertTo-Json -Depth 3 | Set-Content -Path $env:DSC_SETTINGS_PATH
+        $null = dsc resource list -a Test/TestGroup
+        $LASTEXITCODE | Should -Be 0
+        Test-Path $lookupTablePath -PathType Leaf | Should -BeTrue
+    }
+
+    It 'invalid settings file is an error' {
+        @{ resourcePaths = @{} } | ConvertTo-Json -Depth 3 | Set-Content -Path $env:DSC_SETTINGS_PATH
+        $null = dsc resource list 2> $TestDrive/error.txt
+        $LASTEXITCODE | Should -Be 2
+        "$TestDrive/error.txt" | Should -FileContentMatch 'Setting:'
+    }
+
+    It 'resource is not invoked when blocked by the policy' {
+        @{ resourcePath = @{ directories = @($resourceDirectory) }; policy = @{ blockedResources = @('test/settings*') } } | ConvertTo-Json -Depth 3 | Set-Content -Path $env:DSC_SETTINGS_PATH
+        $null = '{}' | dsc resource get -r 'Test/SettingsResource' 2> $TestDrive/error.txt
+        $LASTEXITCODE | Should -Be 2
+        "$TestDrive/error.txt" | Should -FileContentMatch "Policy: Resource 'Test/SettingsResource' is not allowed, the resource type is blocked"
+
+        $resources = dsc resource list 'Test/SettingsResource' | ConvertFrom-Json
+        $resources | Should -BeNullOrEmpty
+    }
+
+    It 'resource is only allowed when it matches the allowed resources' {
+        @{ policy = @{ allowedResources = @('Microsoft.DSC/*') } } | ConvertTo-Json -Depth 3 | Set-Content -Path $env:DSC_SETTINGS_PATH
+        $config_yaml = @"
+            `$schema: https://raw.githubusercontent.com/PowerShell/DSC/main/schemas/2024/04/config/document.json
+            resources:
+            - name: os
+              type: Microsoft/OSInfo
+              properties: {}
+"@
+        $null = $config_yaml | dsc config get 2> $TestDrive/error.txt
+        $LASTEXITCODE | Should -Be 2
+        "$TestDrive/error.txt" | Should -FileContentMatch "Policy: Resource 'Microsoft/OSInfo' is not allowed"
+    }
+
+    It 'manifest must be in the directories from the policy' {
+        $policy = @{ manifestDirectories = @($resourceDirectory) }
+        @{ resourcePath = @{ directories = @($resourceDirectory) }; policy = $policy } | ConvertTo-Json -Depth 3 | Set-Content -Path $env:DSC_SETTINGS_PATH
+        $resources = dsc resource list 'Test/SettingsResource' | ConvertFrom-Json
+        $resources.type | Should -BeExactly 'Test/SettingsResource'
+
+        $null = '{}' | dsc resource get -r 'Microsoft/OSInfo' 2> $TestDrive/error.txt
+        $LASTEXITCODE | Should -Be 2
+        "$TestDrive/error.txt" | Should -FileContentMatch 'is not in an allowed directory'
+    }
+
+    It 'adapted resources are blocked with their adapter' {
+        @{ policy = @{ blockedAdapters = @('Test/TestGroup') } } | ConvertTo-Json -Depth 3 | Set-Content -Path $env:DSC_SETTINGS_PATH
+        $resources = dsc resource list -a Test/TestGroup | ConvertFrom-Json
+        $resources | Should -BeNullOrEmpty
+    }
+}
@@ -1,6 +1,7 @@
 // Copyright (c) Microsoft Corporation.
 // Licensed under the MIT License.
 
+use crate::configure::parameters::ParameterSource;
 use schemars::JsonSchema;
 use serde::{Deserialize, Serialize};
 use serde_json::{Map, Value};
@@ -59,6 +60,9 @@ pub struct MicrosoftDscMetadata {
     /// Identifies if the operation is part of a configuration
     #[serde(skip_serializing_if = "Option::is_none")]
     pub context: Option<ContextKind>,
+    /// Where the value of each parameter came from
+    #[serde(rename = "parameterSources", skip_serializing_if = "Option::is_none")]
+    pub parameter_sources: Option<HashMap<String, ParameterSource>>,
 }
 
 #[derive(Debug, Clone, PartialEq, Deserialize, Serialize, JsonSchema)]
@@ -77,6 +81,8 @@ pub struct Configuration {
     pub parameters: Option<HashMap<String, Parameter>>,
     #[serde(skip_serializing_if = "Option::is_none")]
     pub variables: Option<HashMap<String, Value>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub functions: Option<Vec<UserFunctionNamespace>>,
     pub resources: Vec<Resource>,
     #[serde(skip_serializing_if = "Option::is_none")]
     pub metadata: Option<Metadata>,
@@ -98,12 +104,51 @@ pub struct Parameter {
     pub min_length: Option<i64>,
     #[serde(rename = "maxLength", skip_serializing_if = "Option::is_none")]
     pub max_length: Option<i64>,
+    /// A regular expression that a string value must match.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub pattern: Option<String>,
+    /// A JSON schema that an object value must be valid against.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub schema: Option<Value>,
     #[serde(skip_serializing_if = "Option::is_none")]
     pub description: Option<String>,
     #[serde(skip_serializing_if = "Option::is_none")]
     pub metadata: Option<HashMap<String, Value>>,
 }
 
+#[derive(Debug, Clone, PartialEq, Deserialize, Serialize, JsonSchema)]
+#[serde(deny_unknown_fields)]
+pub struct UserFunctionNamespace {
+    /// The namespace used to call the functions, like `contoso` for `[contoso.path()]`
+    pub namespace: String,
+    pub members: HashMap<String, UserFunctionDefinition>,
+}
+
+#[derive(Debug, Clone, PartialEq, Deserialize, Serialize, JsonSchema)]
+#[serde(deny_unknown_fields)]
+pub struct UserFunctionDefinition {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub parameters: Option<Vec<UserFunctionParameter>>,
+    pub output: UserFunctionOutput,
+}
+
+#[derive(Debug, Clone, PartialEq, Deserialize, Serialize, JsonSchema)]
+#[serde(deny_unknown_fields)]
+pub struct UserFunctionParameter {
+    pub name: String,
+    #[serde(rename = "type")]
+    pub parameter_type: DataType,
+}
+
+#[derive(Debug, Clone, PartialEq, Deserialize, Serialize, JsonSchema)]
+#[serde(deny_unknown_fields)]
+pub struct UserFunctionOutput {
+    #[serde(rename = "type")]
+    pub output_type: DataType,
+    /// The output value, which can be an expression using the `parameters()` of the function
+    pub value: Value,
+}
+
 #[derive(Debug, Clone, PartialEq, Deserialize, Serialize, JsonSchema)]
 pub enum DataType {
     #[serde(rename = "string")]
@@ -132,6 +177,9 @@ pub struct Resource {
     #[serde(rename = "dependsOn", skip_serializing_if = "Option::is_none")]
     #[schemars(regex(pattern = r"^\[resourceId\(\s*'[a-zA-Z0-9\.]+/[a-zA-Z0-9]+'\s*,\s*'[a-zA-Z0-9 ]+'\s*\)]$"))]
     pub depends_on: Option<Vec<String>>,
+    /// The semantic version requirement for the resource, the newest version that satisfies it is used
+    #[serde(rename = "requireVersion", skip_serializing_if = "Option::is_none")]
+    pub require_version: Option<String>,
     #[serde(skip_serializing_if = "Option::is_none")]
     pub properties: Option<Map<String, Value>>,
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -168,6 +216,7 @@ impl Default for Configuration {
             schema: DocumentSchemaUri::Version2024_04,
             parameters: None,
             variables: None,
+            functions: None,
             resources: Vec::new(),
             metadata: None,
         }
@@ -181,6 +230,7 @@ impl Configuration {
             schema: DocumentSchemaUri::Version2024_04,
             parameters: None,
             variables: None,
+            functions: None,
             resources: Vec::new(),
             metadata: None,
         }
@@ -194,6 +244,7 @@ impl Resource {
             resource_type: String::new(),
             name: String::new(),
             depends_on: None,
+            require_version: None,
             properties: None,
             metadata: None,
         }
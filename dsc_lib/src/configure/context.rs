@@ -2,27 +2,40 @@
 // Licensed under the MIT License.
 
 use chrono::{DateTime, Local};
-use crate::configure::config_doc::ExecutionKind;
+use crate::configure::config_doc::{ExecutionKind, Operation};
 use security_context_lib::{get_security_context, SecurityContext};
 use serde_json::Value;
+use std::cell::RefCell;
 use std::collections::HashMap;
+use std::path::PathBuf;
 
 use super::config_doc::{DataType, SecurityContextKind};
 
+/// The environment variable set to the directory of the configuration document.
+pub const DSC_CONFIG_ROOT: &str = "DSC_CONFIG_ROOT";
+
+#[derive(Clone)]
 pub struct Context {
+    pub config_root: Option<PathBuf>, // this is used to resolve relative paths of functions like `loadTextContent()`
     pub execution_type: ExecutionKind,
+    pub lambda_variables: RefCell<HashMap<String, Value>>, // this is used by the `lambdaVariables()` function within a lambda
+    pub operation: Option<Operation>, // this is used by the `context()` function and is not set until an operation starts
     pub outputs: HashMap<String, Value>, // this is used by the `reference()` function to retrieve output
     pub parameters: HashMap<String, (Value, DataType)>,
     pub security_context: SecurityContextKind,
     pub variables: HashMap<String, Value>,
     pub start_datetime: DateTime<Local>,
+    pub user_function_stack: Vec<String>, // this is used to detect recursive calls of user functions
 }
 
 impl Context {
     #[must_use]
     pub fn new() -> Self {
         Self {
+            config_root: std::env::var(DSC_CONFIG_ROOT).ok().map(PathBuf::from),
             execution_type: ExecutionKind::Actual,
+            lambda_variables: RefCell::new(HashMap::new()),
+            operation: None,
             outputs: HashMap::new(),
             parameters: HashMap::new(),
             security_context: match get_security_context() {
@@ -31,6 +44,7 @@ impl Context {
             },
             variables: HashMap::new(),
             start_datetime: chrono::Local::now(),
+            user_function_stack: Vec::new(),
         }
     }
 }
@@ -3,7 +3,11 @@
 
 use crate::configure::config_doc::Parameter;
 use crate::DscError;
+use jsonschema::Validator;
+use regex::Regex;
 use serde_json::Value;
+use std::collections::HashMap;
+use std::hash::BuildHasher;
 
 /// Checks that the given value matches the given parameter length constraints.
 ///
@@ -167,4 +171,113 @@ pub fn check_allowed_values(name: &str, value: &Value, constraint: &Parameter) -
     Ok(())
 }
 
+/// Checks that the given value matches the given pattern constraint.
+///
+/// # Arguments
+///
+/// * `name` - The name of the parameter.
+/// * `value` - The value of the parameter.
+/// * `constraint` - The constraints on the parameter.
+///
+/// # Returns
+///
+/// * `Ok(())` if the value matches the constraints.
+/// * `Err(DscError::Validation)` if the value does not match the constraints.
+///
+/// # Errors
+///
+/// * `DscError::Validation` if the value does not match the constraints or the pattern is invalid.
+pub fn check_pattern(name: &str, value: &Value, constraint: &Parameter) -> Result<(), DscError> {
+    if let Some(pattern) = &constraint.pattern {
+        let Some(value) = value.as_str() else {
+            return Err(DscError::Validation(format!("Parameter '{name}' has pattern constraint but is not a string")));
+        };
+
+        let regex = match Regex::new(pattern) {
+            Ok(regex) => regex,
+            Err(err) => {
+                return Err(DscError::Validation(format!("Parameter '{name}' has invalid pattern '{pattern}': {err}")));
+            }
+        };
+        // the value isn't part of the message as it could be secure
+        if !regex.is_match(value) {
+            return Err(DscError::Validation(format!("Parameter '{name}' does not match pattern '{pattern}'")));
+        }
+    }
+
+    Ok(())
+}
+
+/// Checks that the given value is valid against the given schema constraint.
+///
+/// # Arguments
+///
+/// * `name` - The name of the parameter.
+/// * `value` - The value of the parameter.
+/// * `constraint` - The constraints on the parameter.
+///
+/// # Returns
+///
+/// * `Ok(())` if the value matches the constraints.
+/// * `Err(DscError::Validation)` if the value does not match the constraints.
+///
+/// # Errors
+///
+/// * `DscError::Validation` if the value does not match the constraints or the schema is invalid.
+pub fn check_schema(name: &str, value: &Value, constraint: &Parameter) -> Result<(), DscError> {
+    if let Some(schema) = &constraint.schema {
+        if !value.is_object() {
+            return Err(DscError::Validation(format!("Parameter '{name}' has schema constraint but is not an object")));
+        }
+
+        let validator = match Validator::new(schema) {
+            Ok(validator) => validator,
+            Err(err) => {
+                return Err(DscError::Validation(format!("Parameter '{name}' has invalid schema: {err}")));
+            }
+        };
+        let locations = match validator.validate(value) {
+            // only the location is reported as the message can contain the value which could be secure
+            Err(errors) => errors.map(|err| {
+                let location = err.instance_path.to_string();
+                if location.is_empty() { "/".to_string() } else { location }
+            }).collect::<Vec<String>>(),
+            Ok(()) => Vec::new(),
+        };
+        if !locations.is_empty() {
+            return Err(DscError::Validation(format!("Parameter '{name}' is not valid against its schema at '{0}'", locations.join("', '"))));
+        }
+    }
+
+    Ok(())
+}
+
+/// Checks that all parameters without a default value have a value.
+///
+/// # Arguments
+///
+/// * `parameters` - The parameters defined in the configuration.
+/// * `is_set` - Returns if the parameter with the given name has a value.
+///
+/// # Returns
+///
+/// * `Ok(())` if all parameters have a value.
+/// * `Err(DscError::Validation)` with all parameters that are missing a value.
+///
+/// # Errors
+///
+/// * `DscError::Validation` if any parameter is missing a value.
+pub fn check_required<S: BuildHasher>(parameters: &HashMap<String, Parameter, S>, is_set: impl Fn(&str) -> bool) -> Result<(), DscError> {
+    let mut missing = parameters.iter()
+        .filter(|(name, parameter)| parameter.default_value.is_none() && !is_set(name.as_str()))
+        .map(|(name, _)| name.as_str())
+        .collect::<Vec<&str>>();
+    if missing.is_empty() {
+        return Ok(());
+    }
+
+    missing.sort_unstable();
+    Err(DscError::Validation(format!("Missing value for required parameters: {0}", missing.join(", "))))
+}
+
 // TODO: check nullable
@@ -32,52 +32,38 @@ pub fn get_resource_invocation_order(config: &Configuration, parser: &mut Statem
             return Err(DscError::Validation(format!("Resource named '{0}' is specified more than once in the configuration", resource.name)));
         }
 
+        // each dependency is only evaluated once as it's needed again for the circular dependency check
+        let dependencies = get_dependencies(resource, parser, context)?;
         let mut dependency_already_in_order = true;
-        if let Some(depends_on) = resource.depends_on.clone() {
-            for dependency in depends_on {
-                let statement = parser.parse_and_execute(&dependency, context)?;
-                let Some(string_result) = statement.as_str() else {
-                    return Err(DscError::Validation(format!("'dependsOn' syntax is incorrect: {dependency}")));
-                };
-                let (resource_type, resource_name) = get_type_and_name(string_result)?;
-
-                // find the resource by name
-                let Some(dependency_resource) = config.resources.iter().find(|r| r.name.eq(resource_name)) else {
-                    return Err(DscError::Validation(format!("'dependsOn' resource name '{resource_name}' does not exist for resource named '{0}'", resource.name)));
-                };
-                // validate the type matches
-                if dependency_resource.resource_type != resource_type {
-                    return Err(DscError::Validation(format!("'dependsOn' resource type '{resource_type}' does not match resource type '{0}' for resource named '{1}'", dependency_resource.resource_type, dependency_resource.name)));
-                }
-                // see if the dependency is already in the order
-                if order.iter().any(|r| r.name == resource_name && r.resource_type == resource_type) {
-                    continue;
-                }
-                // add the dependency to the order
-                order.push(dependency_resource.clone());
-                dependency_already_in_order = false;
+        for (resource_type, resource_name) in &dependencies {
+            // find the resource by name
+            let Some(dependency_resource) = config.resources.iter().find(|r| r.name.eq(resource_name)) else {
+                return Err(DscError::Validation(format!("'dependsOn' resource name '{resource_name}' does not exist for resource named '{0}'", resource.name)));
+            };
+            // validate the type matches
+            if dependency_resource.resource_type != *resource_type {
+                return Err(DscError::Validation(format!("'dependsOn' resource type '{resource_type}' does not match resource type '{0}' for resource named '{1}'", dependency_resource.resource_type, dependency_resource.name)));
+            }
+            // see if the dependency is already in the order
+            if order.iter().any(|r| r.name == *resource_name && r.resource_type == *resource_type) {
+                continue;
             }
+            // add the dependency to the order
+            order.push(dependency_resource.clone());
+            dependency_already_in_order = false;
         }
 
         // make sure the resource is not already in the order
         if order.iter().any(|r| r.name == resource.name && r.resource_type == resource.resource_type) {
             // if dependencies were already in the order, then this might be a circular dependency
             if dependency_already_in_order {
-                let Some(ref depends_on) = resource.depends_on else {
-                  continue;
-                };
                 // check if the order has resource before its dependencies
                 let resource_index = order.iter().position(|r| r.name == resource.name && r.resource_type == resource.resource_type).ok_or(DscError::Validation("Resource not found in order".to_string()))?;
-                for dependency in depends_on {
-                  let statement = parser.parse_and_execute(dependency, context)?;
-                  let Some(string_result) = statement.as_str() else {
-                      return Err(DscError::Validation(format!("'dependsOn' syntax is incorrect: {dependency}")));
-                  };
-                  let (resource_type, resource_name) = get_type_and_name(string_result)?;
-                  let dependency_index = order.iter().position(|r| r.name == resource_name && r.resource_type == resource_type).ok_or(DscError::Validation("Dependency not found in order".to_string()))?;
-                  if resource_index < dependency_index {
-                      return Err(DscError::Validation(format!("Circular dependency detected for resource named '{0}'", resource.name)));
-                  }
+                for (resource_type, resource_name) in &dependencies {
+                    let dependency_index = order.iter().position(|r| r.name == *resource_name && r.resource_type == *resource_type).ok_or(DscError::Validation("Dependency not found in order".to_string()))?;
+                    if resource_index < dependency_index {
+                        return Err(DscError::Validation(format!("Circular dependency detected for resource named '{0}'", resource.name)));
+                    }
                 }
             }
 
@@ -91,6 +77,24 @@ pub fn get_resource_invocation_order(config: &Configuration, parser: &mut Statem
     Ok(order)
 }
 
+// evaluates the `dependsOn` entries of a resource to the type and name of each dependency
+fn get_dependencies(resource: &Resource, parser: &mut Statement, context: &Context) -> Result<Vec<(String, String)>, DscError> {
+    let Some(depends_on) = &resource.depends_on else {
+        return Ok(Vec::new());
+    };
+    let mut dependencies = Vec::new();
+    for dependency in depends_on {
+        let statement = parser.parse_and_execute(dependency, context)
+            .map_err(|err| err.with_location(&format!("'dependsOn' of resource '{0}'", resource.name)))?;
+        let Some(string_result) = statement.as_str() else {
+            return Err(DscError::Validation(format!("'dependsOn' syntax is incorrect: {dependency}")));
+        };
+        let (resource_type, resource_name) = get_type_and_name(string_result)?;
+        dependencies.push((resource_type.to_string(), resource_name.to_string()));
+    }
+    Ok(dependencies)
+}
+
 fn get_type_and_name(statement: &str) -> Result<(&str, &str), DscError> {
     let parts: Vec<&str> = statement.split(':').collect();
     if parts.len() != 2 {
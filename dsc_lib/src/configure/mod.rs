@@ -2,7 +2,7 @@
 // Licensed under the MIT License.
 
 use crate::configure::config_doc::{ExecutionKind, Metadata};
-use crate::configure::parameters::Input;
+use crate::configure::parameters::{decrypt_parameters, Input, ParameterSource};
 use crate::dscerror::DscError;
 use crate::dscresources::{
     {dscresource::{Capability, Invoke, get_diff}, invoke_result::{SetResult, ResourceSetResponse}},
@@ -10,17 +10,19 @@ use crate::dscresources::{
     resource_manifest::Kind,
 };
 use crate::DscResource;
-use crate::discovery::Discovery;
-use crate::parser::Statement;
+use crate::discovery::{get_resource_filter, parse_resource_filter, Discovery};
+use crate::parser::{Statement, ValidationScope};
+use crate::settings::DscSettings;
 use self::context::Context;
-use self::config_doc::{Configuration, DataType, MicrosoftDscMetadata, Operation, SecurityContextKind};
+use self::config_doc::{Configuration, DataType, MicrosoftDscMetadata, Operation, Parameter, SecurityContextKind};
 use self::depends_on::get_resource_invocation_order;
 use self::config_result::{ConfigurationExportResult, ConfigurationGetResult, ConfigurationSetResult, ConfigurationTestResult};
-use self::contraints::{check_length, check_number_limits, check_allowed_values};
+use self::contraints::{check_length, check_number_limits, check_allowed_values, check_pattern, check_required, check_schema};
+use self::redaction::{add_derived_value, add_secure_value, redact, redact_error, redact_result};
 use indicatif::ProgressStyle;
 use security_context_lib::{SecurityContext, get_security_context};
 use serde_json::{Map, Value};
-use std::{collections::HashMap, mem};
+use std::{collections::{HashMap, HashSet}, mem};
 use tracing::{debug, info, trace, warn_span, Span};
 use tracing_indicatif::span_ext::IndicatifSpanExt;
 pub mod context;
@@ -29,6 +31,7 @@ pub mod config_result;
 pub mod contraints;
 pub mod depends_on;
 pub mod parameters;
+pub mod redaction;
 
 pub struct Configurator {
     json: String,
@@ -36,6 +39,10 @@ pub struct Configurator {
     pub context: Context,
     discovery: Discovery,
     statement_parser: Statement,
+    // parameters and variables whose values are secure or derived from secure values
+    secure_parameters: HashSet<String>,
+    secure_variables: HashSet<String>,
+    parameter_sources: HashMap<String, ParameterSource>,
 }
 
 /// Add the results of an export operation to a configuration.
@@ -143,6 +150,10 @@ fn get_progress_bar_span(len: u64) -> Result<Span, DscError> {
     Ok(pb_span)
 }
 
+fn is_secure_type(data_type: &DataType) -> bool {
+    matches!(data_type, DataType::SecureString | DataType::SecureObject)
+}
+
 fn add_metadata(kind: &Kind, mut properties: Option<Map<String, Value>> ) -> Result<String, DscError> {
     if *kind == Kind::Adapter {
         // add metadata to the properties so the adapter knows this is a config
@@ -197,18 +208,22 @@ impl Configurator {
     /// # Arguments
     ///
     /// * `config` - The configuration to use in JSON.
+    /// * `settings` - The settings used to find resources.
     ///
     /// # Errors
     ///
     /// This function will return an error if the configuration is invalid or the underlying discovery fails.
-    pub fn new(json: &str) -> Result<Configurator, DscError> {
-        let discovery = Discovery::new()?;
+    pub fn new(json: &str, settings: &DscSettings) -> Result<Configurator, DscError> {
+        let discovery = Discovery::new(settings);
         let mut config = Configurator {
             json: json.to_owned(),
             config: Configuration::new(),
             context: Context::new(),
             discovery,
             statement_parser: Statement::new()?,
+            secure_parameters: HashSet::new(),
+            secure_variables: HashSet::new(),
+            parameter_sources: HashMap::new(),
         };
         config.validate_config()?;
         Ok(config)
@@ -224,6 +239,15 @@ impl Configurator {
         &self.config
     }
 
+    /// Set where the values of the parameters input came from, which is included in the result metadata.
+    ///
+    /// # Arguments
+    ///
+    /// * `sources` - The source of each parameter value, parameters not included are from the parameters input.
+    pub fn set_parameter_sources(&mut self, sources: &HashMap<String, ParameterSource>) {
+        self.parameter_sources.clone_from(sources);
+    }
+
     /// Invoke the get operation on a resource.
     ///
     /// # Returns
@@ -235,21 +259,21 @@ impl Configurator {
     /// This function will return an error if the underlying resource fails.
     pub fn invoke_get(&mut self) -> Result<ConfigurationGetResult, DscError> {
         let mut result = ConfigurationGetResult::new();
+        self.context.operation = Some(Operation::Get);
         let resources = get_resource_invocation_order(&self.config, &mut self.statement_parser, &self.context)?;
         let pb_span = get_progress_bar_span(resources.len() as u64)?;
         let pb_span_enter = pb_span.enter();
         for resource in resources {
             Span::current().pb_inc(1);
             pb_span.pb_set_message(format!("Get '{}'", resource.name).as_str());
-            let properties = self.invoke_property_expressions(&resource.properties)?;
-            let Some(dsc_resource) = self.discovery.find_resource(&resource.resource_type) else {
-                return Err(DscError::ResourceNotFound(resource.resource_type));
-            };
+            let properties = self.invoke_property_expressions(&resource.name, "", &resource.properties)?;
+            let resource_filter = get_resource_filter(&resource.resource_type, resource.require_version.as_deref());
+            let dsc_resource = self.discovery.get_resource(&resource_filter)?;
             debug!("resource_type {}", &resource.resource_type);
             let filter = add_metadata(&dsc_resource.kind, properties)?;
-            trace!("filter: {filter}");
+            trace!("filter: {0}", redact(&filter));
             let start_datetime = chrono::Local::now();
-            let get_result = dsc_resource.get(&filter)?;
+            let get_result = dsc_resource.get(&filter).map_err(redact_error)?;
             let end_datetime = chrono::Local::now();
             self.context.outputs.insert(format!("{}:{}", resource.resource_type, resource.name), serde_json::to_value(&get_result)?);
             let resource_result = config_result::ResourceGetResult {
@@ -265,7 +289,7 @@ impl Configurator {
                 ),
                 name: resource.name.clone(),
                 resource_type: resource.resource_type.clone(),
-                result: get_result,
+                result: redact_result(get_result)?,
             };
             result.results.push(resource_result);
         }
@@ -293,16 +317,16 @@ impl Configurator {
     /// This function will return an error if the underlying resource fails.
     pub fn invoke_set(&mut self, skip_test: bool) -> Result<ConfigurationSetResult, DscError> {
         let mut result = ConfigurationSetResult::new();
+        self.context.operation = Some(Operation::Set);
         let resources = get_resource_invocation_order(&self.config, &mut self.statement_parser, &self.context)?;
         let pb_span = get_progress_bar_span(resources.len() as u64)?;
         let pb_span_enter = pb_span.enter();
         for resource in resources {
             Span::current().pb_inc(1);
             pb_span.pb_set_message(format!("Set '{}'", resource.name).as_str());
-            let properties = self.invoke_property_expressions(&resource.properties)?;
-            let Some(dsc_resource) = self.discovery.find_resource(&resource.resource_type) else {
-                return Err(DscError::ResourceNotFound(resource.resource_type));
-            };
+            let properties = self.invoke_property_expressions(&resource.name, "", &resource.properties)?;
+            let resource_filter = get_resource_filter(&resource.resource_type, resource.require_version.as_deref());
+            let dsc_resource = self.discovery.get_resource(&resource_filter)?;
             debug!("resource_type {}", &resource.resource_type);
 
             // see if the properties contains `_exist` and is false
@@ -320,7 +344,7 @@ impl Configurator {
             };
 
             let desired = add_metadata(&dsc_resource.kind, properties)?;
-            trace!("desired: {desired}");
+            trace!("desired: {0}", redact(&desired));
 
             let start_datetime;
             let end_datetime;
@@ -328,7 +352,7 @@ impl Configurator {
             if exist || dsc_resource.capabilities.contains(&Capability::SetHandlesExist) {
                 debug!("Resource handles _exist or _exist is true");
                 start_datetime = chrono::Local::now();
-                set_result = dsc_resource.set(&desired, skip_test, &self.context.execution_type)?;
+                set_result = dsc_resource.set(&desired, skip_test, &self.context.execution_type).map_err(redact_error)?;
                 end_datetime = chrono::Local::now();
             } else if dsc_resource.capabilities.contains(&Capability::Delete) {
                 if self.context.execution_type == ExecutionKind::WhatIf {
@@ -336,10 +360,10 @@ impl Configurator {
                     return Err(DscError::NotSupported("What-if execution not supported for delete".to_string()));
                 }
                 debug!("Resource implements delete and _exist is false");
-                let before_result = dsc_resource.get(&desired)?;
+                let before_result = dsc_resource.get(&desired).map_err(redact_error)?;
                 start_datetime = chrono::Local::now();
-                dsc_resource.delete(&desired)?;
-                let after_result = dsc_resource.get(&desired)?;
+                dsc_resource.delete(&desired).map_err(redact_error)?;
+                let after_result = dsc_resource.get(&desired).map_err(redact_error)?;
                 // convert get result to set result
                 set_result = match before_result {
                     GetResult::Resource(before_response) => {
@@ -377,7 +401,7 @@ impl Configurator {
                 ),
                 name: resource.name.clone(),
                 resource_type: resource.resource_type.clone(),
-                result: set_result,
+                result: redact_result(set_result)?,
             };
             result.results.push(resource_result);
         }
@@ -401,21 +425,21 @@ impl Configurator {
     /// This function will return an error if the underlying resource fails.
     pub fn invoke_test(&mut self) -> Result<ConfigurationTestResult, DscError> {
         let mut result = ConfigurationTestResult::new();
+        self.context.operation = Some(Operation::Test);
         let resources = get_resource_invocation_order(&self.config, &mut self.statement_parser, &self.context)?;
         let pb_span = get_progress_bar_span(resources.len() as u64)?;
         let pb_span_enter = pb_span.enter();
         for resource in resources {
             Span::current().pb_inc(1);
             pb_span.pb_set_message(format!("Test '{}'", resource.name).as_str());
-            let properties = self.invoke_property_expressions(&resource.properties)?;
-            let Some(dsc_resource) = self.discovery.find_resource(&resource.resource_type) else {
-                return Err(DscError::ResourceNotFound(resource.resource_type));
-            };
+            let properties = self.invoke_property_expressions(&resource.name, "", &resource.properties)?;
+            let resource_filter = get_resource_filter(&resource.resource_type, resource.require_version.as_deref());
+            let dsc_resource = self.discovery.get_resource(&resource_filter)?;
             debug!("resource_type {}", &resource.resource_type);
             let expected = add_metadata(&dsc_resource.kind, properties)?;
-            trace!("expected: {expected}");
+            trace!("expected: {0}", redact(&expected));
             let start_datetime = chrono::Local::now();
-            let test_result = dsc_resource.test(&expected)?;
+            let test_result = dsc_resource.test(&expected).map_err(redact_error)?;
             let end_datetime = chrono::Local::now();
             self.context.outputs.insert(format!("{}:{}", resource.resource_type, resource.name), serde_json::to_value(&test_result)?);
             let resource_result = config_result::ResourceTestResult {
@@ -431,7 +455,7 @@ impl Configurator {
                 ),
                 name: resource.name.clone(),
                 resource_type: resource.resource_type.clone(),
-                result: test_result,
+                result: redact_result(test_result)?,
             };
             result.results.push(resource_result);
         }
@@ -455,6 +479,7 @@ impl Configurator {
     /// This function will return an error if the underlying resource fails.
     pub fn invoke_export(&mut self) -> Result<ConfigurationExportResult, DscError> {
         let mut result = ConfigurationExportResult::new();
+        self.context.operation = Some(Operation::Export);
         let mut conf = config_doc::Configuration::new();
 
         let pb_span = get_progress_bar_span(self.config.resources.len() as u64)?;
@@ -463,22 +488,106 @@ impl Configurator {
         for resource in &resources {
             Span::current().pb_inc(1);
             pb_span.pb_set_message(format!("Export '{}'", resource.name).as_str());
-            let properties = self.invoke_property_expressions(&resource.properties)?;
-            let Some(dsc_resource) = self.discovery.find_resource(&resource.resource_type) else {
-                return Err(DscError::ResourceNotFound(resource.resource_type.clone()));
-            };
+            let properties = self.invoke_property_expressions(&resource.name, "", &resource.properties)?;
+            let resource_filter = get_resource_filter(&resource.resource_type, resource.require_version.as_deref());
+            let dsc_resource = self.discovery.get_resource(&resource_filter)?;
             let input = add_metadata(&dsc_resource.kind, properties)?;
-            trace!("input: {input}");
-            add_resource_export_results_to_configuration(dsc_resource, Some(dsc_resource), &mut conf, input.as_str())?;
+            trace!("input: {0}", redact(&input));
+            add_resource_export_results_to_configuration(dsc_resource, Some(dsc_resource), &mut conf, input.as_str()).map_err(redact_error)?;
         }
 
         conf.metadata = Some(self.get_result_metadata(Operation::Export));
-        result.result = Some(conf);
+        result.result = Some(redact_result(conf)?);
         std::mem::drop(pb_span_enter);
         std::mem::drop(pb_span);
         Ok(result)
     }
 
+    /// Evaluate a statement with the parameters, variables and functions of the configuration.
+    ///
+    /// # Arguments
+    ///
+    /// * `statement` - The statement to evaluate, like `[concat('a', 'b')]`.
+    ///
+    /// # Errors
+    ///
+    /// This function will return an error if the statement fails to parse or execute.
+    pub fn evaluate(&mut self, statement: &str) -> Result<Value, DscError> {
+        self.statement_parser.parse_and_execute(statement, &self.context)
+    }
+
+    /// Check the expressions in parameter default values, variables, user functions, `dependsOn` and resource properties without executing them.
+    ///
+    /// # Returns
+    ///
+    /// * `Vec<DscError>` - The errors found, empty if all expressions are valid.
+    pub fn validate_expressions(&mut self) -> Vec<DscError> {
+        let config = self.config.clone();
+        let scope = ValidationScope {
+            parameters: config.parameters.iter().flat_map(HashMap::keys).cloned().collect(),
+            variables: config.variables.iter().flat_map(HashMap::keys).cloned().collect(),
+            lambda_variables: HashSet::new(),
+        };
+        let mut errors = Vec::new();
+        for (name, parameter) in config.parameters.iter().flatten() {
+            if let Some(statement) = parameter.default_value.as_ref().and_then(Value::as_str) {
+                self.validate_statement(statement, &scope, &format!("default value of parameter '{name}'"), &mut errors);
+            }
+        }
+        for (name, value) in config.variables.iter().flatten() {
+            if let Some(statement) = value.as_str() {
+                self.validate_statement(statement, &scope, &format!("variable '{name}'"), &mut errors);
+            }
+        }
+        for namespace in config.functions.iter().flatten() {
+            for (member, definition) in &namespace.members {
+                let Some(statement) = definition.output.value.as_str() else {
+                    continue;
+                };
+                // the output of a user function can only use its own parameters
+                let function_scope = ValidationScope {
+                    parameters: definition.parameters.iter().flatten().map(|parameter| parameter.name.clone()).collect(),
+                    ..ValidationScope::default()
+                };
+                self.validate_statement(statement, &function_scope, &format!("output of function '{0}.{member}'", namespace.namespace), &mut errors);
+            }
+        }
+        for resource in &config.resources {
+            for dependency in resource.depends_on.iter().flatten() {
+                self.validate_statement(dependency, &scope, &format!("'dependsOn' of resource '{0}'", resource.name), &mut errors);
+            }
+            for (name, value) in resource.properties.iter().flatten() {
+                self.validate_property_expressions(&resource.name, name, value, &scope, &mut errors);
+            }
+        }
+        errors
+    }
+
+    fn validate_property_expressions(&mut self, resource_name: &str, path: &str, value: &Value, scope: &ValidationScope, errors: &mut Vec<DscError>) {
+        match value {
+            Value::Object(object) => {
+                for (name, value) in object {
+                    self.validate_property_expressions(resource_name, &format!("{path}.{name}"), value, scope, errors);
+                }
+            },
+            Value::Array(array) => {
+                for (index, element) in array.iter().enumerate() {
+                    self.validate_property_expressions(resource_name, &format!("{path}[{index}]"), element, scope, errors);
+                }
+            },
+            Value::String(statement) => {
+                self.validate_statement(statement, scope, &format!("resource '{resource_name}' property '{path}'"), errors);
+            },
+            _ => {},
+        }
+    }
+
+    fn validate_statement(&mut self, statement: &str, scope: &ValidationScope, location: &str, errors: &mut Vec<DscError>) {
+        if let Err(err) = self.statement_parser.validate(statement, scope) {
+            errors.push(err.with_location(location));
+        }
+    }
+
     /// Set the parameters and variables context for the configuration.
     ///
     /// # Arguments
@@ -512,7 +621,12 @@ impl Configurator {
                 // default values can be expressions
                 let value = if default_value.is_string() {
                     if let Some(value) = default_value.as_str() {
-                        self.statement_parser.parse_and_execute(value, &self.context)?
+                        let (value, derived_from_secure) = self.evaluate_tracked(value)
+                            .map_err(|err| err.with_location(&format!("default value of parameter '{name}'")))?;
+                        if derived_from_secure {
+                            self.secure_parameters.insert(name.clone());
+                        }
+                        value
                     } else {
                         return Err(DscError::Parser("Default value as string is not defined".to_string()));
                     }
@@ -520,36 +634,54 @@ impl Configurator {
                     default_value.clone()
                 };
                 Configurator::validate_parameter_type(name, &value, &parameter.parameter_type)?;
+                if is_secure_type(&parameter.parameter_type) {
+                    add_secure_value(&value);
+                    self.secure_parameters.insert(name.clone());
+                }
                 self.context.parameters.insert(name.clone(), (value, parameter.parameter_type.clone()));
             }
         }
 
-        let Some(parameters_input) = parameters_input else {
+        if let Some(parameters_input) = parameters_input {
+            self.set_parameters_input(parameters_input, parameters)?;
+        } else {
             debug!("No parameters input");
-            return Ok(());
-        };
+        }
 
-        trace!("parameters_input: {parameters_input}");
-        let parameters: HashMap<String, Value> = serde_json::from_value::<Input>(parameters_input.clone())?.parameters;
-        let Some(parameters_constraints) = &config.parameters else {
-            return Err(DscError::Validation("No parameters defined in configuration".to_string()));
-        };
+        // report all missing parameters at once instead of failing when the first one is used
+        check_required(parameters, |name| self.context.parameters.contains_key(name))
+    }
+
+    fn set_parameters_input(&mut self, parameters_input: &Value, parameters_constraints: &HashMap<String, Parameter>) -> Result<(), DscError> {
+        let mut parameters: HashMap<String, Value> = serde_json::from_value::<Input>(parameters_input.clone())?.parameters;
+        // decrypted values are secure whatever the type of the parameter
+        self.secure_parameters.extend(decrypt_parameters(&mut parameters)?);
+        // secure values are registered before the input is traced so they are masked
+        for (name, value) in &parameters {
+            if parameters_constraints.get(name).is_some_and(|constraint| is_secure_type(&constraint.parameter_type)) {
+                add_secure_value(value);
+                self.secure_parameters.insert(name.clone());
+            }
+        }
+        trace!("parameters_input: {0}", redact(&parameters_input.to_string()));
         for (name, value) in parameters {
             if let Some(constraint) = parameters_constraints.get(&name) {
                 debug!("Validating parameter '{name}'");
                 check_length(&name, &value, constraint)?;
                 check_allowed_values(&name, &value, constraint)?;
                 check_number_limits(&name, &value, constraint)?;
-                // TODO: additional array constraints
-                // TODO: object constraints
+                check_pattern(&name, &value, constraint)?;
+                check_schema(&name, &value, constraint)?;
 
                 Configurator::validate_parameter_type(&name, &value, &constraint.parameter_type)?;
-                if constraint.parameter_type == DataType::SecureString || constraint.parameter_type == DataType::SecureObject {
+                if is_secure_type(&constraint.parameter_type) {
                     info!("Set secure parameter '{name}'");
                 } else {
                     info!("Set parameter '{name}' to '{value}'");
                 }
 
+                let source = self.parameter_sources.entry(name.clone()).or_insert(ParameterSource::Input);
+                debug!("Parameter '{name}' is from {source}");
                 self.context.parameters.insert(name.clone(), (value.clone(), constraint.parameter_type.clone()));
                 // also update the configuration with the parameter value
                 if let Some(parameters) = &mut self.config.parameters {
@@ -573,12 +705,17 @@ impl Configurator {
 
         for (name, value) in variables {
             let new_value = if let Some(string) = value.as_str() {
-                self.statement_parser.parse_and_execute(string, &self.context)?
+                let (new_value, derived_from_secure) = self.evaluate_tracked(string)
+                    .map_err(|err| err.with_location(&format!("variable '{name}'")))?;
+                if derived_from_secure {
+                    self.secure_variables.insert(name.clone());
+                }
+                new_value
             }
             else {
                 value.clone()
             };
-            info!("Set variable '{name}' to '{new_value}'");
+            info!("Set variable '{name}' to '{0}'", redact(&new_value.to_string()));
             self.context.variables.insert(name.to_string(), new_value);
         }
         Ok(())
@@ -597,12 +734,23 @@ impl Configurator {
                     end_datetime: Some(end_datetime.to_rfc3339()),
                     duration: Some(end_datetime.signed_duration_since(self.context.start_datetime).to_string()),
                     security_context: Some(self.context.security_context.clone()),
+                    parameter_sources: self.get_parameter_sources(),
                 }
             )
         }
     }
 
-    fn validate_parameter_type(name: &str, value: &Value, parameter_type: &DataType) -> Result<(), DscError> {
+    fn get_parameter_sources(&self) -> Option<HashMap<String, ParameterSource>> {
+        if self.context.parameters.is_empty() {
+            return None;
+        }
+        // parameters not set by the input use their default value
+        Some(self.context.parameters.keys().map(|name| {
+            (name.clone(), self.parameter_sources.get(name).cloned().unwrap_or(ParameterSource::DefaultValue))
+        }).collect())
+    }
+
+    pub(crate) fn validate_parameter_type(name: &str, value: &Value, parameter_type: &DataType) -> Result<(), DscError> {
         match parameter_type {
             DataType::String | DataType::SecureString => {
                 if !value.is_string() {
@@ -637,15 +785,37 @@ impl Configurator {
     fn validate_config(&mut self) -> Result<(), DscError> {
         let config: Configuration = serde_json::from_str(self.json.as_str())?;
         check_security_context(&config.metadata)?;
+        if let Some(functions) = &config.functions {
+            self.statement_parser.add_user_functions(functions)?;
+        }
 
         // Perform discovery of resources used in config
-        let required_resources = config.resources.iter().map(|p| p.resource_type.clone()).collect::<Vec<String>>();
+        let mut required_resources = Vec::<String>::new();
+        for resource in &config.resources {
+            let resource_filter = get_resource_filter(&resource.resource_type, resource.require_version.as_deref());
+            // an invalid version requirement is reported here instead of as a resource that wasn't found
+            parse_resource_filter(&resource_filter)?;
+            required_resources.push(resource_filter);
+        }
         self.discovery.find_resources(&required_resources);
+        self.statement_parser.add_function_providers(&self.discovery.function_providers);
         self.config = config;
         Ok(())
     }
 
-    fn invoke_property_expressions(&mut self, properties: &Option<Map<String, Value>>) -> Result<Option<Map<String, Value>>, DscError> {
+    // values derived from secrets or secure parameters or variables are registered as secure too, short results
+    // like those of `if()` or `equals()` aren't masked as they would mask unrelated text
+    fn evaluate_tracked(&mut self, statement: &str) -> Result<(Value, bool), DscError> {
+        let parsed_statement = self.statement_parser.compile(statement)?;
+        let value = self.statement_parser.evaluate(&parsed_statement, &self.context)?;
+        let derived_from_secure = parsed_statement.references_secure(&self.secure_parameters, &self.secure_variables);
+        if derived_from_secure {
+            add_derived_value(&value);
+        }
+        Ok((value, derived_from_secure))
+    }
+
+    fn invoke_property_expressions(&mut self, resource_name: &str, parent_path: &str, properties: &Option<Map<String, Value>>) -> Result<Option<Map<String, Value>>, DscError> {
         debug!("Invoke property expressions");
         if properties.is_none() {
             return Ok(None);
@@ -655,18 +825,19 @@ impl Configurator {
         if let Some(properties) = properties {
             for (name, value) in properties {
                 trace!("Invoke property expression for {name}: {value}");
+                let path = if parent_path.is_empty() { name.clone() } else { format!("{parent_path}.{name}") };
                 match value {
                     Value::Object(object) => {
-                        let value = self.invoke_property_expressions(&Some(object.clone()))?;
+                        let value = self.invoke_property_expressions(resource_name, &path, &Some(object.clone()))?;
                         result.insert(name.clone(), serde_json::to_value(value)?);
                         continue;
                     },
                     Value::Array(array) => {
                         let mut result_array: Vec<Value> = Vec::new();
-                        for element in array {
+                        for (index, element) in array.iter().enumerate() {
                             match element {
                                 Value::Object(object) => {
-                                    let value = self.invoke_property_expressions(&Some(object.clone()))?;
+                                    let value = self.invoke_property_expressions(resource_name, &format!("{path}[{index}]"), &Some(object.clone()))?;
                                     result_array.push(serde_json::to_value(value)?);
                                     continue;
                                 },
@@ -678,7 +849,8 @@ impl Configurator {
                                     let Some(statement) = element.as_str() else {
                                         return Err(DscError::Parser("Array element could not be transformed as string".to_string()));
                                     };
-                                    let statement_result = self.statement_parser.parse_and_execute(statement, &self.context)?;
+                                    let (statement_result, _) = self.evaluate_tracked(statement)
+                                        .map_err(|err| err.with_location(&format!("resource '{resource_name}' property '{path}[{index}]'")))?;
                                     let Some(string_result) = statement_result.as_str() else {
                                         return Err(DscError::Parser("Array element could not be transformed as string".to_string()));
                                     };
@@ -696,7 +868,8 @@ impl Configurator {
                         let Some(statement) = value.as_str() else {
                             return Err(DscError::Parser(format!("Property value '{value}' could not be transformed as string")));
                         };
-                        let statement_result = self.statement_parser.parse_and_execute(statement, &self.context)?;
+                        let (statement_result, _) = self.evaluate_tracked(statement)
+                            .map_err(|err| err.with_location(&format!("resource '{resource_name}' property '{path}'")))?;
                         if let Some(string_result) = statement_result.as_str() {
                             result.insert(name.clone(), Value::String(string_result.to_string()));
                         } else {
@@ -1,16 +1,297 @@
 // Copyright (c) Microsoft Corporation.
 // Licensed under the MIT License.
 
+use crate::DscError;
+use crate::configure::config_doc::{DataType, Parameter};
+use crate::configure::redaction::add_secure_value;
+use crate::encryption::{decrypt_value, get_key_file_path, is_encrypted_value, load_identity};
+use crate::util::parse_input_to_json;
+use age::x25519::Identity;
 use schemars::JsonSchema;
 use serde::{Deserialize, Serialize};
-use serde_json::Value;
-use std::collections::HashMap;
+use serde_json::{json, Map, Value};
+use std::collections::{HashMap, HashSet};
+use std::fmt::{Display, Formatter};
+use std::hash::BuildHasher;
+use tracing::debug;
+
+/// The prefix of environment variables that set the value of a parameter, like `DSC_PARAM_port`.
+pub const DSC_PARAM_PREFIX: &str = "DSC_PARAM_";
 
 #[derive(Debug, Clone, PartialEq, Deserialize, Serialize, JsonSchema)]
 pub struct Input {
     pub parameters: HashMap<String, Value>,
 }
 
+/// Where the value of a parameter came from.
+#[derive(Debug, Clone, PartialEq, Eq, Deserialize, Serialize, JsonSchema)]
+#[serde(rename_all = "camelCase")]
+pub enum ParameterSource {
+    /// The default value defined in the configuration.
+    DefaultValue,
+    /// Parameters passed as a document.
+    Input,
+    /// A parameters file with its path.
+    File(String),
+    /// An environment variable with its name.
+    Environment(String),
+    /// A `name=value` override on the command line.
+    CommandLine,
+}
+
+impl Display for ParameterSource {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            ParameterSource::DefaultValue => write!(f, "default value"),
+            ParameterSource::Input => write!(f, "parameters input"),
+            ParameterSource::File(path) => write!(f, "file '{path}'"),
+            ParameterSource::Environment(name) => write!(f, "environment variable '{name}'"),
+            ParameterSource::CommandLine => write!(f, "command line"),
+        }
+    }
+}
+
+/// Parameter values merged from several sources, a value from a later source replaces the value from an earlier one.
+#[derive(Debug, Clone, Default)]
+pub struct ParameterLayers {
+    parameters: Map<String, Value>,
+    sources: HashMap<String, ParameterSource>,
+}
+
+impl ParameterLayers {
+    #[must_use]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Add the parameters of an input like `{"parameters": {"name": "value"}}`.
+    ///
+    /// # Arguments
+    ///
+    /// * `input` - The parameters input.
+    /// * `source` - Where the input came from.
+    ///
+    /// # Errors
+    ///
+    /// This function will return an error if the input doesn't contain parameters.
+    pub fn add_input(&mut self, input: &Value, source: &ParameterSource) -> Result<(), DscError> {
+        let Some(parameters) = input.get("parameters").and_then(Value::as_object) else {
+            return Err(DscError::Validation(format!("Parameters from {source} must be an object with a 'parameters' property")));
+        };
+        for (name, value) in parameters {
+            self.insert(name, value.clone(), source.clone());
+        }
+        Ok(())
+    }
+
+    /// Add a value given as text which is converted to the type of the parameter.
+    ///
+    /// # Arguments
+    ///
+    /// * `name` - The name of the parameter.
+    /// * `text` - The value as text.
+    /// * `parameters` - The parameters defined in the configuration.
+    /// * `source` - Where the value came from.
+    ///
+    /// # Errors
+    ///
+    /// This function will return an error if the parameter isn't defined or the text can't be converted to its type.
+    pub fn add_text(&mut self, name: &str, text: &str, parameters: &HashMap<String, Parameter>, source: ParameterSource) -> Result<(), DscError> {
+        let Some(parameter) = parameters.get(name) else {
+            return Err(DscError::Validation(format!("Parameter '{name}' from {source} not defined in configuration")));
+        };
+        let value = parse_parameter_text(name, text, &parameter.parameter_type)?;
+        self.insert(name, value, source);
+        Ok(())
+    }
+
+    /// Add the values of `DSC_PARAM_<name>` environment variables for the parameters defined in the configuration.
+    ///
+    /// # Arguments
+    ///
+    /// * `parameters` - The parameters defined in the configuration.
+    ///
+    /// # Errors
+    ///
+    /// This function will return an error if a value can't be converted to the type of its parameter.
+    pub fn add_environment(&mut self, parameters: &HashMap<String, Parameter>) -> Result<(), DscError> {
+        // sorted so the values are always added in the same order
+        let mut names = parameters.keys().collect::<Vec<&String>>();
+        names.sort();
+        for name in names {
+            let variable = format!("{DSC_PARAM_PREFIX}{name}");
+            if let Ok(text) = std::env::var(&variable) {
+                self.add_text(name, &text, parameters, ParameterSource::Environment(variable))?;
+            }
+        }
+        Ok(())
+    }
+
+    /// Check if no values were added.
+    #[must_use]
+    pub fn is_empty(&self) -> bool {
+        self.parameters.is_empty()
+    }
+
+    /// Get where the value of each parameter came from.
+    #[must_use]
+    pub fn sources(&self) -> &HashMap<String, ParameterSource> {
+        &self.sources
+    }
+
+    /// Get the merged values as an input like `{"parameters": {"name": "value"}}`.
+    #[must_use]
+    pub fn to_input(&self) -> Value {
+        let mut input = Map::new();
+        input.insert("parameters".to_string(), Value::Object(self.parameters.clone()));
+        Value::Object(input)
+    }
+
+    fn insert(&mut self, name: &str, value: Value, source: ParameterSource) {
+        if let Some(previous) = self.sources.get(name) {
+            debug!("Parameter '{name}' from {previous} replaced by {source}");
+        }
+        self.parameters.insert(name.to_string(), value);
+        self.sources.insert(name.to_string(), source);
+    }
+}
+
+/// Convert the text value of a parameter, like from an environment variable, to the type of the parameter.
+///
+/// # Arguments
+///
+/// * `name` - The name of the parameter.
+/// * `text` - The value as text, arrays and objects are JSON or YAML.
+/// * `parameter_type` - The type of the parameter.
+///
+/// # Errors
+///
+/// This function will return an error if the text can't be converted to the type.
+pub fn parse_parameter_text(name: &str, text: &str, parameter_type: &DataType) -> Result<Value, DscError> {
+    // encrypted values are converted when they are decrypted
+    if is_encrypted_value(text) {
+        return Ok(Value::String(text.to_string()));
+    }
+
+    match parameter_type {
+        DataType::String | DataType::SecureString => Ok(Value::String(text.to_string())),
+        DataType::Int => match text.trim().parse::<i64>() {
+            Ok(value) => Ok(Value::Number(value.into())),
+            Err(_) => Err(DscError::Validation(format!("Parameter '{name}' is not an integer"))),
+        },
+        DataType::Bool => match text.trim().to_lowercase().as_str() {
+            "true" => Ok(Value::Bool(true)),
+            "false" => Ok(Value::Bool(false)),
+            _ => Err(DscError::Validation(format!("Parameter '{name}' is not a boolean"))),
+        },
+        DataType::Array | DataType::Object | DataType::SecureObject => {
+            let json = parse_input_to_json(text).map_err(|err| DscError::Validation(format!("Parameter '{name}' is not valid JSON or YAML: {err}")))?;
+            Ok(serde_json::from_str(&json)?)
+        },
+    }
+}
+
+/// Generate the JSON schema of a parameters file, like `{"parameters": {"name": "value"}}`, for the parameters of a configuration.
+///
+/// # Arguments
+///
+/// * `parameters` - The parameters defined in the configuration.
+#[must_use]
+pub fn get_parameters_schema<S: BuildHasher>(parameters: &HashMap<String, Parameter, S>) -> Value {
+    // sorted so the schema is always the same for a configuration
+    let mut names = parameters.keys().collect::<Vec<&String>>();
+    names.sort();
+    let mut properties = Map::new();
+    let mut required = Vec::<Value>::new();
+    for name in names {
+        let parameter = &parameters[name];
+        properties.insert(name.clone(), get_parameter_schema(parameter));
+        if parameter.default_value.is_none() {
+            required.push(Value::String(name.clone()));
+        }
+    }
+
+    json!({
+        "$schema": "https://json-schema.org/draft/2020-12/schema",
+        "title": "Parameters",
+        "type": "object",
+        "required": ["parameters"],
+        "properties": {
+            "$schema": { "type": "string" },
+            "parameters": {
+                "type": "object",
+                "properties": properties,
+                "required": required,
+                "additionalProperties": false,
+            },
+        },
+        "additionalProperties": false,
+        "$defs": {
+            "encryptedValue": {
+                "description": "A value encrypted with `dsc parameters encrypt`.",
+                "type": "string",
+                "pattern": "^ENC\\[AGE,[A-Za-z0-9+/=]+\\]$",
+            },
+        },
+    })
+}
+
+fn get_parameter_schema(parameter: &Parameter) -> Value {
+    let mut schema = Map::new();
+    let json_type = match parameter.parameter_type {
+        DataType::String | DataType::SecureString => "string",
+        DataType::Int => "integer",
+        DataType::Bool => "boolean",
+        DataType::Array => "array",
+        DataType::Object | DataType::SecureObject => "object",
+    };
+    schema.insert("type".to_string(), Value::String(json_type.to_string()));
+    if let Some(allowed_values) = &parameter.allowed_values {
+        schema.insert("enum".to_string(), Value::Array(allowed_values.clone()));
+    }
+    if let Some(min_value) = parameter.min_value {
+        schema.insert("minimum".to_string(), Value::Number(min_value.into()));
+    }
+    if let Some(max_value) = parameter.max_value {
+        schema.insert("maximum".to_string(), Value::Number(max_value.into()));
+    }
+    let (min_length, max_length) = if parameter.parameter_type == DataType::Array {
+        ("minItems", "maxItems")
+    } else {
+        ("minLength", "maxLength")
+    };
+    if let Some(length) = parameter.min_length {
+        schema.insert(min_length.to_string(), Value::Number(length.into()));
+    }
+    if let Some(length) = parameter.max_length {
+        schema.insert(max_length.to_string(), Value::Number(length.into()));
+    }
+    if let Some(pattern) = &parameter.pattern {
+        schema.insert("pattern".to_string(), Value::String(pattern.clone()));
+    }
+    if let Some(object_schema) = &parameter.schema {
+        schema.insert("allOf".to_string(), Value::Array(vec![object_schema.clone()]));
+    }
+
+    // values can also be encrypted whatever the type of the parameter
+    let mut result = Map::new();
+    if let Some(description) = &parameter.description {
+        result.insert("description".to_string(), Value::String(description.clone()));
+    }
+    if let Some(default_value) = &parameter.default_value {
+        // a default value that is an expression is only known when the configuration runs
+        let is_expression = default_value.as_str().is_some_and(|value| value.starts_with('[') && !value.starts_with("[[") && value.ends_with(']'));
+        if !is_expression {
+            result.insert("default".to_string(), default_value.clone());
+        }
+    }
+    if matches!(parameter.parameter_type, DataType::SecureString | DataType::SecureObject) {
+        result.insert("writeOnly".to_string(), Value::Bool(true));
+    }
+    result.insert("anyOf".to_string(), json!([schema, { "$ref": "#/$defs/encryptedValue" }]));
+    Value::Object(result)
+}
+
 #[derive(Debug, Clone, PartialEq, Deserialize, Serialize, JsonSchema)]
 #[serde(deny_unknown_fields, untagged)]
 pub enum SecureKind {
@@ -19,3 +300,104 @@ pub enum SecureKind {
     #[serde(rename = "secureObject")]
     SecureObject(Value),
 }
+
+/// Decrypt the encrypted parameter values, including those within arrays and objects, decrypted values are registered as secure.
+///
+/// # Arguments
+///
+/// * `parameters` - The parameter values.
+///
+/// # Returns
+///
+/// The names of the parameters that had encrypted values.
+///
+/// # Errors
+///
+/// This function will return an error if the key file can't be loaded or a value fails to decrypt.
+pub fn decrypt_parameters<'a>(parameters: impl IntoIterator<Item = (&'a String, &'a mut Value)>) -> Result<HashSet<String>, DscError> {
+    // the key is only loaded if there are encrypted values
+    let mut identity = None;
+    let mut decrypted = HashSet::new();
+    for (name, value) in parameters {
+        if decrypt_values(value, &mut identity)? {
+            debug!("Decrypted value of parameter '{name}'");
+            decrypted.insert(name.clone());
+        }
+    }
+    Ok(decrypted)
+}
+
+fn decrypt_values(value: &mut Value, identity: &mut Option<Identity>) -> Result<bool, DscError> {
+    match value {
+        Value::String(string) if is_encrypted_value(string) => {
+            if identity.is_none() {
+                *identity = Some(load_identity(&get_key_file_path()?)?);
+            }
+            let Some(identity) = identity.as_ref() else {
+                return Err(DscError::Encryption("Key not loaded".to_string()));
+            };
+            let decrypted = decrypt_value(string, identity)?;
+            add_secure_value(&decrypted);
+            *value = decrypted;
+            Ok(true)
+        },
+        Value::Array(array) => {
+            let mut any_decrypted = false;
+            for element in array {
+                any_decrypted |= decrypt_values(element, identity)?;
+            }
+            Ok(any_decrypted)
+        },
+        Value::Object(object) => {
+            let mut any_decrypted = false;
+            for element in object.values_mut() {
+                any_decrypted |= decrypt_values(element, identity)?;
+            }
+            Ok(any_decrypted)
+        },
+        _ => Ok(false),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn parameter(parameter_type: DataType) -> Parameter {
+        serde_json::from_value(json!({"type": parameter_type})).unwrap()
+    }
+
+    #[test]
+    fn text_is_converted_to_parameter_type() {
+        assert_eq!(parse_parameter_text("p", "42", &DataType::Int).unwrap(), json!(42));
+        assert_eq!(parse_parameter_text("p", "True", &DataType::Bool).unwrap(), json!(true));
+        assert_eq!(parse_parameter_text("p", "[1, 2]", &DataType::Array).unwrap(), json!([1, 2]));
+        assert_eq!(parse_parameter_text("p", "42", &DataType::String).unwrap(), json!("42"));
+        assert!(parse_parameter_text("p", "forty", &DataType::Int).is_err());
+    }
+
+    #[test]
+    fn later_layers_replace_values() {
+        let parameters = HashMap::from([("port".to_string(), parameter(DataType::Int))]);
+        let mut layers = ParameterLayers::new();
+        layers.add_input(&json!({"parameters": {"port": 80}}), &ParameterSource::File("base.yaml".to_string())).unwrap();
+        layers.add_text("port", "8080", &parameters, ParameterSource::CommandLine).unwrap();
+        assert_eq!(layers.to_input(), json!({"parameters": {"port": 8080}}));
+        assert_eq!(layers.sources().get("port"), Some(&ParameterSource::CommandLine));
+        assert!(layers.add_text("other", "1", &parameters, ParameterSource::CommandLine).is_err());
+    }
+
+    #[test]
+    fn parameters_schema_requires_parameters_without_default() {
+        let mut with_default = parameter(DataType::String);
+        with_default.default_value = Some(json!("value"));
+        let parameters = HashMap::from([
+            ("b".to_string(), with_default),
+            ("a".to_string(), parameter(DataType::Array)),
+        ]);
+        let schema = get_parameters_schema(&parameters);
+        assert_eq!(schema["properties"]["parameters"]["required"], json!(["a"]));
+        assert_eq!(schema["properties"]["parameters"]["properties"]["b"]["default"], json!("value"));
+        assert_eq!(schema["properties"]["parameters"]["properties"]["a"]["anyOf"][0]["type"], json!("array"));
+    }
+}
@@ -0,0 +1,217 @@
+// Copyright (c) Microsoft Corporation.
+// Licensed under the MIT License.
+
+use crate::DscError;
+use serde::{de::DeserializeOwned, Serialize};
+use serde_json::Value;
+use std::sync::{Mutex, PoisonError};
+
+/// The text shown in place of a secure value.
+pub const REDACTED: &str = "<redacted>";
+
+/// The minimum length of a secure value to be masked within other text, shorter ones would also mask unrelated text.
+/// Shorter secure values are only masked as whole values and shorter derived values aren't masked.
+pub const MIN_SECURE_LENGTH: usize = 8;
+
+// secure values are process wide so they are also masked in traces written outside of a configuration
+static SECURE_VALUES: Mutex<Vec<String>> = Mutex::new(Vec::new());
+
+/// Register a secure value so it's masked wherever it would be written.
+///
+/// # Arguments
+///
+/// * `value` - The secure value, each string, number and boolean within an array or object is registered individually.
+pub fn add_secure_value(value: &Value) {
+    match value {
+        Value::String(string) => add_secure_string(string),
+        Value::Number(number) => add_secure_string(&number.to_string()),
+        Value::Bool(bool) => add_secure_string(&bool.to_string()),
+        Value::Array(array) => {
+            for element in array {
+                add_secure_value(element);
+            }
+        },
+        Value::Object(object) => {
+            for element in object.values() {
+                add_secure_value(element);
+            }
+        },
+        Value::Null => {},
+    }
+}
+
+fn add_secure_string(string: &str) {
+    if string.is_empty() {
+        return;
+    }
+    let mut secure_values = SECURE_VALUES.lock().unwrap_or_else(PoisonError::into_inner);
+    let mut new_values = vec![string.to_string()];
+    // within JSON text the value can appear with escaped characters
+    if let Ok(json) = serde_json::to_string(string) {
+        if let Some(escaped) = json.strip_prefix('"').and_then(|json| json.strip_suffix('"')) {
+            new_values.push(escaped.to_string());
+        }
+    }
+    for secure_value in new_values {
+        if !secure_values.contains(&secure_value) {
+            secure_values.push(secure_value);
+        }
+    }
+    // longer values first so a value containing another one is masked completely
+    secure_values.sort_by_key(|secure_value| std::cmp::Reverse(secure_value.len()));
+}
+
+/// Register a value derived from a secure value so it's masked wherever it would be written.
+///
+/// # Arguments
+///
+/// * `value` - The derived value, strings shorter than `MIN_SECURE_LENGTH` are not registered.
+pub fn add_derived_value(value: &Value) {
+    match value {
+        Value::String(string) if string.chars().count() >= MIN_SECURE_LENGTH => add_secure_string(string),
+        Value::Array(array) => {
+            for element in array {
+                add_derived_value(element);
+            }
+        },
+        Value::Object(object) => {
+            for element in object.values() {
+                add_derived_value(element);
+            }
+        },
+        _ => {},
+    }
+}
+
+/// Check if any secure values have been registered.
+#[must_use]
+pub fn has_secure_values() -> bool {
+    !SECURE_VALUES.lock().unwrap_or_else(PoisonError::into_inner).is_empty()
+}
+
+/// Mask the registered secure values in text.
+///
+/// # Arguments
+///
+/// * `text` - The text to mask.
+#[must_use]
+pub fn redact(text: &str) -> String {
+    let secure_values = SECURE_VALUES.lock().unwrap_or_else(PoisonError::into_inner);
+    let mut result = text.to_string();
+    for secure_value in secure_values.iter() {
+        if secure_value.chars().count() >= MIN_SECURE_LENGTH {
+            if result.contains(secure_value.as_str()) {
+                result = result.replace(secure_value.as_str(), REDACTED);
+            }
+        } else {
+            // short values are only masked as whole JSON strings
+            let quoted = format!("\"{secure_value}\"");
+            if result.contains(&quoted) {
+                result = result.replace(&quoted, &format!("\"{REDACTED}\""));
+            }
+        }
+    }
+    result
+}
+
+fn is_secure_value(string: &str) -> bool {
+    SECURE_VALUES.lock().unwrap_or_else(PoisonError::into_inner).iter().any(|secure_value| secure_value == string)
+}
+
+/// Mask the registered secure values in the strings of a JSON value.
+///
+/// # Arguments
+///
+/// * `value` - The value to mask.
+#[must_use]
+pub fn redact_value(value: &Value) -> Value {
+    match value {
+        Value::String(string) if is_secure_value(string) => Value::String(REDACTED.to_string()),
+        Value::String(string) => Value::String(redact(string)),
+        Value::Array(array) => Value::Array(array.iter().map(redact_value).collect()),
+        Value::Object(object) => Value::Object(object.iter().map(|(name, value)| (name.clone(), redact_value(value))).collect()),
+        _ => value.clone(),
+    }
+}
+
+/// Mask the registered secure values in a result that is returned to the caller.
+///
+/// # Arguments
+///
+/// * `result` - The result to mask.
+///
+/// # Errors
+///
+/// This function will return an error if the result can't be converted to and from JSON.
+pub fn redact_result<T: Serialize + DeserializeOwned>(result: T) -> Result<T, DscError> {
+    if !has_secure_values() {
+        return Ok(result);
+    }
+    let value = redact_value(&serde_json::to_value(result)?);
+    Ok(serde_json::from_value(value)?)
+}
+
+/// Mask the registered secure values in the output of a command that is part of an error.
+///
+/// # Arguments
+///
+/// * `err` - The error to mask.
+#[must_use]
+pub fn redact_error(err: DscError) -> DscError {
+    match err {
+        DscError::Command(resource, exit_code, stderr) => DscError::Command(resource, exit_code, redact(&stderr)),
+        DscError::CommandExit(executable, exit_code, stderr) => DscError::CommandExit(executable, exit_code, redact(&stderr)),
+        DscError::CommandExitFromManifest(resource, exit_code, description) => DscError::CommandExitFromManifest(resource, exit_code, redact(&description)),
+        err => err,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    #[test]
+    fn redact_nested_values() {
+        add_secure_value(&json!({"password": "redaction-test-secret", "list": ["redaction-test-item"]}));
+        assert_eq!(redact("user redaction-test-secret"), format!("user {REDACTED}"));
+        let value = redact_value(&json!({"a": ["x redaction-test-item"], "b": 1}));
+        assert_eq!(value, json!({"a": [format!("x {REDACTED}")], "b": 1}));
+    }
+
+    #[test]
+    fn redact_escaped_value() {
+        add_secure_value(&json!("redaction \"quoted\" secret"));
+        let text = serde_json::to_string(&json!({"value": "redaction \"quoted\" secret"})).unwrap();
+        assert_eq!(redact(&text), format!("{{\"value\":\"{REDACTED}\"}}"));
+    }
+
+    #[test]
+    fn short_values_are_redacted_as_whole_values() {
+        add_secure_value(&json!("rdct"));
+        assert_eq!(redact("ardctb"), "ardctb");
+        assert_eq!(redact("{\"value\":\"rdct\"}"), format!("{{\"value\":\"{REDACTED}\"}}"));
+        assert_eq!(redact_value(&json!(["rdct", "ardctb"])), json!([REDACTED, "ardctb"]));
+    }
+
+    #[test]
+    fn redact_number_and_bool_leaves() {
+        add_secure_value(&json!({"port": 8_675_309_123_u64, "enabled": true}));
+        assert_eq!(redact("port 8675309123"), format!("port {REDACTED}"));
+        assert_eq!(redact_value(&json!("true")), json!(REDACTED));
+    }
+
+    #[test]
+    fn short_derived_values_are_not_redacted() {
+        add_derived_value(&json!(["no", "redaction-test-derived"]));
+        assert_eq!(redact("no"), "no");
+        assert_eq!(redact("redaction-test-derived"), REDACTED);
+    }
+
+    #[test]
+    fn redact_command_error() {
+        add_secure_value(&json!("redaction-test-stderr"));
+        let err = redact_error(DscError::Command("Test/Resource".to_string(), 1, "failed with redaction-test-stderr".to_string()));
+        assert!(!err.to_string().contains("redaction-test-stderr"));
+    }
+}
@@ -2,15 +2,18 @@
 // Licensed under the MIT License.
 
 use crate::discovery::discovery_trait::ResourceDiscovery;
-use crate::discovery::convert_wildcard_to_regex;
+use crate::discovery::{convert_wildcard_to_regex, find_matching_version, parse_resource_filter};
+use crate::discovery::index::DiscoveryIndex;
 use crate::dscresources::dscresource::{Capability, DscResource, ImplementedAs};
 use crate::dscresources::resource_manifest::{import_manifest, validate_semver, Kind, ResourceManifest};
 use crate::dscresources::command_resource::invoke_command;
 use crate::dscerror::DscError;
+use crate::settings::DscSettings;
+use crate::util::get_user_directory;
 use indicatif::ProgressStyle;
 use linked_hash_map::LinkedHashMap;
 use regex::RegexBuilder;
-use semver::Version;
+use semver::{Version, VersionReq};
 use std::collections::{BTreeMap, HashSet, HashMap};
 use std::env;
 use std::ffi::OsStr;
@@ -26,70 +29,80 @@ pub struct CommandDiscovery {
     resources: BTreeMap<String, Vec<DscResource>>,
     adapters: BTreeMap<String, Vec<DscResource>>,
     adapted_resources: BTreeMap<String, Vec<DscResource>>,
+    // the filters of the last `find_resources()` where the resource type was found but no version satisfied the requirement
+    unmatched_versions: BTreeMap<String, Vec<String>>,
+    settings: DscSettings,
 }
 
 impl CommandDiscovery {
-    pub fn new() -> CommandDiscovery {
+    pub fn new(settings: &DscSettings) -> CommandDiscovery {
         CommandDiscovery {
             resources: BTreeMap::new(),
             adapters: BTreeMap::new(),
             adapted_resources: BTreeMap::new(),
+            unmatched_versions: BTreeMap::new(),
+            settings: settings.clone(),
         }
     }
 
-    fn get_resource_paths() -> Result<Vec<PathBuf>, DscError>
+    fn get_resource_paths(&self) -> Result<Vec<PathBuf>, DscError>
     {
-        let mut using_custom_path = false;
+        let settings = &self.settings.resource_path;
 
-        // try DSC_RESOURCE_PATH env var first otherwise use PATH
-        let path_env = if let Some(value) = env::var_os("DSC_RESOURCE_PATH") {
-            debug!("Using DSC_RESOURCE_PATH: {:?}", value.to_string_lossy());
-            using_custom_path = true;
-            value
-        } else {
-            trace!("DSC_RESOURCE_PATH not set, trying PATH");
+        // try DSC_RESOURCE_PATH env var first if the settings allow it
+        if let Some(value) = env::var_os("DSC_RESOURCE_PATH") {
+            if settings.allow_env_override {
+                debug!("Using DSC_RESOURCE_PATH: {:?}", value.to_string_lossy());
+                return Ok(get_unique_paths(env::split_paths(&value)));
+            }
+            warn!("DSC_RESOURCE_PATH is ignored as it is not allowed by the settings");
+        }
+
+        // the directories from the settings are searched before PATH
+        let mut paths = settings.directories.iter().map(PathBuf::from).collect::<Vec<_>>();
+        if settings.use_path {
+            trace!("DSC_RESOURCE_PATH not used, trying PATH");
             match env::var_os("PATH") {
                 Some(value) => {
                     trace!("Original PATH: {:?}", value.to_string_lossy());
-                    value
+                    paths.extend(env::split_paths(&value));
                 },
                 None => {
                     return Err(DscError::Operation("Failed to get PATH environment variable".to_string()));
                 }
             }
-        };
-
-        let mut paths = env::split_paths(&path_env).collect::<Vec<_>>();
-        // remove duplicate entries
-        let mut uniques = HashSet::new();
-        paths.retain(|e|uniques.insert((*e).clone()));
-
-        // if exe home is not already in PATH env var then add it to env var and list of searched paths
-        if !using_custom_path {
-            if let Some(exe_home) = env::current_exe()?.parent() {
-                let exe_home_pb = exe_home.to_path_buf();
-                if paths.contains(&exe_home_pb) {
-                    trace!("Exe home is already in path: {}", exe_home.to_string_lossy());
-                } else {
-                    trace!("Adding exe home to path: {}", exe_home.to_string_lossy());
-                    paths.push(exe_home_pb);
+        } else {
+            debug!("PATH is not searched as it is disabled by the settings");
+        }
+        let mut paths = get_unique_paths(paths);
+
+        // the resources that ship with DSC are always found, if exe home is not already in the list of searched
+        // paths then add it, and to PATH if it is searched
+        if let Some(exe_home) = env::current_exe()?.parent() {
+            let exe_home_pb = exe_home.to_path_buf();
+            if paths.contains(&exe_home_pb) {
+                trace!("Exe home is already in path: {}", exe_home.to_string_lossy());
+            } else {
+                trace!("Adding exe home to path: {}", exe_home.to_string_lossy());
+                paths.push(exe_home_pb);
 
+                if settings.use_path {
                     if let Ok(new_path) = env::join_paths(paths.clone()) {
                         debug!("Using PATH: {:?}", new_path.to_string_lossy());
                         env::set_var("PATH", &new_path);
                     }
                 }
             }
-        };
+        }
 
         Ok(paths)
     }
 }
 
-impl Default for CommandDiscovery {
-    fn default() -> Self {
-        Self::new()
-    }
+// remove duplicate entries while keeping the order
+fn get_unique_paths(paths: impl IntoIterator<Item = PathBuf>) -> Vec<PathBuf> {
+    let mut uniques = HashSet::new();
+    paths.into_iter().filter(|path| uniques.insert(path.clone())).collect()
 }
 
 impl ResourceDiscovery for CommandDiscovery {
@@ -115,56 +128,44 @@ impl ResourceDiscovery for CommandDiscovery {
         let mut resources = BTreeMap::<String, Vec<DscResource>>::new();
         let mut adapters = BTreeMap::<String, Vec<DscResource>>::new();
 
-        if let Ok(paths) = CommandDiscovery::get_resource_paths() {
+        if let Ok(paths) = self.get_resource_paths() {
+            // directories that didn't change since the last search are taken from the index instead of searched again,
+            // unless the manifests must be in allowed directories so the index can't affect which manifests are checked
+            let use_index = !self.settings.policy.has_manifest_directories();
+            let mut index = if use_index { DiscoveryIndex::load() } else { DiscoveryIndex::default() };
             for path in paths {
                 if path.exists() && path.is_dir() {
-                    for entry in path.read_dir().unwrap() {
-                        let entry = entry.unwrap();
-                        let path = entry.path();
-                        if path.is_file() {
-                            let Some(os_file_name) = path.file_name() else {
-                                // skip if not a file
+                    for resource in index.get_resources(&path) {
+                        let resource = match resource {
+                            Ok(r) => r,
+                            Err(e) => {
+                                // At this point we can't determine whether or not the bad manifest contains
+                                // resource that is requested by resource/config operation
+                                // if it is, then "ResouceNotFound" error will be issued later
+                                // and here we just write as warning
+                                warn!("{e}");
                                 continue;
-                            };
-                            let Some(file_name) = os_file_name.to_str() else {
-                                // skip if not a valid file name
-                                continue;
-                            };
-                            let file_name_lowercase = file_name.to_lowercase();
-                            if file_name_lowercase.ends_with(".dsc.resource.json") ||
-                                file_name_lowercase.ends_with(".dsc.resource.yaml") ||
-                                file_name_lowercase.ends_with(".dsc.resource.yml") {
-                                trace!("Found resource manifest: {path:?}");
-                                let resource = match load_manifest(&path)
-                                {
-                                    Ok(r) => r,
-                                    Err(e) => {
-                                        // At this point we can't determine whether or not the bad manifest contains
-                                        // resource that is requested by resource/config operation
-                                        // if it is, then "ResouceNotFound" error will be issued later
-                                        // and here we just write as warning
-                                        warn!("{e}");
-                                        continue;
-                                    },
-                                };
-
-                                if regex.is_match(&resource.type_name) {
-                                    if let Some(ref manifest) = resource.manifest {
-                                        let manifest = import_manifest(manifest.clone())?;
-                                        if manifest.kind == Some(Kind::Adapter) {
-                                            trace!("Resource adapter '{}' found", resource.type_name);
-                                            insert_resource(&mut adapters, &resource, true);
-                                        } else {
-                                            trace!("Resource '{}' found", resource.type_name);
-                                            insert_resource(&mut resources, &resource, true);
-                                        }
-                                    }
+                            },
+                        };
+
+                        if regex.is_match(&resource.type_name) {
+                            if let Some(ref manifest) = resource.manifest {
+                                let manifest = import_manifest(manifest.clone())?;
+                                if manifest.kind == Some(Kind::Adapter) {
+                                    trace!("Resource adapter '{}' found", resource.type_name);
+                                    insert_resource(&mut adapters, &resource, true);
+                                } else {
+                                    trace!("Resource '{}' found", resource.type_name);
+                                    insert_resource(&mut resources, &resource, true);
                                 }
                             }
                         }
                     }
                 }
             }
+            if use_index {
+                index.save();
+            }
         }
         debug!("Found {} matching non-adapter-based resources", resources.len());
         self.resources = resources;
@@ -206,6 +207,8 @@ impl ResourceDiscovery for CommandDiscovery {
 
         let mut adapted_resources = BTreeMap::<String, Vec<DscResource>>::new();
 
+        // an adapter is only invoked to list its resources if the policy allows it
+        let policy = &self.settings.policy;
         let mut found_adapter: bool = false;
         for (adapter_name, adapters) in &self.adapters {
             for adapter in adapters {
@@ -214,6 +217,10 @@ impl ResourceDiscovery for CommandDiscovery {
                 }
 
                 found_adapter = true;
+                if let Err(err) = policy.check(adapter) {
+                    info!("{err}");
+                    continue;
+                }
                 info!("Enumerating resources for adapter '{}'", adapter_name);
                 let pb_adapter_span = warn_span!("");
                 pb_adapter_span.pb_set_style(&ProgressStyle::with_template(
@@ -299,7 +306,7 @@ impl ResourceDiscovery for CommandDiscovery {
             self.discover_adapted_resources(type_name_filter, adapter_name_filter)?;
             
             // add/update found adapted resources to the lookup_table
-            add_resources_to_lookup_table(&self.adapted_resources);
+            add_resources_to_lookup_table(&self.settings, &self.adapted_resources);
 
             // note: in next line 'BTreeMap::append' will leave self.adapted_resources empty
             resources.append(&mut self.adapted_resources);
@@ -308,86 +315,130 @@ impl ResourceDiscovery for CommandDiscovery {
         Ok(resources)
     }
 
-    // TODO: handle version requirements
     fn find_resources(&mut self, required_resource_types: &[String]) -> Result<BTreeMap<String, DscResource>, DscError>
     {
         debug!("Searching for resources: {:?}", required_resource_types);
         self.discover_resources("*")?;
-
-        // convert required_resource_types to lowercase to handle case-insentiive search
-        let mut remaining_required_resource_types = required_resource_types.iter().map(|x| x.to_lowercase()).collect::<Vec<String>>();
-        remaining_required_resource_types.sort_unstable();
-        remaining_required_resource_types.dedup();
+        self.unmatched_versions.clear();
+
+        // the filters are the resource types in lowercase to handle case-insensitive search, with an optional version requirement
+        let mut remaining_required_resources = RequiredResources::default();
+        for filter in required_resource_types {
+            match parse_resource_filter(filter) {
+                Ok((type_name, version_req)) => {
+                    remaining_required_resources.filters.insert(filter.to_lowercase(), (type_name, version_req));
+                },
+                Err(err) => warn!("{err}"),
+            }
+        }
 
         let mut found_resources = BTreeMap::<String, DscResource>::new();
+        if remaining_required_resources.filters.is_empty() {
+            // nothing to find, which avoids enumerating the resources of every adapter
+            return Ok(found_resources);
+        }
 
         for (resource_name, resources) in &self.resources {
-            // TODO: handle version requirements
-            let Some(resource ) = resources.first() else {
-                // skip if no resources
-                continue;
-            };
-
-            if remaining_required_resource_types.contains(&resource_name.to_lowercase())
-            {
-                // remove the resource from the list of required resources
-                remaining_required_resource_types.retain(|x| *x != resource_name.to_lowercase());
-                found_resources.insert(resource_name.to_lowercase(), resource.clone());
-                if remaining_required_resource_types.is_empty()
-                {
-                    return Ok(found_resources);
-                }
+            remaining_required_resources.find(resource_name, resources, &mut found_resources);
+            if remaining_required_resources.filters.is_empty() {
+                return Ok(found_resources);
             }
         }
         debug!("Found {} matching non-adapter-based resources", found_resources.len());
 
         // now go through the adapters
-        let sorted_adapters = sort_adapters_based_on_lookup_table(&self.adapters, &remaining_required_resource_types);
+        let sorted_adapters = sort_adapters_based_on_lookup_table(&self.settings, &self.adapters, &remaining_required_resources.type_names());
         for (adapter_name, adapters) in sorted_adapters {
-            // TODO: handle version requirements
+            // the newest version of an adapter is used for the resources it adapts
             let Some(adapter) = adapters.first() else {
                 // skip if no adapters
                 continue;
             };
 
-            if remaining_required_resource_types.contains(&adapter_name.to_lowercase())
-            {
-                // remove the adapter from the list of required resources
-                remaining_required_resource_types.retain(|x| *x != adapter_name.to_lowercase());
-                found_resources.insert(adapter_name.to_lowercase(), adapter.clone());
-                if remaining_required_resource_types.is_empty()
-                {
-                    return Ok(found_resources);
-                }
+            remaining_required_resources.find(&adapter_name, &adapters, &mut found_resources);
+            if remaining_required_resources.filters.is_empty() {
+                return Ok(found_resources);
             }
 
             self.discover_adapted_resources("*", &adapter_name)?;
             // add/update found adapted resources to the lookup_table
-            add_resources_to_lookup_table(&self.adapted_resources);
+            add_resources_to_lookup_table(&self.settings, &self.adapted_resources);
 
             // now go through the adapter resources and add them to the list of resources
-            for (adapted_name, adapted_resource) in &self.adapted_resources {
-                let Some(adapted_resource) = adapted_resource.first() else {
-                    // skip if no resources
-                    continue;
-                };
-
-                if remaining_required_resource_types.contains(&adapted_name.to_lowercase())
-                {
-                    remaining_required_resource_types.retain(|x| *x != adapted_name.to_lowercase());
-                    found_resources.insert(adapted_name.to_lowercase(), adapted_resource.clone());
-
+            for (adapted_name, adapted_resources) in &self.adapted_resources {
+                if remaining_required_resources.find(adapted_name, adapted_resources, &mut found_resources) {
                     // also insert the adapter
                     found_resources.insert(adapter_name.to_lowercase(), adapter.clone());
-                    if remaining_required_resource_types.is_empty()
-                    {
+                    if remaining_required_resources.filters.is_empty() {
                         return Ok(found_resources);
                     }
                 }
             }
         }
+
+        let RequiredResources { filters, unmatched_versions } = remaining_required_resources;
+        self.unmatched_versions = unmatched_versions.into_iter().filter(|(filter, _)| filters.contains_key(filter)).collect();
         Ok(found_resources)
     }
+
+    fn find_function_providers(&mut self) -> Result<Vec<DscResource>, DscError> {
+        if self.resources.is_empty() {
+            self.discover_resources("*")?;
+        }
+
+        let mut function_providers = Vec::<DscResource>::new();
+        for (provider_name, providers) in &self.resources {
+            // only the newest version of a provider is used
+            let Some(provider) = providers.first() else {
+                continue;
+            };
+
+            if provider.kind == Kind::FunctionProvider {
+                debug!("Function provider '{provider_name}' found");
+                function_providers.push(provider.clone());
+            }
+        }
+        Ok(function_providers)
+    }
+
+    fn get_unmatched_versions(&self) -> BTreeMap<String, Vec<String>> {
+        self.unmatched_versions.clone()
+    }
+}
+
+#[derive(Default)]
+struct RequiredResources {
+    // the lowercase filter and its lowercase resource type and version requirement
+    filters: BTreeMap<String, (String, Option<VersionReq>)>,
+    // the versions available for filters where the resource type was found but no version satisfied the requirement
+    unmatched_versions: BTreeMap<String, Vec<String>>,
+}
+
+impl RequiredResources {
+    // find the newest version of a resource for each filter of its type, returns true if any filter was satisfied
+    fn find(&mut self, type_name: &str, resources: &[DscResource], found_resources: &mut BTreeMap<String, DscResource>) -> bool {
+        let type_name = type_name.to_lowercase();
+        let mut found = false;
+        let unmatched_versions = &mut self.unmatched_versions;
+        self.filters.retain(|filter, (required_type_name, version_req)| {
+            if *required_type_name != type_name {
+                return true;
+            }
+            if let Some(resource) = find_matching_version(resources, version_req.as_ref()) {
+                debug!("Found resource '{}' version {} for '{filter}'", resource.type_name, resource.version);
+                found_resources.insert(filter.clone(), resource.clone());
+                found = true;
+                return false;
+            }
+            unmatched_versions.entry(filter.clone()).or_default().extend(resources.iter().map(|resource| resource.version.clone()));
+            true
+        });
+        found
+    }
+
+    fn type_names(&self) -> Vec<String> {
+        self.filters.values().map(|(type_name, _)| type_name.clone()).collect()
+    }
 }
 
 // helper to insert a resource into a vector of resources in order of newest to oldest
@@ -432,7 +483,14 @@ fn insert_resource(resources: &mut BTreeMap<String, Vec<DscResource>>, resource:
     }
 }
 
-fn load_manifest(path: &Path) -> Result<DscResource, DscError> {
+pub(crate) fn is_manifest_file_name(file_name: &str) -> bool {
+    let file_name_lowercase = file_name.to_lowercase();
+    file_name_lowercase.ends_with(".dsc.resource.json") ||
+        file_name_lowercase.ends_with(".dsc.resource.yaml") ||
+        file_name_lowercase.ends_with(".dsc.resource.yml")
+}
+
+pub(crate) fn load_manifest(path: &Path) -> Result<DscResource, DscError> {
     let file = File::open(path)?;
     let reader = BufReader::new(file);
     let manifest: ResourceManifest = if path.extension() == Some(OsStr::new("json")) {
@@ -453,13 +511,15 @@ fn load_manifest(path: &Path) -> Result<DscResource, DscError> {
     };
 
     if let Err(err) = validate_semver(&manifest.version) {
-        return Err(DscError::Validation(format!("Invalid manifest {path:?} version value: {err}")));
+        return Err(DscError::Validation(format!("Invalid manifest {} version value: {err}", path.display())));
     }
 
     let kind = if let Some(kind) = manifest.kind.clone() {
         kind
     } else if manifest.adapter.is_some() {
         Kind::Adapter
+    } else if manifest.functions.is_some() {
+        Kind::FunctionProvider
     } else {
         Kind::Resource
     };
@@ -508,10 +568,10 @@ fn load_manifest(path: &Path) -> Result<DscResource, DscError> {
     Ok(resource)
 }
 
-fn sort_adapters_based_on_lookup_table(unsorted_adapters: &BTreeMap<String, Vec<DscResource>>, needed_resource_types: &Vec<String>) -> LinkedHashMap<String, Vec<DscResource>>
+fn sort_adapters_based_on_lookup_table(settings: &DscSettings, unsorted_adapters: &BTreeMap<String, Vec<DscResource>>, needed_resource_types: &Vec<String>) -> LinkedHashMap<String, Vec<DscResource>>
 {
     let mut result = LinkedHashMap::<String, Vec<DscResource>>::new();
-    let lookup_table = load_adapted_resources_lookup_table();
+    let lookup_table = load_adapted_resources_lookup_table(settings);
     // first add adapters (for needed types) that can be found in the lookup table
     for needed_resource in needed_resource_types {
         if let Some(adapter_name) = lookup_table.get(needed_resource) {
@@ -532,9 +592,9 @@ fn sort_adapters_based_on_lookup_table(unsorted_adapters: &BTreeMap<String, Vec<
     result
 }
 
-fn add_resources_to_lookup_table(adapted_resources: &BTreeMap<String, Vec<DscResource>>)
+fn add_resources_to_lookup_table(settings: &DscSettings, adapted_resources: &BTreeMap<String, Vec<DscResource>>)
 {
-    let mut lookup_table = load_adapted_resources_lookup_table();
+    let mut lookup_table = load_adapted_resources_lookup_table(settings);
 
     let mut lookup_table_changed = false;
     for (resource_name, res_vec) in adapted_resources {
@@ -550,14 +610,14 @@ fn add_resources_to_lookup_table(adapted_resources: &BTreeMap<String, Vec<DscRes
     };
 
     if lookup_table_changed {
-        save_adapted_resources_lookup_table(&lookup_table);
+        save_adapted_resources_lookup_table(settings, &lookup_table);
     }
 }
 
-fn save_adapted_resources_lookup_table(lookup_table: &HashMap<String, String>)
+fn save_adapted_resources_lookup_table(settings: &DscSettings, lookup_table: &HashMap<String, String>)
 {
     if let Ok(lookup_table_json) = serde_json::to_string(&lookup_table) {
-        let file_path = get_lookup_table_file_path();
+        let file_path = get_lookup_table_file_path(settings);
         debug!("Saving lookup table with {} items to {:?}", lookup_table.len(), file_path);
 
         let path = std::path::Path::new(&file_path);
@@ -577,9 +637,26 @@ fn save_adapted_resources_lookup_table(lookup_table: &HashMap<String, String>)
     }
 }
 
-fn load_adapted_resources_lookup_table() -> HashMap<String, String>
+pub(crate) fn delete_adapted_resources_lookup_table(settings: &DscSettings) -> Result<(), DscError>
 {
-    let file_path = get_lookup_table_file_path();
+    let file_path = get_lookup_table_file_path(settings);
+    if file_path.is_empty() {
+        return Ok(());
+    }
+
+    match fs::remove_file(&file_path) {
+        Ok(()) => {
+            debug!("Deleted lookup table {file_path:?}");
+            Ok(())
+        },
+        Err(err) if err.kind() == std::io::ErrorKind::NotFound => Ok(()),
+        Err(err) => Err(DscError::Operation(format!("Failed to delete lookup table {file_path:?}: {err}"))),
+    }
+}
+
+fn load_adapted_resources_lookup_table(settings: &DscSettings) -> HashMap<String, String>
+{
+    let file_path = get_lookup_table_file_path(settings);
     
     let lookup_table: HashMap<String, String> = match fs::read(file_path.clone()){
         Ok(data) => { serde_json::from_slice(&data).unwrap_or_default() },
@@ -590,19 +667,13 @@ fn load_adapted_resources_lookup_table() -> HashMap<String, String>
     lookup_table
 }
 
-#[cfg(target_os = "windows")]
-fn get_lookup_table_file_path() -> String
+fn get_lookup_table_file_path(settings: &DscSettings) -> String
 {
-    // $env:LocalAppData+"dsc\AdaptedResourcesLookupTable.json"
-    let Ok(local_app_data_path) = std::env::var("LocalAppData") else { return String::new(); };
-
-    Path::new(&local_app_data_path).join("dsc").join("AdaptedResourcesLookupTable.json").display().to_string()
-}
+    // the location from the settings is used if set
+    if let Some(path) = &settings.adapter_lookup_table {
+        return path.clone();
+    }
 
-#[cfg(not(target_os = "windows"))]
-fn get_lookup_table_file_path() -> String
-{
-    // $env:HOME+".dsc/AdaptedResourcesLookupTable.json"
-    let Ok(home_path) = std::env::var("HOME") else { return String::new(); };
-    Path::new(&home_path).join(".dsc").join("AdaptedResourcesLookupTable.json").display().to_string()
+    // $env:LocalAppData+"dsc\AdaptedResourcesLookupTable.json" or $env:HOME+".dsc/AdaptedResourcesLookupTable.json"
+    get_user_directory().map(|directory| directory.join("AdaptedResourcesLookupTable.json").display().to_string()).unwrap_or_default()
 }
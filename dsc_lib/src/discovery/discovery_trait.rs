@@ -9,4 +9,6 @@ pub trait ResourceDiscovery {
     fn discover_adapted_resources(&mut self, name_filter: &str, adapter_filter: &str) -> Result<(), DscError>;
     fn list_available_resources(&mut self, type_name_filter: &str, adapter_name_filter: &str) -> Result<BTreeMap<String, Vec<DscResource>>, DscError>;
     fn find_resources(&mut self, required_resource_types: &[String]) -> Result<BTreeMap<String, DscResource>, DscError>;
+    fn find_function_providers(&mut self) -> Result<Vec<DscResource>, DscError>;
+    fn get_unmatched_versions(&self) -> BTreeMap<String, Vec<String>>;
 }
@@ -0,0 +1,273 @@
+// Copyright (c) Microsoft Corporation.
+// Licensed under the MIT License.
+
+use crate::discovery::command_discovery::{is_manifest_file_name, load_manifest};
+use crate::dscerror::DscError;
+use crate::dscresources::dscresource::DscResource;
+use crate::util::get_user_directory;
+use serde::{Deserialize, Serialize};
+use std::collections::{BTreeMap, HashSet};
+use std::fs;
+use std::path::{Path, PathBuf};
+use std::time::{SystemTime, UNIX_EPOCH};
+use tracing::{debug, info, trace};
+
+// the index is rebuilt when DSC is updated as the format of a `DscResource` can change
+const INDEX_VERSION: &str = env!("CARGO_PKG_VERSION");
+// a change within this time before a directory was indexed might not have changed the modified time on
+// file systems with a coarse resolution, so such entries are checked again
+const RACY_NANOSECONDS: u64 = 2_000_000_000;
+
+#[derive(Debug, Clone, Default, Deserialize, Serialize)]
+struct IndexedDirectory {
+    modified: u64,
+    indexed: u64,
+    // only the file names of the manifests are kept, the manifests themselves are loaded every time so the
+    // index can't change what a resource runs
+    manifests: Vec<String>,
+}
+
+impl IndexedDirectory {
+    fn is_current(&self, modified: u64) -> bool {
+        modified == self.modified && modified.saturating_add(RACY_NANOSECONDS) < self.indexed
+    }
+}
+
+#[derive(Debug, Default, Deserialize, Serialize)]
+struct IndexFile {
+    version: String,
+    directories: BTreeMap<String, IndexedDirectory>,
+}
+
+/// An on-disk index of the resource manifests found in each directory, a directory is only searched again
+/// if it changed.
+#[derive(Debug, Default)]
+pub struct DiscoveryIndex {
+    directories: BTreeMap<String, IndexedDirectory>,
+    visited: HashSet<String>,
+    changed: bool,
+}
+
+impl DiscoveryIndex {
+    /// Load the index, which is empty if it doesn't exist or is for another version of DSC.
+    #[must_use]
+    pub fn load() -> Self {
+        let Some(path) = get_index_file_path() else {
+            return Self::default();
+        };
+        let index_file = match fs::read(&path) {
+            Ok(data) => serde_json::from_slice::<IndexFile>(&data).unwrap_or_default(),
+            Err(_) => IndexFile::default(),
+        };
+        if index_file.version != INDEX_VERSION {
+            debug!("Discovery index at {} is not for this version", path.display());
+            return Self::default();
+        }
+
+        debug!("Read {} directories into discovery index from {}", index_file.directories.len(), path.display());
+        Self {
+            directories: index_file.directories,
+            visited: HashSet::new(),
+            changed: false,
+        }
+    }
+
+    /// Get the resources from the manifests in a directory, using the index for the manifests in it if it didn't change.
+    ///
+    /// # Arguments
+    ///
+    /// * `directory` - The directory to search.
+    ///
+    /// # Returns
+    ///
+    /// The resource or the error loading it for each manifest.
+    pub fn get_resources(&mut self, directory: &Path) -> Vec<Result<DscResource, String>> {
+        let key = directory.to_string_lossy().to_string();
+        self.visited.insert(key.clone());
+        let indexed = get_nanoseconds(SystemTime::now()).unwrap_or_default();
+        let modified = fs::metadata(directory).ok().and_then(|metadata| get_modified(&metadata));
+        let cached = self.directories.remove(&key).unwrap_or_default();
+
+        // the modified time of a directory changes when a file is added, removed or renamed
+        let manifest_names = if modified.is_some_and(|modified| cached.is_current(modified)) {
+            trace!("Using discovery index for {}", directory.display());
+            cached.manifests
+        } else {
+            trace!("Searching {} for resource manifests", directory.display());
+            self.changed = true;
+            find_manifests(directory)
+        };
+
+        let mut manifests = Vec::<String>::new();
+        let mut resources = Vec::<Result<DscResource, String>>::new();
+        for name in manifest_names {
+            // a name from the index that isn't a manifest file directly within the directory is ignored
+            if !is_manifest_file_name(&name) || Path::new(&name).file_name().and_then(|file_name| file_name.to_str()) != Some(name.as_str()) {
+                self.changed = true;
+                continue;
+            }
+            let path = directory.join(&name);
+            if !path.is_file() {
+                self.changed = true;
+                continue;
+            }
+
+            trace!("Found resource manifest: {}", path.display());
+            resources.push(load_manifest(&path).map_err(|err| err.to_string()));
+            manifests.push(name);
+        }
+
+        self.directories.insert(key, IndexedDirectory {
+            modified: modified.unwrap_or_default(),
+            indexed,
+            manifests,
+        });
+        resources
+    }
+
+    /// Save the index if it changed, only the directories searched since it was loaded are kept.
+    pub fn save(&mut self) {
+        let stale = self.directories.len() != self.visited.len();
+        if !self.changed && !stale {
+            return;
+        }
+        let Some(path) = get_index_file_path() else {
+            info!("Unable to get the path of the discovery index");
+            return;
+        };
+
+        let visited = &self.visited;
+        self.directories.retain(|directory, _| visited.contains(directory));
+        let index_file = IndexFile {
+            version: INDEX_VERSION.to_string(),
+            directories: self.directories.clone(),
+        };
+        let Ok(json) = serde_json::to_string(&index_file) else {
+            info!("Unable to serialize discovery index to json");
+            return;
+        };
+
+        debug!("Saving discovery index with {} directories to {}", self.directories.len(), path.display());
+        if let Some(prefix) = path.parent() {
+            if fs::create_dir_all(prefix).is_err() {
+                info!("Unable to create parent directories of the discovery index {}", path.display());
+                return;
+            }
+        }
+        // several instances of DSC can run at the same time so the index is replaced instead of written in place
+        let temp_path = path.with_extension(format!("{}.tmp", std::process::id()));
+        if fs::write(&temp_path, json).is_err() || fs::rename(&temp_path, &path).is_err() {
+            info!("Unable to write discovery index {}", path.display());
+            let _ = fs::remove_file(&temp_path);
+            return;
+        }
+        self.changed = false;
+    }
+
+    /// Get the number of manifests in the index.
+    #[must_use]
+    pub fn manifest_count(&self) -> usize {
+        self.directories.values().map(|directory| directory.manifests.len()).sum()
+    }
+}
+
+/// Delete the discovery index so the resource manifests are searched and loaded again.
+///
+/// # Errors
+///
+/// This function will return an error if the index exists but can't be deleted.
+pub fn clear_index() -> Result<(), DscError> {
+    let Some(path) = get_index_file_path() else {
+        return Ok(());
+    };
+    match fs::remove_file(&path) {
+        Ok(()) => {
+            debug!("Deleted discovery index {}", path.display());
+            Ok(())
+        },
+        Err(err) if err.kind() == std::io::ErrorKind::NotFound => Ok(()),
+        Err(err) => Err(DscError::Operation(format!("Failed to delete discovery index {}: {err}", path.display()))),
+    }
+}
+
+fn find_manifests(directory: &Path) -> Vec<String> {
+    let Ok(entries) = directory.read_dir() else {
+        return Vec::new();
+    };
+    entries.filter_map(Result::ok)
+        .filter(|entry| entry.path().is_file())
+        .filter_map(|entry| entry.file_name().to_str().map(ToString::to_string))
+        .filter(|name| is_manifest_file_name(name))
+        .collect()
+}
+
+fn get_modified(metadata: &fs::Metadata) -> Option<u64> {
+    get_nanoseconds(metadata.modified().ok()?)
+}
+
+fn get_nanoseconds(time: SystemTime) -> Option<u64> {
+    u64::try_from(time.duration_since(UNIX_EPOCH).ok()?.as_nanos()).ok()
+}
+
+fn get_index_file_path() -> Option<PathBuf> {
+    get_user_directory().map(|directory| directory.join("DiscoveryIndex.json"))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn unchanged_directory_is_not_searched_again() {
+        let directory = std::env::temp_dir().join(format!("dsc_discovery_index_{}", std::process::id()));
+        fs::create_dir_all(&directory).unwrap();
+        let manifest = r#"{"$schema": "https://raw.githubusercontent.com/PowerShell/DSC/main/schemas/2024/04/bundled/resource/manifest.json", "type": "Test/Index", "version": "1.0.0", "get": {"executable": "dscecho"}}"#;
+        fs::write(directory.join("index.dsc.resource.json"), manifest).unwrap();
+
+        let mut index = DiscoveryIndex::default();
+        let resources = index.get_resources(&directory);
+        assert_eq!(resources.len(), 1);
+        assert_eq!(resources[0].as_ref().unwrap().type_name, "Test/Index");
+        assert!(index.changed);
+
+        // as if the index was loaded by a later run, after the racy time of the directory and manifest
+        index.changed = false;
+        let key = directory.to_string_lossy().to_string();
+        index.directories.get_mut(&key).unwrap().indexed += 2 * RACY_NANOSECONDS;
+        let resources = index.get_resources(&directory);
+        assert_eq!(resources.len(), 1);
+        assert!(!index.changed);
+
+        // the manifest is loaded again even though the directory didn't change
+        fs::write(directory.join("index.dsc.resource.json"), manifest.replace("1.0.0", "2.0.0")).unwrap();
+        index.directories.get_mut(&key).unwrap().indexed += 2 * RACY_NANOSECONDS;
+        let resources = index.get_resources(&directory);
+        assert_eq!(resources[0].as_ref().unwrap().version, "2.0.0");
+
+        fs::remove_dir_all(&directory).unwrap();
+    }
+
+    #[test]
+    fn manifest_outside_directory_is_ignored() {
+        let directory = std::env::temp_dir().join(format!("dsc_discovery_index_outside_{}", std::process::id()));
+        fs::create_dir_all(directory.join("nested")).unwrap();
+        let manifest = r#"{"$schema": "https://raw.githubusercontent.com/PowerShell/DSC/main/schemas/2024/04/bundled/resource/manifest.json", "type": "Test/Outside", "version": "1.0.0", "get": {"executable": "dscecho"}}"#;
+        fs::write(directory.join("nested").join("outside.dsc.resource.json"), manifest).unwrap();
+
+        let mut index = DiscoveryIndex::default();
+        assert!(index.get_resources(&directory).is_empty());
+
+        // as if the index file was changed to list manifests of another directory
+        let key = directory.to_string_lossy().to_string();
+        let indexed = index.directories.get_mut(&key).unwrap();
+        indexed.indexed += 2 * RACY_NANOSECONDS;
+        indexed.manifests = vec![
+            "nested/outside.dsc.resource.json".to_string(),
+            directory.join("nested").join("outside.dsc.resource.json").to_string_lossy().to_string(),
+        ];
+        assert!(index.get_resources(&directory).is_empty());
+        assert!(index.directories[&key].manifests.is_empty());
+
+        fs::remove_dir_all(&directory).unwrap();
+    }
+}
@@ -3,27 +3,40 @@
 
 mod command_discovery;
 mod discovery_trait;
+mod index;
 
 use crate::discovery::discovery_trait::ResourceDiscovery;
 use crate::{dscresources::dscresource::DscResource, dscerror::DscError};
+use crate::settings::DscSettings;
+use semver::{Version, VersionReq};
 use std::collections::BTreeMap;
-use tracing::error;
+use tracing::{debug, error};
 
 pub struct Discovery {
     pub resources: BTreeMap<String, DscResource>,
+    pub function_providers: Vec<DscResource>,
+    settings: DscSettings,
+    // the resources that were found but aren't allowed by the policy and why
+    blocked_resources: BTreeMap<String, (String, String)>,
+    // the filters where the resource type was found but no version satisfied the requirement and the available versions
+    unmatched_versions: BTreeMap<String, Vec<String>>,
 }
 
 impl Discovery {
     /// Create a new `Discovery` instance.
     ///
-    /// # Errors
-    ///
-    /// This function will return an error if the underlying instance creation fails.
+    /// # Arguments
     ///
-    pub fn new() -> Result<Self, DscError> {
-        Ok(Self {
+    /// * `settings` - The settings with the resource path and policy used to find resources.
+    #[must_use]
+    pub fn new(settings: &DscSettings) -> Self {
+        Self {
             resources: BTreeMap::new(),
-        })
+            function_providers: Vec::new(),
+            settings: settings.clone(),
+            blocked_resources: BTreeMap::new(),
+            unmatched_versions: BTreeMap::new(),
+        }
     }
 
     /// List operation for getting available resources based on the filters.
@@ -38,7 +51,7 @@ impl Discovery {
     /// A vector of `DscResource` instances.
     pub fn list_available_resources(&mut self, type_name_filter: &str, adapter_name_filter: &str) -> Vec<DscResource> {
         let discovery_types: Vec<Box<dyn ResourceDiscovery>> = vec![
-            Box::new(command_discovery::CommandDiscovery::new()),
+            Box::new(command_discovery::CommandDiscovery::new(&self.settings)),
         ];
 
         let mut resources: Vec<DscResource> = Vec::new();
@@ -55,6 +68,10 @@ impl Discovery {
 
             for (_resource_name, found_resources) in discovered_resources {
                 for resource in found_resources {
+                    if let Some(reason) = self.settings.policy.get_violation(&resource) {
+                        debug!("Resource '{}' is not listed as it is not allowed, {reason}", resource.type_name);
+                        continue;
+                    }
                     resources.push(resource.clone());
                 }
             };
@@ -63,21 +80,72 @@ impl Discovery {
         resources
     }
 
-    #[must_use]
-    pub fn find_resource(&self, type_name: &str) -> Option<&DscResource> {
-        self.resources.get(&type_name.to_lowercase())
+    /// Delete the discovery index and the adapted resources lookup table so resources are searched for again.
+    ///
+    /// # Arguments
+    ///
+    /// * `settings` - The settings with the path of the adapted resources lookup table.
+    ///
+    /// # Errors
+    ///
+    /// This function will return an error if a file exists but can't be deleted.
+    pub fn clear_cache(settings: &DscSettings) -> Result<(), DscError> {
+        index::clear_index()?;
+        command_discovery::delete_adapted_resources_lookup_table(settings)
+    }
+
+    /// Search for all resource manifests again and save them to a new discovery index.
+    ///
+    /// # Arguments
+    ///
+    /// * `settings` - The settings with the resource path to search.
+    ///
+    /// # Returns
+    ///
+    /// The number of manifests in the index.
+    ///
+    /// # Errors
+    ///
+    /// This function will return an error if the cache can't be cleared or the search fails.
+    pub fn rebuild_cache(settings: &DscSettings) -> Result<usize, DscError> {
+        Discovery::clear_cache(settings)?;
+        command_discovery::CommandDiscovery::new(settings).discover_resources("*")?;
+        Ok(index::DiscoveryIndex::load().manifest_count())
+    }
+
+    /// Get a resource that was found by `find_resources()`.
+    ///
+    /// # Arguments
+    ///
+    /// * `type_name` - The resource type, or the resource filter with a version requirement like `Microsoft/OSInfo@^1.2`.
+    ///
+    /// # Errors
+    ///
+    /// This function will return an error if the resource wasn't found, no version satisfies the requirement
+    /// or it isn't allowed by the policy.
+    pub fn get_resource(&self, type_name: &str) -> Result<&DscResource, DscError> {
+        let key = type_name.to_lowercase();
+        if let Some((resource_type, reason)) = self.blocked_resources.get(&key) {
+            return Err(DscError::PolicyViolation(resource_type.clone(), reason.clone()));
+        }
+        if let Some(versions) = self.unmatched_versions.get(&key) {
+            return Err(DscError::ResourceVersionNotFound(type_name.to_string(), versions.join(", ")));
+        }
+        self.resources.get(&key).ok_or_else(|| DscError::ResourceNotFound(type_name.to_string()))
     }
 
-    /// Find resources based on the required resource types.
+    /// Find resources based on the required resource types, the available function providers are also found.
     ///
     /// # Arguments
     ///
-    /// * `required_resource_types` - The required resource types.
+    /// * `required_resource_types` - The required resource types, each can have a version requirement like `Microsoft/OSInfo@^1.2`
+    ///   in which case the newest version that satisfies it is found.
     pub fn find_resources(&mut self, required_resource_types: &[String]) {
         let discovery_types: Vec<Box<dyn ResourceDiscovery>> = vec![
-            Box::new(command_discovery::CommandDiscovery::new()),
+            Box::new(command_discovery::CommandDiscovery::new(&self.settings)),
         ];
         let mut remaining_required_resource_types = required_resource_types.to_owned();
+        self.function_providers.clear();
         for mut discovery_type in discovery_types {
 
             let discovered_resources = match discovery_type.find_resources(&remaining_required_resource_types) {
@@ -88,21 +156,100 @@ impl Discovery {
                 }
             };
 
-            for resource in discovered_resources {
-                self.resources.insert(resource.0.clone(), resource.1);
-                remaining_required_resource_types.retain(|x| *x != resource.0);
+            for (resource_filter, versions) in discovery_type.get_unmatched_versions() {
+                debug!("No version of resource satisfies '{resource_filter}', available versions: {}", versions.join(", "));
+                self.unmatched_versions.insert(resource_filter, versions);
+            }
+
+            for (resource_filter, resource) in &discovered_resources {
+                remaining_required_resource_types.retain(|x| x.to_lowercase() != *resource_filter);
+                self.unmatched_versions.remove(resource_filter);
+                // an adapted resource is checked with the adapter that was found for it, which has the manifest
+                let adapter = resource.require_adapter.as_ref().and_then(|adapter| discovered_resources.get(&adapter.to_lowercase()));
+                let violation = match adapter {
+                    Some(adapter) => self.settings.policy.get_adapted_violation(resource, adapter),
+                    None => self.settings.policy.get_violation(resource),
+                };
+                // the resource is kept out so it can't be invoked, but the reason is reported when it's requested
+                if let Some(reason) = violation {
+                    debug!("{}", DscError::PolicyViolation(resource.type_name.clone(), reason.clone()));
+                    self.blocked_resources.insert(resource_filter.clone(), (resource.type_name.clone(), reason));
+                    continue;
+                }
+                self.resources.insert(resource_filter.clone(), resource.clone());
             };
+
+            // the manifests were already loaded by `find_resources()` so this doesn't search again
+            match discovery_type.find_function_providers() {
+                Ok(function_providers) => {
+                    for function_provider in function_providers {
+                        if let Some(reason) = self.settings.policy.get_violation(&function_provider) {
+                            debug!("Function provider '{}' is not used as it is not allowed, {reason}", function_provider.type_name);
+                            continue;
+                        }
+                        self.function_providers.push(function_provider);
+                    }
+                },
+                Err(err) => error!("{err}"),
+            }
         }
     }
 }
 
-fn convert_wildcard_to_regex(wildcard: &str) -> String {
+pub(crate) fn convert_wildcard_to_regex(wildcard: &str) -> String {
     let mut regex = wildcard.to_string().replace('.', "\\.").replace('?', ".").replace('*', ".*?");
     regex.insert(0, '^');
     regex.push('$');
     regex
 }
 
+/// Get the filter to find a resource with a version that satisfies a requirement.
+///
+/// # Arguments
+///
+/// * `type_name` - The resource type.
+/// * `require_version` - The optional semantic version requirement, like `^1.2`.
+///
+/// # Returns
+///
+/// The resource type followed by `@` and the version requirement, or just the resource type if there is no requirement.
+#[must_use]
+pub fn get_resource_filter(type_name: &str, require_version: Option<&str>) -> String {
+    match require_version {
+        Some(require_version) => format!("{type_name}@{require_version}"),
+        None => type_name.to_string(),
+    }
+}
+
+/// Split a resource filter into the lowercase resource type and the version requirement.
+///
+/// # Arguments
+///
+/// * `filter` - The resource type, optionally followed by `@` and a semantic version requirement.
+///
+/// # Errors
+///
+/// This function will return an error if the version requirement isn't valid.
+pub fn parse_resource_filter(filter: &str) -> Result<(String, Option<VersionReq>), DscError> {
+    let Some((type_name, require_version)) = filter.rsplit_once('@') else {
+        return Ok((filter.to_lowercase(), None));
+    };
+    match VersionReq::parse(require_version) {
+        Ok(version_req) => Ok((type_name.to_lowercase(), Some(version_req))),
+        Err(err) => Err(DscError::Validation(format!("Invalid version requirement '{require_version}' for resource '{type_name}': {err}"))),
+    }
+}
+
+// the versions of a resource are ordered newest to oldest so the first match is the newest
+pub(crate) fn find_matching_version<'a>(resources: &'a [DscResource], version_req: Option<&VersionReq>) -> Option<&'a DscResource> {
+    let Some(version_req) = version_req else {
+        return resources.first();
+    };
+    resources.iter().find(|resource| {
+        Version::parse(&resource.version).is_ok_and(|version| version_req.matches(&version))
+    })
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -121,11 +268,53 @@ mod tests {
         let regex = convert_wildcard_to_regex(wildcard);
         assert_eq!(regex, "^r.*?$");
     }
-}
 
-impl Default for Discovery {
-    fn default() -> Self {
-        Self::new().unwrap()
+    #[test]
+    fn test_parse_resource_filter() {
+        let (type_name, version_req) = parse_resource_filter("Test/Version").unwrap();
+        assert_eq!(type_name, "test/version");
+        assert!(version_req.is_none());
+
+        let filter = get_resource_filter("Test/Version", Some("^1.2"));
+        assert_eq!(filter, "Test/Version@^1.2");
+        let (type_name, version_req) = parse_resource_filter(&filter).unwrap();
+        assert_eq!(type_name, "test/version");
+        assert_eq!(version_req, Some(VersionReq::parse("^1.2").unwrap()));
+
+        assert!(parse_resource_filter("Test/Version@not a version").is_err());
+    }
+
+    #[test]
+    fn test_get_resource_without_matching_version() {
+        let mut discovery = Discovery {
+            resources: BTreeMap::new(),
+            function_providers: Vec::new(),
+            settings: DscSettings::default(),
+            blocked_resources: BTreeMap::new(),
+            unmatched_versions: BTreeMap::new(),
+        };
+        discovery.unmatched_versions.insert("test/version@^3".to_string(), vec!["2.0.0".to_string(), "1.0.0".to_string()]);
+
+        let err = discovery.get_resource("Test/Version@^3").unwrap_err();
+        assert!(matches!(err, DscError::ResourceVersionNotFound(..)));
+        assert_eq!(err.to_string(), "Resource not found: No version satisfies 'Test/Version@^3', available versions: 2.0.0, 1.0.0");
+        assert!(matches!(discovery.get_resource("Test/Version@^2"), Err(DscError::ResourceNotFound(_))));
+    }
+
+    #[test]
+    fn test_find_matching_version() {
+        let resources = ["2.0.0", "1.3.0", "1.2.0", "invalid"].iter().map(|version| DscResource {
+            version: (*version).to_string(),
+            ..Default::default()
+        }).collect::<Vec<DscResource>>();
+
+        assert_eq!(find_matching_version(&resources, None).unwrap().version, "2.0.0");
+        let version_req = VersionReq::parse("^1.2").unwrap();
+        assert_eq!(find_matching_version(&resources, Some(&version_req)).unwrap().version, "1.3.0");
+        let version_req = VersionReq::parse("=1.2.0").unwrap();
+        assert_eq!(find_matching_version(&resources, Some(&version_req)).unwrap().version, "1.2.0");
+        let version_req = VersionReq::parse(">=3").unwrap();
+        assert!(find_matching_version(&resources, Some(&version_req)).is_none());
     }
 }
 
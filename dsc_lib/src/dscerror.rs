@@ -1,12 +1,12 @@
 // Copyright (c) Microsoft Corporation.
 // Licensed under the MIT License.
 
+use std::fmt::{Display, Formatter};
 use std::str::Utf8Error;
 
 use indicatif::style::TemplateError;
 use thiserror::Error;
-use tracing::error;
-use tree_sitter::LanguageError;
+use tree_sitter::{LanguageError, Node};
 
 #[derive(Error, Debug)]
 pub enum DscError {
@@ -28,6 +28,9 @@ pub enum DscError {
     #[error("CommandOperation: {0} for executable '{1}'")]
     CommandOperation(String, String),
 
+    #[error("Encryption: {0}")]
+    Encryption(String),
+
     #[error("Function '{0}' error: {1}")]
     Function(String, String),
 
@@ -85,6 +88,12 @@ pub enum DscError {
     #[error("Parser: {0}")]
     Parser(String),
 
+    #[error("{0}")]
+    ParserAt(Box<ParserError>),
+
+    #[error("Policy: Resource '{0}' is not allowed, {1}")]
+    PolicyViolation(String, String),
+
     #[error("Progress: {0}")]
     Progress(#[from] TemplateError),
 
@@ -94,15 +103,24 @@ pub enum DscError {
     #[error("Resource manifest not found: {0}")]
     ResourceManifestNotFound(String),
 
+    #[error("Resource not found: No version satisfies '{0}', available versions: {1}")]
+    ResourceVersionNotFound(String, String),
+
     #[error("Schema: {0}")]
     Schema(String),
 
     #[error("No Schema found and `validate` is not supported: {0}")]
     SchemaNotAvailable(String),
 
+    #[error("Secret: {0}")]
+    Secret(String),
+
     #[error("Security context: {0}")]
     SecurityContext(String),
 
+    #[error("Setting: {0}")]
+    Setting(String),
+
     #[error("Utf-8 conversion error: {0}")]
     Utf8Conversion(#[from] Utf8Error),
 
@@ -118,3 +136,108 @@ pub enum DscError {
     #[error("YAML: {0}")]
     Yaml(#[from] serde_yaml::Error),
 }
+
+impl DscError {
+    /// Attach the position of the expression that caused a parser error, other errors and parser errors that already
+    /// have a position are kept as is.
+    ///
+    /// # Arguments
+    ///
+    /// * `span` - The position of the expression within the statement.
+    #[must_use]
+    pub fn with_span(self, span: SourceSpan) -> Self {
+        match self {
+            DscError::Parser(message) => DscError::ParserAt(Box::new(ParserError {
+                message,
+                statement: String::new(),
+                span,
+                location: None,
+            })),
+            error => error,
+        }
+    }
+
+    /// Attach the statement text to a parser error with a position if it doesn't have one.
+    ///
+    /// # Arguments
+    ///
+    /// * `statement` - The statement that the position of the error refers to.
+    #[must_use]
+    pub fn with_statement(mut self, statement: &str) -> Self {
+        if let DscError::ParserAt(parser_error) = &mut self {
+            if parser_error.statement.is_empty() {
+                parser_error.statement = statement.to_string();
+            }
+        }
+        self
+    }
+
+    /// Attach where a parser error with a position occurred, like the resource and property path.
+    ///
+    /// # Arguments
+    ///
+    /// * `location` - The description of the location.
+    #[must_use]
+    pub fn with_location(mut self, location: &str) -> Self {
+        if let DscError::ParserAt(parser_error) = &mut self {
+            if parser_error.location.is_none() {
+                parser_error.location = Some(location.to_string());
+            }
+        }
+        self
+    }
+}
+
+/// The byte and column range of an expression within a statement.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct SourceSpan {
+    pub start_byte: usize,
+    pub end_byte: usize,
+    pub start_column: usize,
+    pub end_column: usize,
+}
+
+impl SourceSpan {
+    /// Create a new `SourceSpan` from the range of a parsed node.
+    #[must_use]
+    pub fn from_node(node: &Node) -> Self {
+        Self {
+            start_byte: node.start_byte(),
+            end_byte: node.end_byte(),
+            start_column: node.start_position().column,
+            end_column: node.end_position().column,
+        }
+    }
+}
+
+/// A parser error along with the position of the expression where it occurred.
+#[derive(Debug)]
+pub struct ParserError {
+    /// The description of the error.
+    pub message: String,
+    /// The statement that contains the expression.
+    pub statement: String,
+    /// The position of the expression that caused the error.
+    pub span: SourceSpan,
+    /// Where the statement appears, like the resource and property path.
+    pub location: Option<String>,
+}
+
+impl Display for ParserError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "Parser: {0}", self.message)?;
+        if let Some(location) = &self.location {
+            write!(f, " in {location}")?;
+        }
+        let (Some(before), Some(within)) = (self.statement.get(..self.span.start_byte), self.statement.get(self.span.start_byte..self.span.end_byte)) else {
+            return write!(f, " at column {0}", self.span.start_column + 1);
+        };
+
+        // only the line with the start of the expression is shown
+        let line_start = before.rfind('\n').map_or(0, |index| index + 1);
+        let line = self.statement[line_start..].lines().next().unwrap_or_default();
+        let indent = before[line_start..].chars().count();
+        let width = within.lines().next().unwrap_or_default().chars().count().max(1);
+        write!(f, "\n  {line}\n  {0}{1}", " ".repeat(indent), "^".repeat(width))
+    }
+}
@@ -876,20 +876,31 @@ pub fn log_stderr_line<'a>(process_id: &u32, trace_line: &'a str) -> &'a str
     ""
 }
 
+// the lowercase names are used in the settings file
 #[derive(Clone, Debug, PartialEq, Eq, Deserialize, ValueEnum)]
 pub enum TraceLevel {
-    #[serde(rename = "ERROR")]
+    #[serde(rename = "ERROR", alias = "error")]
     Error,
-    #[serde(rename = "WARN")]
+    #[serde(rename = "WARN", alias = "warn")]
     Warn,
-    #[serde(rename = "INFO")]
+    #[serde(rename = "INFO", alias = "info")]
     Info,
-    #[serde(rename = "DEBUG")]
+    #[serde(rename = "DEBUG", alias = "debug")]
     Debug,
-    #[serde(rename = "TRACE")]
+    #[serde(rename = "TRACE", alias = "trace")]
     Trace,
 }
 
+#[derive(Clone, Debug, PartialEq, Eq, Deserialize, ValueEnum)]
+#[serde(rename_all = "camelCase")]
+pub enum TraceFormat {
+    Default,
+    Plaintext,
+    Json,
+    #[clap(hide = true)]
+    PassThrough,
+}
+
 #[derive(Deserialize)]
 struct Fields {
     message: String,
@@ -12,6 +12,7 @@ use crate::dscerror::DscError;
 #[derive(Debug, Clone, PartialEq, Deserialize, Serialize, JsonSchema)]
 pub enum Kind {
     Adapter,
+    FunctionProvider,
     Group,
     Importer,
     Resource,
@@ -61,6 +62,9 @@ pub struct ResourceManifest {
     /// Indicates the resource is a adapter of other resources.
     #[serde(skip_serializing_if = "Option::is_none")]
     pub adapter: Option<Adapter>,
+    /// Indicates the resource provides expression functions.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub functions: Option<FunctionProvider>,
     /// Mapping of exit codes to descriptions.  Zero is always success and non-zero is always failure.
     #[serde(rename = "exitCodes", skip_serializing_if = "Option::is_none")]
     pub exit_codes: Option<HashMap<i32, String>>,
@@ -237,6 +241,22 @@ pub struct Adapter {
     pub config: ConfigKind,
 }
 
+#[derive(Debug, Clone, PartialEq, Deserialize, Serialize, JsonSchema)]
+pub struct FunctionProvider {
+    /// The way to list the functions provided.
+    pub list: ListMethod,
+    /// The way to invoke a function, the name and arguments are passed as JSON via STDIN.
+    pub invoke: FunctionInvokeMethod,
+}
+
+#[derive(Debug, Clone, PartialEq, Deserialize, Serialize, JsonSchema)]
+pub struct FunctionInvokeMethod {
+    /// The command to run to invoke a function.
+    pub executable: String,
+    /// The arguments to pass to the command to invoke a function.
+    pub args: Option<Vec<String>>,
+}
+
 #[derive(Debug, Clone, PartialEq, Deserialize, Serialize, JsonSchema)]
 pub enum ConfigKind {
     /// The adapter accepts full unprocessed configuration.
@@ -0,0 +1,220 @@
+// Copyright (c) Microsoft Corporation.
+// Licensed under the MIT License.
+
+use crate::DscError;
+use crate::util::get_user_directory;
+use age::secrecy::ExposeSecret;
+use age::x25519::{Identity, Recipient};
+use base64::{Engine as _, engine::general_purpose};
+use serde_json::Value;
+use std::io::Write;
+use std::path::{Path, PathBuf};
+use std::str::FromStr;
+use tracing::debug;
+
+/// The environment variable with the path of the age key file used to decrypt secrets and values.
+pub const DSC_KEY_FILE: &str = "DSC_KEY_FILE";
+
+// encrypted values are strings like `ENC[AGE,<base64>]` so they can be used in JSON and YAML
+const ENCRYPTED_VALUE_PREFIX: &str = "ENC[AGE,";
+const ENCRYPTED_VALUE_SUFFIX: &str = "]";
+
+/// Get the path of the key file, which is `DSC_KEY_FILE` if set or `key.txt` in the user directory.
+///
+/// # Errors
+///
+/// This function will return an error if neither `DSC_KEY_FILE` nor the user directory is available.
+pub fn get_key_file_path() -> Result<PathBuf, DscError> {
+    if let Ok(path) = std::env::var(DSC_KEY_FILE) {
+        return Ok(PathBuf::from(path));
+    }
+    match get_user_directory() {
+        Some(directory) => Ok(directory.join("key.txt")),
+        None => Err(DscError::Encryption(format!("Key file not found, set '{DSC_KEY_FILE}' to its path"))),
+    }
+}
+
+/// Load the private key from an age key file, lines starting with `#` are comments.
+///
+/// # Arguments
+///
+/// * `path` - The path of the key file.
+///
+/// # Errors
+///
+/// This function will return an error if the file can't be read or doesn't contain a valid key.
+pub fn load_identity(path: &Path) -> Result<Identity, DscError> {
+    debug!("Loading key from '{}'", path.display());
+    let contents = match std::fs::read_to_string(path) {
+        Ok(contents) => contents,
+        Err(err) => return Err(DscError::Encryption(format!("Failed to read key file '{}': {err}", path.display()))),
+    };
+    let Some(key) = contents.lines().map(str::trim).find(|line| !line.is_empty() && !line.starts_with('#')) else {
+        return Err(DscError::Encryption(format!("Key file '{}' does not contain a key", path.display())));
+    };
+    Identity::from_str(key).map_err(|err| DscError::Encryption(format!("Invalid key in '{}': {err}", path.display())))
+}
+
+/// Generate a new private key and write it to a key file in the same format as `age-keygen`.
+///
+/// # Arguments
+///
+/// * `path` - The path of the key file, which must not exist.
+///
+/// # Returns
+///
+/// The public key of the new private key.
+///
+/// # Errors
+///
+/// This function will return an error if the file already exists or can't be written.
+pub fn new_key_file(path: &Path) -> Result<Recipient, DscError> {
+    let identity = Identity::generate();
+    let recipient = identity.to_public();
+    if let Some(parent) = path.parent() {
+        if !parent.as_os_str().is_empty() {
+            std::fs::create_dir_all(parent)?;
+        }
+    }
+    let mut options = std::fs::OpenOptions::new();
+    options.write(true).create_new(true);
+    // only the current user can read the private key
+    #[cfg(unix)]
+    std::os::unix::fs::OpenOptionsExt::mode(&mut options, 0o600);
+    let mut file = match options.open(path) {
+        Ok(file) => file,
+        Err(err) => return Err(DscError::Encryption(format!("Failed to create key file '{}': {err}", path.display()))),
+    };
+    writeln!(file, "# created: {}", chrono::Local::now().to_rfc3339())?;
+    writeln!(file, "# public key: {recipient}")?;
+    writeln!(file, "{}", identity.to_string().expose_secret())?;
+    debug!("Created key file '{}'", path.display());
+    Ok(recipient)
+}
+
+/// Parse the public key that values are encrypted for, like `age1...`.
+///
+/// # Arguments
+///
+/// * `recipient` - The public key.
+///
+/// # Errors
+///
+/// This function will return an error if the public key is invalid.
+pub fn parse_recipient(recipient: &str) -> Result<Recipient, DscError> {
+    Recipient::from_str(recipient.trim()).map_err(|err| DscError::Encryption(format!("Invalid public key '{recipient}': {err}")))
+}
+
+/// Encrypt data for a public key as ASCII armored text.
+///
+/// # Arguments
+///
+/// * `plaintext` - The data to encrypt.
+/// * `recipient` - The public key that can decrypt the data.
+///
+/// # Errors
+///
+/// This function will return an error if encryption fails.
+pub fn encrypt(plaintext: &[u8], recipient: &Recipient) -> Result<String, DscError> {
+    age::encrypt_and_armor(recipient, plaintext).map_err(|err| DscError::Encryption(err.to_string()))
+}
+
+/// Decrypt data that is binary or ASCII armored.
+///
+/// # Arguments
+///
+/// * `ciphertext` - The encrypted data.
+/// * `identity` - The private key.
+///
+/// # Errors
+///
+/// This function will return an error if the data isn't encrypted for the key.
+pub fn decrypt(ciphertext: &[u8], identity: &Identity) -> Result<Vec<u8>, DscError> {
+    age::decrypt(identity, ciphertext).map_err(|err| DscError::Encryption(format!("Failed to decrypt: {err}")))
+}
+
+/// Check if a string is a value encrypted by `encrypt_value()`.
+///
+/// # Arguments
+///
+/// * `value` - The string to check.
+#[must_use]
+pub fn is_encrypted_value(value: &str) -> bool {
+    value.starts_with(ENCRYPTED_VALUE_PREFIX) && value.ends_with(ENCRYPTED_VALUE_SUFFIX)
+}
+
+/// Encrypt a JSON value for a public key as a string like `ENC[AGE,<base64>]`.
+///
+/// # Arguments
+///
+/// * `value` - The value to encrypt, which keeps its type when decrypted.
+/// * `recipient` - The public key that can decrypt the value.
+///
+/// # Errors
+///
+/// This function will return an error if encryption fails.
+pub fn encrypt_value(value: &Value, recipient: &Recipient) -> Result<String, DscError> {
+    let plaintext = serde_json::to_string(value)?;
+    let encrypted = age::encrypt(recipient, plaintext.as_bytes()).map_err(|err| DscError::Encryption(err.to_string()))?;
+    Ok(format!("{ENCRYPTED_VALUE_PREFIX}{}{ENCRYPTED_VALUE_SUFFIX}", general_purpose::STANDARD.encode(encrypted)))
+}
+
+/// Decrypt a value encrypted by `encrypt_value()`.
+///
+/// # Arguments
+///
+/// * `value` - The encrypted value.
+/// * `identity` - The private key.
+///
+/// # Errors
+///
+/// This function will return an error if the value isn't an encrypted value or isn't encrypted for the key.
+pub fn decrypt_value(value: &str, identity: &Identity) -> Result<Value, DscError> {
+    let Some(encoded) = value.strip_prefix(ENCRYPTED_VALUE_PREFIX).and_then(|value| value.strip_suffix(ENCRYPTED_VALUE_SUFFIX)) else {
+        return Err(DscError::Encryption("Value is not encrypted".to_string()));
+    };
+    let Ok(encrypted) = general_purpose::STANDARD.decode(encoded) else {
+        return Err(DscError::Encryption("Encrypted value is not valid base64".to_string()));
+    };
+    let decrypted = decrypt(&encrypted, identity)?;
+    Ok(serde_json::from_slice(&decrypted)?)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn encrypt_and_decrypt() {
+        let identity = Identity::generate();
+        let encrypted = encrypt(b"secret value", &identity.to_public()).unwrap();
+        assert!(encrypted.starts_with("-----BEGIN AGE ENCRYPTED FILE-----"));
+        assert_eq!(decrypt(encrypted.as_bytes(), &identity).unwrap(), b"secret value");
+    }
+
+    #[test]
+    fn encrypt_and_decrypt_value() {
+        let identity = Identity::generate();
+        let value = serde_json::json!({"user": "admin", "pin": 1234});
+        let encrypted = encrypt_value(&value, &identity.to_public()).unwrap();
+        assert!(is_encrypted_value(&encrypted));
+        assert_eq!(decrypt_value(&encrypted, &identity).unwrap(), value);
+        assert!(!is_encrypted_value("plain text"));
+    }
+
+    #[test]
+    fn new_key_file_can_be_loaded() {
+        let path = std::env::temp_dir().join(format!("dsc-key-{}.txt", std::process::id()));
+        let recipient = new_key_file(&path).unwrap();
+        let identity = load_identity(&path);
+        assert!(new_key_file(&path).is_err());
+        std::fs::remove_file(&path).unwrap();
+        assert_eq!(identity.unwrap().to_public().to_string(), recipient.to_string());
+    }
+
+    #[test]
+    fn decrypt_with_other_key() {
+        let encrypted = encrypt(b"secret value", &Identity::generate().to_public()).unwrap();
+        assert!(decrypt(encrypted.as_bytes(), &Identity::generate()).is_err());
+    }
+}
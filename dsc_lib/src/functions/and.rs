@@ -0,0 +1,88 @@
+// Copyright (c) Microsoft Corporation.
+// Licensed under the MIT License.
+
+use crate::DscError;
+use crate::configure::context::Context;
+use crate::functions::{AcceptedArgKind, Function, FunctionCategory};
+use serde_json::Value;
+use tracing::debug;
+
+#[derive(Debug, Default)]
+pub struct And {}
+
+impl Function for And {
+    fn min_args(&self) -> usize {
+        2
+    }
+
+    fn max_args(&self) -> usize {
+        usize::MAX
+    }
+
+    fn accepted_arg_types(&self) -> Vec<AcceptedArgKind> {
+        vec![AcceptedArgKind::Boolean]
+    }
+
+    fn description(&self) -> String {
+        "Returns true if all values are true.".to_string()
+    }
+
+    fn category(&self) -> FunctionCategory {
+        FunctionCategory::Logical
+    }
+
+    fn parameter_names(&self) -> Vec<&str> {
+        vec!["arg1", "arg2"]
+    }
+
+    fn return_types(&self) -> Vec<AcceptedArgKind> {
+        vec![AcceptedArgKind::Boolean]
+    }
+
+    fn invoke(&self, args: &[Value], _context: &Context) -> Result<Value, DscError> {
+        debug!("and function");
+        for arg in args {
+            let Some(value) = arg.as_bool() else {
+                return Err(DscError::Parser("Invalid argument(s)".to_string()));
+            };
+            if !value {
+                return Ok(Value::Bool(false));
+            }
+        }
+        Ok(Value::Bool(true))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::configure::context::Context;
+    use crate::parser::Statement;
+
+    #[test]
+    fn all_true() {
+        let mut parser = Statement::new().unwrap();
+        let result = parser.parse_and_execute("[and(true, true, true)]", &Context::new()).unwrap();
+        assert_eq!(result, true);
+    }
+
+    #[test]
+    fn one_false() {
+        let mut parser = Statement::new().unwrap();
+        let result = parser.parse_and_execute("[and(true, false)]", &Context::new()).unwrap();
+        assert_eq!(result, false);
+    }
+
+    #[test]
+    fn invalid_one_parameter() {
+        let mut parser = Statement::new().unwrap();
+        let result = parser.parse_and_execute("[and(true)]", &Context::new());
+        assert!(result.is_err());
+    }
+
+    #[test]
+    fn number() {
+        let mut parser = Statement::new().unwrap();
+        let result = parser.parse_and_execute("[and(true, 1)]", &Context::new());
+        assert!(result.is_err());
+    }
+}
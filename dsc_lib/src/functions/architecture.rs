@@ -0,0 +1,71 @@
+// Copyright (c) Microsoft Corporation.
+// Licensed under the MIT License.
+
+use crate::DscError;
+use crate::configure::context::Context;
+use crate::functions::{AcceptedArgKind, Function, FunctionCategory};
+use serde_json::Value;
+use tracing::debug;
+
+#[derive(Debug, Default)]
+pub struct Architecture {}
+
+impl Function for Architecture {
+    fn min_args(&self) -> usize {
+        0
+    }
+
+    fn max_args(&self) -> usize {
+        0
+    }
+
+    fn accepted_arg_types(&self) -> Vec<AcceptedArgKind> {
+        vec![]
+    }
+
+    fn description(&self) -> String {
+        "Returns the processor architecture of the host.".to_string()
+    }
+
+    fn category(&self) -> FunctionCategory {
+        FunctionCategory::System
+    }
+
+    fn parameter_names(&self) -> Vec<&str> {
+        vec![]
+    }
+
+    fn return_types(&self) -> Vec<AcceptedArgKind> {
+        vec![AcceptedArgKind::String]
+    }
+
+    fn invoke(&self, _args: &[Value], _context: &Context) -> Result<Value, DscError> {
+        debug!("architecture function");
+        // the OS reported architecture matches `os().architecture`, otherwise fall back to the architecture DSC was built for
+        let architecture = match os_info::get().architecture() {
+            Some(architecture) => architecture.to_string(),
+            None => std::env::consts::ARCH.to_string(),
+        };
+        Ok(Value::String(architecture))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::configure::context::Context;
+    use crate::parser::Statement;
+
+    #[test]
+    fn not_empty() {
+        let mut parser = Statement::new().unwrap();
+        let result = parser.parse_and_execute("[architecture()]", &Context::new()).unwrap();
+        assert!(!result.as_str().unwrap().is_empty());
+    }
+
+    #[test]
+    fn with_args() {
+        let mut parser = Statement::new().unwrap();
+        let result = parser.parse_and_execute("[architecture('x64')]", &Context::new());
+        assert!(result.is_err());
+    }
+}
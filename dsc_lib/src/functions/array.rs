@@ -0,0 +1,76 @@
+// Copyright (c) Microsoft Corporation.
+// Licensed under the MIT License.
+
+use crate::DscError;
+use crate::configure::context::Context;
+use crate::functions::{AcceptedArgKind, Function, FunctionCategory};
+use serde_json::Value;
+use tracing::debug;
+
+#[derive(Debug, Default)]
+pub struct Array {}
+
+impl Function for Array {
+    fn min_args(&self) -> usize {
+        1
+    }
+
+    fn max_args(&self) -> usize {
+        1
+    }
+
+    fn accepted_arg_types(&self) -> Vec<AcceptedArgKind> {
+        vec![AcceptedArgKind::Array, AcceptedArgKind::Boolean, AcceptedArgKind::Number, AcceptedArgKind::Object, AcceptedArgKind::String]
+    }
+
+    fn description(&self) -> String {
+        "Converts the value to an array.".to_string()
+    }
+
+    fn category(&self) -> FunctionCategory {
+        FunctionCategory::Array
+    }
+
+    fn parameter_names(&self) -> Vec<&str> {
+        vec!["valueToConvert"]
+    }
+
+    fn return_types(&self) -> Vec<AcceptedArgKind> {
+        vec![AcceptedArgKind::Array]
+    }
+
+    fn invoke(&self, args: &[Value], _context: &Context) -> Result<Value, DscError> {
+        debug!("array function");
+        match &args[0] {
+            Value::Array(array) => Ok(Value::Array(array.clone())),
+            value => Ok(Value::Array(vec![value.clone()])),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::configure::context::Context;
+    use crate::parser::Statement;
+
+    #[test]
+    fn string() {
+        let mut parser = Statement::new().unwrap();
+        let result = parser.parse_and_execute("[array('a')]", &Context::new()).unwrap();
+        assert_eq!(result.to_string(), r#"["a"]"#);
+    }
+
+    #[test]
+    fn object() {
+        let mut parser = Statement::new().unwrap();
+        let result = parser.parse_and_execute("[array(createObject('a', 1))]", &Context::new()).unwrap();
+        assert_eq!(result.to_string(), r#"[{"a":1}]"#);
+    }
+
+    #[test]
+    fn array() {
+        let mut parser = Statement::new().unwrap();
+        let result = parser.parse_and_execute("[array(createArray(1, 2))]", &Context::new()).unwrap();
+        assert_eq!(result.to_string(), "[1,2]");
+    }
+}
@@ -5,7 +5,7 @@ use base64::{Engine as _, engine::general_purpose};
 
 use crate::DscError;
 use crate::configure::context::Context;
-use crate::functions::AcceptedArgKind;
+use crate::functions::{AcceptedArgKind, FunctionCategory};
 use serde_json::Value;
 use super::Function;
 
@@ -17,6 +17,22 @@ impl Function for Base64 {
         vec![AcceptedArgKind::String]
     }
 
+    fn description(&self) -> String {
+        "Returns the base64 representation of a string.".to_string()
+    }
+
+    fn category(&self) -> FunctionCategory {
+        FunctionCategory::String
+    }
+
+    fn parameter_names(&self) -> Vec<&str> {
+        vec!["inputString"]
+    }
+
+    fn return_types(&self) -> Vec<AcceptedArgKind> {
+        vec![AcceptedArgKind::String]
+    }
+
     fn min_args(&self) -> usize {
         1
     }
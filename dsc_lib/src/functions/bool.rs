@@ -0,0 +1,89 @@
+// Copyright (c) Microsoft Corporation.
+// Licensed under the MIT License.
+
+use crate::DscError;
+use crate::configure::context::Context;
+use crate::functions::{AcceptedArgKind, Function, FunctionCategory};
+use serde_json::Value;
+use tracing::debug;
+
+#[derive(Debug, Default)]
+pub struct Bool {}
+
+impl Function for Bool {
+    fn min_args(&self) -> usize {
+        1
+    }
+
+    fn max_args(&self) -> usize {
+        1
+    }
+
+    fn accepted_arg_types(&self) -> Vec<AcceptedArgKind> {
+        vec![AcceptedArgKind::Boolean, AcceptedArgKind::Number, AcceptedArgKind::String]
+    }
+
+    fn description(&self) -> String {
+        "Converts the value to a boolean.".to_string()
+    }
+
+    fn category(&self) -> FunctionCategory {
+        FunctionCategory::Logical
+    }
+
+    fn parameter_names(&self) -> Vec<&str> {
+        vec!["arg1"]
+    }
+
+    fn return_types(&self) -> Vec<AcceptedArgKind> {
+        vec![AcceptedArgKind::Boolean]
+    }
+
+    fn invoke(&self, args: &[Value], _context: &Context) -> Result<Value, DscError> {
+        debug!("bool function");
+        match &args[0] {
+            Value::Bool(value) => Ok(Value::Bool(*value)),
+            Value::String(value) => {
+                match value.to_lowercase().as_str() {
+                    "true" => Ok(Value::Bool(true)),
+                    "false" => Ok(Value::Bool(false)),
+                    _ => Err(DscError::FunctionArg("bool".to_string(), format!("unable to convert string '{value}' to boolean"))),
+                }
+            },
+            Value::Number(value) => {
+                let Some(value) = value.as_i64() else {
+                    return Err(DscError::FunctionArg("bool".to_string(), "unable to convert number to boolean".to_string()));
+                };
+                Ok(Value::Bool(value != 0))
+            },
+            _ => Err(DscError::FunctionArg("bool".to_string(), "Invalid argument type".to_string())),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::configure::context::Context;
+    use crate::parser::Statement;
+
+    #[test]
+    fn string() {
+        let mut parser = Statement::new().unwrap();
+        let result = parser.parse_and_execute("[bool('True')]", &Context::new()).unwrap();
+        assert_eq!(result, true);
+    }
+
+    #[test]
+    fn number() {
+        let mut parser = Statement::new().unwrap();
+        let result = parser.parse_and_execute("[bool(0)]", &Context::new()).unwrap();
+        assert_eq!(result, false);
+    }
+
+    #[test]
+    fn invalid_string() {
+        let mut parser = Statement::new().unwrap();
+        let result = parser.parse_and_execute("[bool('yes')]", &Context::new());
+        assert!(result.is_err());
+    }
+}
@@ -0,0 +1,78 @@
+// Copyright (c) Microsoft Corporation.
+// Licensed under the MIT License.
+
+use crate::DscError;
+use crate::configure::context::Context;
+use crate::functions::{AcceptedArgKind, Function, FunctionCategory};
+use serde_json::Value;
+use tracing::debug;
+
+#[derive(Debug, Default)]
+pub struct Coalesce {}
+
+impl Function for Coalesce {
+    fn min_args(&self) -> usize {
+        1
+    }
+
+    fn max_args(&self) -> usize {
+        usize::MAX
+    }
+
+    fn accepted_arg_types(&self) -> Vec<AcceptedArgKind> {
+        vec![AcceptedArgKind::Array, AcceptedArgKind::Boolean, AcceptedArgKind::Number, AcceptedArgKind::Object, AcceptedArgKind::String]
+    }
+
+    fn description(&self) -> String {
+        "Returns the first value that isn't null.".to_string()
+    }
+
+    fn category(&self) -> FunctionCategory {
+        FunctionCategory::Logical
+    }
+
+    fn parameter_names(&self) -> Vec<&str> {
+        vec!["arg1"]
+    }
+
+    fn return_types(&self) -> Vec<AcceptedArgKind> {
+        vec![AcceptedArgKind::Array, AcceptedArgKind::Boolean, AcceptedArgKind::Number, AcceptedArgKind::Object, AcceptedArgKind::String]
+    }
+
+    fn invoke(&self, args: &[Value], _context: &Context) -> Result<Value, DscError> {
+        debug!("coalesce function");
+        Ok(args.iter().find(|arg| !arg.is_null()).cloned().unwrap_or(Value::Null))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::configure::context::Context;
+    use crate::parser::Statement;
+    use serde_json::Value;
+
+    #[test]
+    fn first_non_null() {
+        let mut parser = Statement::new().unwrap();
+        let mut context = Context::new();
+        context.variables.insert("empty".to_string(), Value::Null);
+        let result = parser.parse_and_execute("[coalesce(variables('empty'), 'a', 'b')]", &context).unwrap();
+        assert_eq!(result, "a");
+    }
+
+    #[test]
+    fn all_null() {
+        let mut parser = Statement::new().unwrap();
+        let mut context = Context::new();
+        context.variables.insert("empty".to_string(), Value::Null);
+        let result = parser.parse_and_execute("[coalesce(variables('empty'))]", &context).unwrap();
+        assert_eq!(result, Value::Null);
+    }
+
+    #[test]
+    fn first_value() {
+        let mut parser = Statement::new().unwrap();
+        let result = parser.parse_and_execute("[coalesce(1, 'a')]", &Context::new()).unwrap();
+        assert_eq!(result, 1);
+    }
+}
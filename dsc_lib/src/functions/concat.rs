@@ -3,7 +3,7 @@
 
 use crate::DscError;
 use crate::configure::context::Context;
-use crate::functions::{AcceptedArgKind, Function};
+use crate::functions::{AcceptedArgKind, Function, FunctionCategory};
 use serde_json::Value;
 use tracing::debug;
 
@@ -23,6 +23,22 @@ impl Function for Concat {
         vec![AcceptedArgKind::String, AcceptedArgKind::Array]
     }
 
+    fn description(&self) -> String {
+        "Combines multiple strings or arrays.".to_string()
+    }
+
+    fn category(&self) -> FunctionCategory {
+        FunctionCategory::String
+    }
+
+    fn parameter_names(&self) -> Vec<&str> {
+        vec!["arg1", "arg2"]
+    }
+
+    fn return_types(&self) -> Vec<AcceptedArgKind> {
+        vec![AcceptedArgKind::Array, AcceptedArgKind::String]
+    }
+
     fn invoke(&self, args: &[Value], _context: &Context) -> Result<Value, DscError> {
         debug!("concat function");
         let mut string_result = String::new();
@@ -0,0 +1,71 @@
+// Copyright (c) Microsoft Corporation.
+// Licensed under the MIT License.
+
+use crate::DscError;
+use crate::configure::context::Context;
+use crate::functions::{AcceptedArgKind, Function, FunctionCategory, get_config_root};
+use serde_json::Value;
+use tracing::debug;
+
+#[derive(Debug, Default)]
+pub struct ConfigRoot {}
+
+impl Function for ConfigRoot {
+    fn min_args(&self) -> usize {
+        0
+    }
+
+    fn max_args(&self) -> usize {
+        0
+    }
+
+    fn accepted_arg_types(&self) -> Vec<AcceptedArgKind> {
+        vec![]
+    }
+
+    fn description(&self) -> String {
+        "Returns the directory of the configuration document.".to_string()
+    }
+
+    fn category(&self) -> FunctionCategory {
+        FunctionCategory::Deployment
+    }
+
+    fn parameter_names(&self) -> Vec<&str> {
+        vec![]
+    }
+
+    fn return_types(&self) -> Vec<AcceptedArgKind> {
+        vec![AcceptedArgKind::String]
+    }
+
+    fn invoke(&self, _args: &[Value], context: &Context) -> Result<Value, DscError> {
+        debug!("configRoot function");
+        Ok(Value::String(get_config_root("configRoot", context)?.to_string_lossy().into_owned()))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::configure::context::Context;
+    use crate::parser::Statement;
+    use std::path::PathBuf;
+
+    #[test]
+    fn from_context() {
+        let mut parser = Statement::new().unwrap();
+        let mut context = Context::new();
+        context.config_root = Some(PathBuf::from("/configs"));
+        let result = parser.parse_and_execute("[configRoot()]", &context).unwrap();
+        assert_eq!(result, "/configs");
+    }
+
+    #[test]
+    fn current_directory() {
+        let mut parser = Statement::new().unwrap();
+        let mut context = Context::new();
+        context.config_root = None;
+        let result = parser.parse_and_execute("[configRoot()]", &context).unwrap();
+        assert_eq!(result, std::env::current_dir().unwrap().to_string_lossy().as_ref());
+    }
+}
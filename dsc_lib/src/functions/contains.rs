@@ -0,0 +1,121 @@
+// Copyright (c) Microsoft Corporation.
+// Licensed under the MIT License.
+
+use crate::DscError;
+use crate::configure::context::Context;
+use crate::functions::{AcceptedArgKind, Function, FunctionCategory};
+use serde_json::Value;
+use tracing::debug;
+
+#[derive(Debug, Default)]
+pub struct Contains {}
+
+impl Function for Contains {
+    fn min_args(&self) -> usize {
+        2
+    }
+
+    fn max_args(&self) -> usize {
+        2
+    }
+
+    fn accepted_arg_types(&self) -> Vec<AcceptedArgKind> {
+        vec![AcceptedArgKind::Array, AcceptedArgKind::Boolean, AcceptedArgKind::Number, AcceptedArgKind::Object, AcceptedArgKind::String]
+    }
+
+    fn description(&self) -> String {
+        "Checks whether an array contains a value, an object contains a key or a string contains a substring.".to_string()
+    }
+
+    fn category(&self) -> FunctionCategory {
+        FunctionCategory::Array
+    }
+
+    fn parameter_names(&self) -> Vec<&str> {
+        vec!["container", "itemToFind"]
+    }
+
+    fn parameter_types(&self) -> Vec<Vec<AcceptedArgKind>> {
+        vec![
+            vec![AcceptedArgKind::Array, AcceptedArgKind::Object, AcceptedArgKind::String],
+            vec![AcceptedArgKind::Array, AcceptedArgKind::Boolean, AcceptedArgKind::Number, AcceptedArgKind::Object, AcceptedArgKind::String],
+        ]
+    }
+
+    fn return_types(&self) -> Vec<AcceptedArgKind> {
+        vec![AcceptedArgKind::Boolean]
+    }
+
+    fn invoke(&self, args: &[Value], _context: &Context) -> Result<Value, DscError> {
+        debug!("contains function");
+        let item = &args[1];
+        match &args[0] {
+            Value::Array(array) => Ok(Value::Bool(array.contains(item))),
+            Value::Object(object) => {
+                let Some(key) = item.as_str() else {
+                    return Err(DscError::Parser("Key to find in an object must be a string".to_string()));
+                };
+                Ok(Value::Bool(object.contains_key(key)))
+            },
+            Value::String(string) => {
+                let value = match item {
+                    Value::String(value) => value.clone(),
+                    Value::Number(value) => value.to_string(),
+                    _ => {
+                        return Err(DscError::Parser("Value to find in a string must be a string or number".to_string()));
+                    }
+                };
+                Ok(Value::Bool(string.contains(&value)))
+            },
+            _ => Err(DscError::Parser("Container must be an array, object or string".to_string())),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::configure::context::Context;
+    use crate::parser::Statement;
+
+    #[test]
+    fn array() {
+        let mut parser = Statement::new().unwrap();
+        let result = parser.parse_and_execute("[contains(createArray('a', 'b'), 'b')]", &Context::new()).unwrap();
+        assert_eq!(result, true);
+    }
+
+    #[test]
+    fn array_not_found() {
+        let mut parser = Statement::new().unwrap();
+        let result = parser.parse_and_execute("[contains(createArray(1, 2), 3)]", &Context::new()).unwrap();
+        assert_eq!(result, false);
+    }
+
+    #[test]
+    fn object() {
+        let mut parser = Statement::new().unwrap();
+        let result = parser.parse_and_execute("[contains(createObject('a', 1), 'a')]", &Context::new()).unwrap();
+        assert_eq!(result, true);
+    }
+
+    #[test]
+    fn string() {
+        let mut parser = Statement::new().unwrap();
+        let result = parser.parse_and_execute("[contains('hello', 'ell')]", &Context::new()).unwrap();
+        assert_eq!(result, true);
+    }
+
+    #[test]
+    fn string_case_sensitive() {
+        let mut parser = Statement::new().unwrap();
+        let result = parser.parse_and_execute("[contains('hello', 'ELL')]", &Context::new()).unwrap();
+        assert_eq!(result, false);
+    }
+
+    #[test]
+    fn number_container() {
+        let mut parser = Statement::new().unwrap();
+        let result = parser.parse_and_execute("[contains(1, 1)]", &Context::new());
+        assert!(result.is_err());
+    }
+}
@@ -0,0 +1,85 @@
+// Copyright (c) Microsoft Corporation.
+// Licensed under the MIT License.
+
+use crate::DscError;
+use crate::configure::context::Context;
+use crate::functions::{AcceptedArgKind, Function, FunctionCategory};
+use serde_json::{Map, Value};
+use tracing::debug;
+
+#[derive(Debug, Default)]
+pub struct ContextFn {}
+
+impl Function for ContextFn {
+    fn min_args(&self) -> usize {
+        0
+    }
+
+    fn max_args(&self) -> usize {
+        0
+    }
+
+    fn accepted_arg_types(&self) -> Vec<AcceptedArgKind> {
+        vec![]
+    }
+
+    fn description(&self) -> String {
+        "Returns information about the current operation.".to_string()
+    }
+
+    fn category(&self) -> FunctionCategory {
+        FunctionCategory::Deployment
+    }
+
+    fn parameter_names(&self) -> Vec<&str> {
+        vec![]
+    }
+
+    fn return_types(&self) -> Vec<AcceptedArgKind> {
+        vec![AcceptedArgKind::Object]
+    }
+
+    fn invoke(&self, _args: &[Value], context: &Context) -> Result<Value, DscError> {
+        debug!("context function");
+        // same property names as the `Microsoft.DSC` metadata of the result
+        let mut result = Map::new();
+        result.insert("executionType".to_string(), serde_json::to_value(&context.execution_type)?);
+        result.insert("operation".to_string(), serde_json::to_value(&context.operation)?);
+        result.insert("securityContext".to_string(), serde_json::to_value(&context.security_context)?);
+        Ok(Value::Object(result))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::configure::config_doc::{ExecutionKind, Operation, SecurityContextKind};
+    use crate::configure::context::Context;
+    use crate::parser::Statement;
+
+    #[test]
+    fn what_if_set() {
+        let mut parser = Statement::new().unwrap();
+        let mut context = Context::new();
+        context.execution_type = ExecutionKind::WhatIf;
+        context.operation = Some(Operation::Set);
+        context.security_context = SecurityContextKind::Restricted;
+        let result = parser.parse_and_execute("[context()]", &context).unwrap();
+        assert_eq!(result.to_string(), r#"{"executionType":"WhatIf","operation":"Set","securityContext":"Restricted"}"#);
+    }
+
+    #[test]
+    fn no_operation() {
+        let mut parser = Statement::new().unwrap();
+        let result = parser.parse_and_execute("[context().operation]", &Context::new()).unwrap();
+        assert!(result.is_null());
+    }
+
+    #[test]
+    fn conditional() {
+        let mut parser = Statement::new().unwrap();
+        let mut context = Context::new();
+        context.execution_type = ExecutionKind::WhatIf;
+        let result = parser.parse_and_execute("[if(equals(context().executionType, 'WhatIf'), 'preview', 'apply')]", &context).unwrap();
+        assert_eq!(result, "preview");
+    }
+}
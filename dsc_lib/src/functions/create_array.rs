@@ -3,7 +3,7 @@
 
 use crate::DscError;
 use crate::configure::context::Context;
-use crate::functions::{AcceptedArgKind, Function};
+use crate::functions::{AcceptedArgKind, Function, FunctionCategory};
 use serde_json::Value;
 use tracing::debug;
 
@@ -23,6 +23,22 @@ impl Function for CreateArray {
         vec![AcceptedArgKind::String, AcceptedArgKind::Number, AcceptedArgKind::Object, AcceptedArgKind::Array]
     }
 
+    fn description(&self) -> String {
+        "Creates an array from the values.".to_string()
+    }
+
+    fn category(&self) -> FunctionCategory {
+        FunctionCategory::Array
+    }
+
+    fn parameter_names(&self) -> Vec<&str> {
+        vec!["arg1"]
+    }
+
+    fn return_types(&self) -> Vec<AcceptedArgKind> {
+        vec![AcceptedArgKind::Array]
+    }
+
     fn invoke(&self, args: &[Value], _context: &Context) -> Result<Value, DscError> {
         debug!("createArray function");
         let mut array_result = Vec::<Value>::new();
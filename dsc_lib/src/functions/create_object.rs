@@ -0,0 +1,106 @@
+// Copyright (c) Microsoft Corporation.
+// Licensed under the MIT License.
+
+use crate::DscError;
+use crate::configure::context::Context;
+use crate::functions::{AcceptedArgKind, Function, FunctionCategory};
+use serde_json::{Map, Value};
+use tracing::debug;
+
+#[derive(Debug, Default)]
+pub struct CreateObject {}
+
+impl Function for CreateObject {
+    fn min_args(&self) -> usize {
+        0
+    }
+
+    fn max_args(&self) -> usize {
+        usize::MAX
+    }
+
+    fn accepted_arg_types(&self) -> Vec<AcceptedArgKind> {
+        vec![AcceptedArgKind::Array, AcceptedArgKind::Boolean, AcceptedArgKind::Number, AcceptedArgKind::Object, AcceptedArgKind::String]
+    }
+
+    fn description(&self) -> String {
+        "Creates an object from pairs of keys and values.".to_string()
+    }
+
+    fn category(&self) -> FunctionCategory {
+        FunctionCategory::Object
+    }
+
+    fn parameter_names(&self) -> Vec<&str> {
+        vec!["key1", "value1"]
+    }
+
+    fn parameter_types(&self) -> Vec<Vec<AcceptedArgKind>> {
+        vec![
+            vec![AcceptedArgKind::String],
+            vec![AcceptedArgKind::Array, AcceptedArgKind::Boolean, AcceptedArgKind::Number, AcceptedArgKind::Object, AcceptedArgKind::String],
+        ]
+    }
+
+    fn return_types(&self) -> Vec<AcceptedArgKind> {
+        vec![AcceptedArgKind::Object]
+    }
+
+    fn invoke(&self, args: &[Value], _context: &Context) -> Result<Value, DscError> {
+        debug!("createObject function");
+        if args.len() % 2 == 1 {
+            return Err(DscError::Parser("Arguments must be key and value pairs".to_string()));
+        }
+
+        let mut object = Map::new();
+        for pair in args.chunks(2) {
+            let Some(key) = pair[0].as_str() else {
+                return Err(DscError::Parser("Object keys must be strings".to_string()));
+            };
+            object.insert(key.to_string(), pair[1].clone());
+        }
+
+        Ok(Value::Object(object))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::configure::context::Context;
+    use crate::parser::Statement;
+
+    #[test]
+    fn pairs() {
+        let mut parser = Statement::new().unwrap();
+        let result = parser.parse_and_execute("[createObject('a', 1, 'b', createArray('c'))]", &Context::new()).unwrap();
+        assert_eq!(result.to_string(), r#"{"a":1,"b":["c"]}"#);
+    }
+
+    #[test]
+    fn empty() {
+        let mut parser = Statement::new().unwrap();
+        let result = parser.parse_and_execute("[createObject()]", &Context::new()).unwrap();
+        assert_eq!(result.to_string(), "{}");
+    }
+
+    #[test]
+    fn with_accessor() {
+        let mut parser = Statement::new().unwrap();
+        let result = parser.parse_and_execute("[createObject('a', 'b').a]", &Context::new()).unwrap();
+        assert_eq!(result, "b");
+    }
+
+    #[test]
+    fn odd_arguments() {
+        let mut parser = Statement::new().unwrap();
+        let result = parser.parse_and_execute("[createObject('a', 1, 'b')]", &Context::new());
+        assert!(result.is_err());
+    }
+
+    #[test]
+    fn non_string_key() {
+        let mut parser = Statement::new().unwrap();
+        let result = parser.parse_and_execute("[createObject(1, 'a')]", &Context::new());
+        assert!(result.is_err());
+    }
+}
@@ -0,0 +1,215 @@
+// Copyright (c) Microsoft Corporation.
+// Licensed under the MIT License.
+
+use crate::DscError;
+use crate::configure::context::Context;
+use crate::functions::{AcceptedArgKind, Function, FunctionCategory};
+use crate::functions::utc_now::format_datetime;
+use chrono::{DateTime, FixedOffset, Months, NaiveDateTime, SecondsFormat, TimeDelta};
+use serde_json::Value;
+use tracing::debug;
+
+#[derive(Debug, Default)]
+pub struct DateTimeAdd {}
+
+impl Function for DateTimeAdd {
+    fn min_args(&self) -> usize {
+        2
+    }
+
+    fn max_args(&self) -> usize {
+        3
+    }
+
+    fn accepted_arg_types(&self) -> Vec<AcceptedArgKind> {
+        vec![AcceptedArgKind::String]
+    }
+
+    fn description(&self) -> String {
+        "Adds an ISO 8601 duration to a date and time.".to_string()
+    }
+
+    fn category(&self) -> FunctionCategory {
+        FunctionCategory::Date
+    }
+
+    fn parameter_names(&self) -> Vec<&str> {
+        vec!["base", "duration", "format"]
+    }
+
+    fn return_types(&self) -> Vec<AcceptedArgKind> {
+        vec![AcceptedArgKind::String]
+    }
+
+    fn invoke(&self, args: &[Value], _context: &Context) -> Result<Value, DscError> {
+        debug!("dateTimeAdd function");
+        let (Some(base), Some(duration)) = (args[0].as_str(), args[1].as_str()) else {
+            return Err(DscError::Parser("Invalid argument(s)".to_string()));
+        };
+        let datetime = parse_datetime(base)?;
+        let result = add_duration(&datetime, duration)?;
+
+        match args.get(2).and_then(Value::as_str) {
+            Some(format) => Ok(Value::String(format_datetime(&result, format)?)),
+            None => Ok(Value::String(result.to_rfc3339_opts(SecondsFormat::AutoSi, true))),
+        }
+    }
+}
+
+/// Parse a date and time in ISO 8601 format, values without an offset are treated as UTC.
+///
+/// # Arguments
+///
+/// * `value` - The date and time to parse, like `2024-03-05T07:08:09Z` or `20240305T070809Z`.
+///
+/// # Errors
+///
+/// This function will return an error if the value is not a valid date and time.
+pub(crate) fn parse_datetime(value: &str) -> Result<DateTime<FixedOffset>, DscError> {
+    if let Ok(datetime) = DateTime::parse_from_rfc3339(value) {
+        return Ok(datetime);
+    }
+
+    // also accept the default format of `utcNow()` and values without an offset
+    let naive = value.strip_suffix('Z').unwrap_or(value);
+    for format in ["%Y%m%dT%H%M%S", "%Y-%m-%dT%H:%M:%S%.f", "%Y-%m-%d %H:%M:%S%.f"] {
+        if let Ok(datetime) = NaiveDateTime::parse_from_str(naive, format) {
+            return Ok(datetime.and_utc().fixed_offset());
+        }
+    }
+
+    Err(DscError::Parser(format!("Invalid date and time '{value}'")))
+}
+
+fn add_duration(datetime: &DateTime<FixedOffset>, duration: &str) -> Result<DateTime<FixedOffset>, DscError> {
+    let invalid_duration = || DscError::Parser(format!("Invalid ISO 8601 duration '{duration}'"));
+    let (negative, value) = match duration.strip_prefix('-') {
+        Some(value) => (true, value),
+        None => (false, duration),
+    };
+    let Some(value) = value.strip_prefix('P') else {
+        return Err(invalid_duration());
+    };
+
+    // designators must appear in this order and each at most once
+    let designators = ['Y', 'M', 'W', 'D', 'T', 'H', 'M', 'S'];
+    let mut position = 0;
+    let mut months: u32 = 0;
+    let mut delta = TimeDelta::zero();
+    let mut number = String::new();
+    for c in value.chars() {
+        if c.is_ascii_digit() || c == '.' {
+            number.push(c);
+            continue;
+        }
+
+        let Some(index) = designators.iter().skip(position).position(|d| *d == c).map(|index| index + position) else {
+            return Err(invalid_duration());
+        };
+        // hours, minutes and seconds must follow the time designator
+        if index > 4 && position <= 4 {
+            return Err(invalid_duration());
+        }
+        position = index + 1;
+        if c == 'T' {
+            if !number.is_empty() {
+                return Err(invalid_duration());
+            }
+            continue;
+        }
+
+        // only seconds can have a fraction
+        let (whole, fraction) = match number.split_once('.') {
+            Some((whole, fraction)) if c == 'S' && !fraction.is_empty() => (whole, fraction),
+            Some(_) => return Err(invalid_duration()),
+            None => (number.as_str(), ""),
+        };
+        let whole = whole.parse::<u32>().map_err(|_| invalid_duration())?;
+        let (added_months, added_delta) = match index {
+            0 => (whole.checked_mul(12), Some(TimeDelta::zero())),
+            1 => (Some(whole), Some(TimeDelta::zero())),
+            2 => (Some(0), Some(TimeDelta::weeks(whole.into()))),
+            3 => (Some(0), Some(TimeDelta::days(whole.into()))),
+            5 => (Some(0), Some(TimeDelta::hours(whole.into()))),
+            6 => (Some(0), Some(TimeDelta::minutes(whole.into()))),
+            _ => {
+                // pad or truncate the fraction to nanoseconds
+                let nanoseconds = format!("{fraction:0<9}")[..9].parse::<i64>().map_err(|_| invalid_duration())?;
+                (Some(0), TimeDelta::seconds(whole.into()).checked_add(&TimeDelta::nanoseconds(nanoseconds)))
+            },
+        };
+        months = added_months.and_then(|added| months.checked_add(added)).ok_or_else(invalid_duration)?;
+        delta = added_delta.and_then(|added| delta.checked_add(&added)).ok_or_else(invalid_duration)?;
+        number.clear();
+    }
+
+    // a designator is required and `PT` by itself is not valid
+    if !number.is_empty() || value.is_empty() || value.ends_with('T') {
+        return Err(invalid_duration());
+    }
+
+    let months = Months::new(months);
+    let result = if negative {
+        datetime.checked_sub_months(months).and_then(|result| result.checked_sub_signed(delta))
+    } else {
+        datetime.checked_add_months(months).and_then(|result| result.checked_add_signed(delta))
+    };
+    result.ok_or_else(|| DscError::Parser(format!("Adding duration '{duration}' results in an invalid date and time")))
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::configure::context::Context;
+    use crate::parser::Statement;
+
+    #[test]
+    fn add_days() {
+        let mut parser = Statement::new().unwrap();
+        let result = parser.parse_and_execute("[dateTimeAdd('2024-02-28T10:00:00Z', 'P2D')]", &Context::new()).unwrap();
+        assert_eq!(result, "2024-03-01T10:00:00Z");
+    }
+
+    #[test]
+    fn add_years_and_time() {
+        let mut parser = Statement::new().unwrap();
+        let result = parser.parse_and_execute("[dateTimeAdd('2024-01-31T23:30:00Z', 'P1Y1MT45M')]", &Context::new()).unwrap();
+        assert_eq!(result, "2025-03-01T00:15:00Z");
+    }
+
+    #[test]
+    fn subtract() {
+        let mut parser = Statement::new().unwrap();
+        let result = parser.parse_and_execute("[dateTimeAdd('20240305T070809Z', '-PT1H30.5S')]", &Context::new()).unwrap();
+        assert_eq!(result, "2024-03-05T06:07:38.500Z");
+    }
+
+    #[test]
+    fn with_format() {
+        let mut parser = Statement::new().unwrap();
+        let result = parser.parse_and_execute("[dateTimeAdd('2024-03-05T07:08:09Z', 'P1W', 'yyyy-MM-dd')]", &Context::new()).unwrap();
+        assert_eq!(result, "2024-03-12");
+    }
+
+    #[test]
+    fn with_utc_now() {
+        let mut parser = Statement::new().unwrap();
+        let mut context = Context::new();
+        context.start_datetime = chrono::DateTime::parse_from_rfc3339("2024-03-05T07:08:09Z").unwrap().with_timezone(&chrono::Local);
+        let result = parser.parse_and_execute("[dateTimeAdd(utcNow(), 'P1D')]", &context).unwrap();
+        assert_eq!(result, "2024-03-06T07:08:09Z");
+    }
+
+    #[test]
+    fn invalid_duration() {
+        let mut parser = Statement::new().unwrap();
+        let result = parser.parse_and_execute("[dateTimeAdd('2024-03-05T07:08:09Z', 'PT')]", &Context::new());
+        assert!(result.is_err());
+    }
+
+    #[test]
+    fn invalid_datetime() {
+        let mut parser = Statement::new().unwrap();
+        let result = parser.parse_and_execute("[dateTimeAdd('yesterday', 'P1D')]", &Context::new());
+        assert!(result.is_err());
+    }
+}
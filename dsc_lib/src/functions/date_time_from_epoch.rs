@@ -0,0 +1,81 @@
+// Copyright (c) Microsoft Corporation.
+// Licensed under the MIT License.
+
+use crate::DscError;
+use crate::configure::context::Context;
+use crate::functions::{AcceptedArgKind, Function, FunctionCategory};
+use chrono::{DateTime, SecondsFormat};
+use serde_json::Value;
+use tracing::debug;
+
+#[derive(Debug, Default)]
+pub struct DateTimeFromEpoch {}
+
+impl Function for DateTimeFromEpoch {
+    fn min_args(&self) -> usize {
+        1
+    }
+
+    fn max_args(&self) -> usize {
+        1
+    }
+
+    fn accepted_arg_types(&self) -> Vec<AcceptedArgKind> {
+        vec![AcceptedArgKind::Number]
+    }
+
+    fn description(&self) -> String {
+        "Converts a Unix epoch time to an ISO 8601 date and time.".to_string()
+    }
+
+    fn category(&self) -> FunctionCategory {
+        FunctionCategory::Date
+    }
+
+    fn parameter_names(&self) -> Vec<&str> {
+        vec!["epochTime"]
+    }
+
+    fn return_types(&self) -> Vec<AcceptedArgKind> {
+        vec![AcceptedArgKind::String]
+    }
+
+    fn invoke(&self, args: &[Value], _context: &Context) -> Result<Value, DscError> {
+        debug!("dateTimeFromEpoch function");
+        let Some(epoch) = args[0].as_i64() else {
+            return Err(DscError::Parser("Epoch time must be an integer".to_string()));
+        };
+        let Some(datetime) = DateTime::from_timestamp(epoch, 0) else {
+            return Err(DscError::Parser(format!("Epoch time {epoch} is out of range")));
+        };
+
+        Ok(Value::String(datetime.to_rfc3339_opts(SecondsFormat::Secs, true)))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::configure::context::Context;
+    use crate::parser::Statement;
+
+    #[test]
+    fn valid() {
+        let mut parser = Statement::new().unwrap();
+        let result = parser.parse_and_execute("[dateTimeFromEpoch(1683040573)]", &Context::new()).unwrap();
+        assert_eq!(result, "2023-05-02T15:16:13Z");
+    }
+
+    #[test]
+    fn round_trip() {
+        let mut parser = Statement::new().unwrap();
+        let result = parser.parse_and_execute("[dateTimeToEpoch(dateTimeFromEpoch(1683040573))]", &Context::new()).unwrap();
+        assert_eq!(result, 1_683_040_573);
+    }
+
+    #[test]
+    fn invalid_type() {
+        let mut parser = Statement::new().unwrap();
+        let result = parser.parse_and_execute("[dateTimeFromEpoch('1683040573')]", &Context::new());
+        assert!(result.is_err());
+    }
+}
@@ -0,0 +1,78 @@
+// Copyright (c) Microsoft Corporation.
+// Licensed under the MIT License.
+
+use crate::DscError;
+use crate::configure::context::Context;
+use crate::functions::{AcceptedArgKind, Function, FunctionCategory};
+use crate::functions::date_time_add::parse_datetime;
+use serde_json::Value;
+use tracing::debug;
+
+#[derive(Debug, Default)]
+pub struct DateTimeToEpoch {}
+
+impl Function for DateTimeToEpoch {
+    fn min_args(&self) -> usize {
+        1
+    }
+
+    fn max_args(&self) -> usize {
+        1
+    }
+
+    fn accepted_arg_types(&self) -> Vec<AcceptedArgKind> {
+        vec![AcceptedArgKind::String]
+    }
+
+    fn description(&self) -> String {
+        "Converts an ISO 8601 date and time to a Unix epoch time.".to_string()
+    }
+
+    fn category(&self) -> FunctionCategory {
+        FunctionCategory::Date
+    }
+
+    fn parameter_names(&self) -> Vec<&str> {
+        vec!["dateTime"]
+    }
+
+    fn return_types(&self) -> Vec<AcceptedArgKind> {
+        vec![AcceptedArgKind::Number]
+    }
+
+    fn invoke(&self, args: &[Value], _context: &Context) -> Result<Value, DscError> {
+        debug!("dateTimeToEpoch function");
+        let Some(value) = args[0].as_str() else {
+            return Err(DscError::Parser("Invalid argument".to_string()));
+        };
+
+        Ok(Value::Number(parse_datetime(value)?.timestamp().into()))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::configure::context::Context;
+    use crate::parser::Statement;
+
+    #[test]
+    fn utc() {
+        let mut parser = Statement::new().unwrap();
+        let result = parser.parse_and_execute("[dateTimeToEpoch('2023-05-02T15:16:13Z')]", &Context::new()).unwrap();
+        assert_eq!(result, 1_683_040_573);
+    }
+
+    #[test]
+    fn with_offset() {
+        let mut parser = Statement::new().unwrap();
+        let result = parser.parse_and_execute("[dateTimeToEpoch('2023-05-02T17:16:13+02:00')]", &Context::new()).unwrap();
+        assert_eq!(result, 1_683_040_573);
+    }
+
+    #[test]
+    fn invalid() {
+        let mut parser = Statement::new().unwrap();
+        let result = parser.parse_and_execute("[dateTimeToEpoch('not a date')]", &Context::new());
+        assert!(result.is_err());
+    }
+}
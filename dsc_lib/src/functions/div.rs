@@ -3,7 +3,7 @@
 
 use crate::DscError;
 use crate::configure::context::Context;
-use crate::functions::{AcceptedArgKind, Function};
+use crate::functions::{AcceptedArgKind, Function, FunctionCategory, arithmetic};
 use serde_json::Value;
 use tracing::debug;
 
@@ -23,17 +23,29 @@ impl Function for Div {
         vec![AcceptedArgKind::Number]
     }
 
+    fn description(&self) -> String {
+        "Returns the quotient of two numbers.".to_string()
+    }
+
+    fn category(&self) -> FunctionCategory {
+        FunctionCategory::Numeric
+    }
+
+    fn parameter_names(&self) -> Vec<&str> {
+        vec!["operand1", "operand2"]
+    }
+
+    fn return_types(&self) -> Vec<AcceptedArgKind> {
+        vec![AcceptedArgKind::Number]
+    }
+
     fn invoke(&self, args: &[Value], _context: &Context) -> Result<Value, DscError> {
         debug!("div function");
-        if let (Some(arg1), Some(arg2)) = (args[0].as_i64(), args[1].as_i64()) {
-            if let Some(value) = arg1.checked_div(arg2) {
-                Ok(Value::Number(value.into()))
-            } else {
-                Err(DscError::Parser("Cannot divide by zero".to_string()))
-            }
-        } else {
-            Err(DscError::Parser("Invalid argument(s)".to_string()))
+        if args[1].as_f64().is_some_and(|divisor| divisor == 0.0) {
+            return Err(DscError::Parser("Cannot divide by zero".to_string()));
         }
+        // integer division truncates unless either number is a decimal
+        arithmetic("div", &args[0], &args[1], i64::checked_div, |left, right| left / right)
     }
 }
 
@@ -77,5 +89,18 @@ mod tests {
         let result = parser.parse_and_execute("[div(9223372036854775808, 2)]", &Context::new());
         assert!(result.is_err());
     }
-}
 
+    #[test]
+    fn decimals() {
+        let mut parser = Statement::new().unwrap();
+        let result = parser.parse_and_execute("[div(7, 2.0)]", &Context::new()).unwrap();
+        assert_eq!(result, 3.5);
+    }
+
+    #[test]
+    fn invalid_decimal_div_by_zero() {
+        let mut parser = Statement::new().unwrap();
+        let result = parser.parse_and_execute("[div(5.5, 0)]", &Context::new());
+        assert!(result.is_err());
+    }
+}
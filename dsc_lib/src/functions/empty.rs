@@ -0,0 +1,82 @@
+// Copyright (c) Microsoft Corporation.
+// Licensed under the MIT License.
+
+use crate::DscError;
+use crate::configure::context::Context;
+use crate::functions::{AcceptedArgKind, Function, FunctionCategory};
+use serde_json::Value;
+use tracing::debug;
+
+#[derive(Debug, Default)]
+pub struct Empty {}
+
+impl Function for Empty {
+    fn min_args(&self) -> usize {
+        1
+    }
+
+    fn max_args(&self) -> usize {
+        1
+    }
+
+    fn accepted_arg_types(&self) -> Vec<AcceptedArgKind> {
+        vec![AcceptedArgKind::Array, AcceptedArgKind::Object, AcceptedArgKind::String]
+    }
+
+    fn description(&self) -> String {
+        "Checks whether an array, object or string is empty.".to_string()
+    }
+
+    fn category(&self) -> FunctionCategory {
+        FunctionCategory::Array
+    }
+
+    fn parameter_names(&self) -> Vec<&str> {
+        vec!["itemToTest"]
+    }
+
+    fn return_types(&self) -> Vec<AcceptedArgKind> {
+        vec![AcceptedArgKind::Boolean]
+    }
+
+    fn invoke(&self, args: &[Value], _context: &Context) -> Result<Value, DscError> {
+        debug!("empty function");
+        let empty = match &args[0] {
+            Value::Array(array) => array.is_empty(),
+            Value::Object(object) => object.is_empty(),
+            Value::String(string) => string.is_empty(),
+            Value::Null => true,
+            _ => {
+                return Err(DscError::Parser("Invalid argument".to_string()));
+            }
+        };
+        Ok(Value::Bool(empty))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::configure::context::Context;
+    use crate::parser::Statement;
+
+    #[test]
+    fn empty_array() {
+        let mut parser = Statement::new().unwrap();
+        let result = parser.parse_and_execute("[empty(createArray())]", &Context::new()).unwrap();
+        assert_eq!(result, true);
+    }
+
+    #[test]
+    fn non_empty_string() {
+        let mut parser = Statement::new().unwrap();
+        let result = parser.parse_and_execute("[empty('a')]", &Context::new()).unwrap();
+        assert_eq!(result, false);
+    }
+
+    #[test]
+    fn empty_object() {
+        let mut parser = Statement::new().unwrap();
+        let result = parser.parse_and_execute("[empty(createObject())]", &Context::new()).unwrap();
+        assert_eq!(result, true);
+    }
+}
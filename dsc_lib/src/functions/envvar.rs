@@ -3,7 +3,7 @@
 
 use crate::DscError;
 use crate::configure::context::Context;
-use crate::functions::AcceptedArgKind;
+use crate::functions::{AcceptedArgKind, FunctionCategory};
 use super::Function;
 use serde_json::Value;
 use std::env;
@@ -16,6 +16,22 @@ impl Function for Envvar {
         vec![AcceptedArgKind::String]
     }
 
+    fn description(&self) -> String {
+        "Returns the value of an environment variable.".to_string()
+    }
+
+    fn category(&self) -> FunctionCategory {
+        FunctionCategory::System
+    }
+
+    fn parameter_names(&self) -> Vec<&str> {
+        vec!["name"]
+    }
+
+    fn return_types(&self) -> Vec<AcceptedArgKind> {
+        vec![AcceptedArgKind::String]
+    }
+
     fn min_args(&self) -> usize {
         1
     }
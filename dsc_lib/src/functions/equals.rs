@@ -0,0 +1,87 @@
+// Copyright (c) Microsoft Corporation.
+// Licensed under the MIT License.
+
+use crate::DscError;
+use crate::configure::context::Context;
+use crate::functions::{AcceptedArgKind, Function, FunctionCategory};
+use serde_json::Value;
+use tracing::debug;
+
+#[derive(Debug, Default)]
+pub struct Equals {}
+
+impl Function for Equals {
+    fn min_args(&self) -> usize {
+        2
+    }
+
+    fn max_args(&self) -> usize {
+        2
+    }
+
+    fn accepted_arg_types(&self) -> Vec<AcceptedArgKind> {
+        vec![AcceptedArgKind::Array, AcceptedArgKind::Boolean, AcceptedArgKind::Number, AcceptedArgKind::Object, AcceptedArgKind::String]
+    }
+
+    fn description(&self) -> String {
+        "Checks whether two values are equal.".to_string()
+    }
+
+    fn category(&self) -> FunctionCategory {
+        FunctionCategory::Comparison
+    }
+
+    fn parameter_names(&self) -> Vec<&str> {
+        vec!["arg1", "arg2"]
+    }
+
+    fn return_types(&self) -> Vec<AcceptedArgKind> {
+        vec![AcceptedArgKind::Boolean]
+    }
+
+    fn invoke(&self, args: &[Value], _context: &Context) -> Result<Value, DscError> {
+        debug!("equals function");
+        Ok(Value::Bool(args[0] == args[1]))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::configure::context::Context;
+    use crate::parser::Statement;
+
+    #[test]
+    fn strings() {
+        let mut parser = Statement::new().unwrap();
+        let result = parser.parse_and_execute("[equals('a', 'a')]", &Context::new()).unwrap();
+        assert_eq!(result, true);
+    }
+
+    #[test]
+    fn strings_case_sensitive() {
+        let mut parser = Statement::new().unwrap();
+        let result = parser.parse_and_execute("[equals('a', 'A')]", &Context::new()).unwrap();
+        assert_eq!(result, false);
+    }
+
+    #[test]
+    fn numbers() {
+        let mut parser = Statement::new().unwrap();
+        let result = parser.parse_and_execute("[equals(1, 2)]", &Context::new()).unwrap();
+        assert_eq!(result, false);
+    }
+
+    #[test]
+    fn arrays() {
+        let mut parser = Statement::new().unwrap();
+        let result = parser.parse_and_execute("[equals(createArray('a', 'b'), createArray('a', 'b'))]", &Context::new()).unwrap();
+        assert_eq!(result, true);
+    }
+
+    #[test]
+    fn different_types() {
+        let mut parser = Statement::new().unwrap();
+        let result = parser.parse_and_execute("[equals(1, '1')]", &Context::new()).unwrap();
+        assert_eq!(result, false);
+    }
+}
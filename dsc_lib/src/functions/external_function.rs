@@ -0,0 +1,147 @@
+// Copyright (c) Microsoft Corporation.
+// Licensed under the MIT License.
+
+use crate::DscError;
+use crate::configure::context::Context;
+use crate::dscresources::command_resource::invoke_command;
+use crate::dscresources::dscresource::DscResource;
+use crate::dscresources::resource_manifest::{import_manifest, FunctionInvokeMethod};
+use crate::functions::{AcceptedArgKind, Function, FunctionCategory};
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+use std::collections::HashMap;
+use tracing::{debug, info, trace};
+
+/// The signature of a function as returned by the `list` command of a function provider.
+#[derive(Debug, Clone, Deserialize)]
+pub struct FunctionSignature {
+    /// The name of the function including the namespace, like `contoso.lookup`.
+    pub name: String,
+    /// The minimum number of arguments that the function accepts.
+    #[serde(rename = "minArgs")]
+    pub min_args: usize,
+    /// The maximum number of arguments that the function accepts.
+    #[serde(rename = "maxArgs")]
+    pub max_args: usize,
+    /// The types of arguments that the function accepts.
+    #[serde(rename = "acceptedArgTypes")]
+    pub accepted_arg_types: Vec<AcceptedArgKind>,
+    /// A short description of the function.
+    #[serde(default)]
+    pub description: String,
+    /// The names of the parameters of the function.
+    #[serde(default)]
+    pub parameters: Vec<String>,
+    /// The types of values that the function returns, all types if not specified.
+    #[serde(rename = "returnTypes", default)]
+    pub return_types: Option<Vec<AcceptedArgKind>>,
+}
+
+/// The JSON passed via STDIN to the `invoke` command of a function provider.
+#[derive(Debug, Serialize)]
+struct FunctionInvokeRequest<'a> {
+    name: &'a str,
+    args: &'a [Value],
+}
+
+/// A function supplied by a function provider executable.
+#[derive(Debug)]
+pub struct ExternalFunction {
+    provider: String,
+    signature: FunctionSignature,
+    invoke: FunctionInvokeMethod,
+    directory: String,
+    exit_codes: Option<HashMap<i32, String>>,
+}
+
+impl ExternalFunction {
+    /// The name of the function including the namespace.
+    #[must_use]
+    pub fn name(&self) -> &str {
+        &self.signature.name
+    }
+}
+
+impl Function for ExternalFunction {
+    fn min_args(&self) -> usize {
+        self.signature.min_args
+    }
+
+    fn max_args(&self) -> usize {
+        self.signature.max_args
+    }
+
+    fn accepted_arg_types(&self) -> Vec<AcceptedArgKind> {
+        self.signature.accepted_arg_types.clone()
+    }
+
+    fn description(&self) -> String {
+        self.signature.description.clone()
+    }
+
+    fn category(&self) -> FunctionCategory {
+        FunctionCategory::External
+    }
+
+    fn parameter_names(&self) -> Vec<&str> {
+        self.signature.parameters.iter().map(String::as_str).collect()
+    }
+
+    fn return_types(&self) -> Vec<AcceptedArgKind> {
+        self.signature.return_types.clone().unwrap_or_else(|| vec![AcceptedArgKind::Array, AcceptedArgKind::Boolean, AcceptedArgKind::Number, AcceptedArgKind::Object, AcceptedArgKind::String])
+    }
+
+    fn invoke(&self, args: &[Value], _context: &Context) -> Result<Value, DscError> {
+        debug!("external function '{0}' from provider '{1}'", self.signature.name, self.provider);
+        let input = serde_json::to_string(&FunctionInvokeRequest { name: &self.signature.name, args })?;
+        let (_exit_code, stdout, _stderr) = invoke_command(&self.invoke.executable, self.invoke.args.clone(), Some(&input), Some(&self.directory), None, &self.exit_codes)?;
+        match serde_json::from_str(&stdout) {
+            Ok(result) => Ok(result),
+            Err(err) => Err(DscError::Function(self.signature.name.clone(), format!("Provider '{0}' returned invalid JSON: {err}", self.provider))),
+        }
+    }
+}
+
+/// Get the functions supplied by a function provider by invoking its `list` command.
+///
+/// # Arguments
+///
+/// * `provider` - The function provider resource.
+///
+/// # Errors
+///
+/// This function will return an error if the manifest is invalid, the `list` command fails or returns an invalid signature.
+pub fn list_external_functions(provider: &DscResource) -> Result<Vec<ExternalFunction>, DscError> {
+    let Some(manifest) = &provider.manifest else {
+        return Err(DscError::MissingManifest(provider.type_name.clone()));
+    };
+    let manifest = import_manifest(manifest.clone())?;
+    let Some(functions) = manifest.functions else {
+        return Err(DscError::Operation(format!("Function provider '{0}' does not define functions", provider.type_name)));
+    };
+
+    info!("Listing functions of provider '{0}'", provider.type_name);
+    let (_exit_code, stdout, _stderr) = invoke_command(&functions.list.executable, functions.list.args, None, Some(&provider.directory), None, &manifest.exit_codes)?;
+    let mut external_functions = Vec::<ExternalFunction>::new();
+    for line in stdout.lines() {
+        trace!("Function signature: {line}");
+        let signature = match serde_json::from_str::<FunctionSignature>(line) {
+            Ok(signature) => signature,
+            Err(err) => {
+                return Err(DscError::Operation(format!("Function provider '{0}' returned invalid signature '{line}': {err}", provider.type_name)));
+            }
+        };
+        if signature.min_args > signature.max_args {
+            return Err(DscError::Operation(format!("Function '{0}' from provider '{1}' has more minimum than maximum arguments", signature.name, provider.type_name)));
+        }
+        external_functions.push(ExternalFunction {
+            provider: provider.type_name.clone(),
+            signature,
+            invoke: functions.invoke.clone(),
+            directory: provider.directory.clone(),
+            exit_codes: manifest.exit_codes.clone(),
+        });
+    }
+
+    Ok(external_functions)
+}
@@ -0,0 +1,73 @@
+// Copyright (c) Microsoft Corporation.
+// Licensed under the MIT License.
+
+use crate::DscError;
+use crate::configure::context::Context;
+use crate::functions::{AcceptedArgKind, Function, FunctionCategory};
+use serde_json::Value;
+use tracing::debug;
+
+#[derive(Debug, Default)]
+pub struct False {}
+
+impl Function for False {
+    fn min_args(&self) -> usize {
+        0
+    }
+
+    fn max_args(&self) -> usize {
+        0
+    }
+
+    fn accepted_arg_types(&self) -> Vec<AcceptedArgKind> {
+        vec![]
+    }
+
+    fn description(&self) -> String {
+        "Returns false.".to_string()
+    }
+
+    fn category(&self) -> FunctionCategory {
+        FunctionCategory::Logical
+    }
+
+    fn parameter_names(&self) -> Vec<&str> {
+        vec![]
+    }
+
+    fn return_types(&self) -> Vec<AcceptedArgKind> {
+        vec![AcceptedArgKind::Boolean]
+    }
+
+    fn invoke(&self, _args: &[Value], _context: &Context) -> Result<Value, DscError> {
+        debug!("false function");
+        Ok(Value::Bool(false))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::configure::context::Context;
+    use crate::parser::Statement;
+
+    #[test]
+    fn returns_false() {
+        let mut parser = Statement::new().unwrap();
+        let result = parser.parse_and_execute("[false()]", &Context::new()).unwrap();
+        assert_eq!(result, false);
+    }
+
+    #[test]
+    fn as_argument() {
+        let mut parser = Statement::new().unwrap();
+        let result = parser.parse_and_execute("[equals(false(), false)]", &Context::new()).unwrap();
+        assert_eq!(result, true);
+    }
+
+    #[test]
+    fn with_argument() {
+        let mut parser = Statement::new().unwrap();
+        let result = parser.parse_and_execute("[false(1)]", &Context::new());
+        assert!(result.is_err());
+    }
+}
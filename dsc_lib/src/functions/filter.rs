@@ -0,0 +1,113 @@
+// Copyright (c) Microsoft Corporation.
+// Licensed under the MIT License.
+
+use crate::DscError;
+use crate::configure::context::Context;
+use crate::functions::{AcceptedArgKind, Function, FunctionCategory, LambdaArg};
+use serde_json::Value;
+use tracing::debug;
+
+#[derive(Debug, Default)]
+pub struct Filter {}
+
+impl Function for Filter {
+    fn min_args(&self) -> usize {
+        2
+    }
+
+    fn max_args(&self) -> usize {
+        2
+    }
+
+    fn accepted_arg_types(&self) -> Vec<AcceptedArgKind> {
+        vec![AcceptedArgKind::Array]
+    }
+
+    fn description(&self) -> String {
+        "Returns the array elements for which the lambda returns true.".to_string()
+    }
+
+    fn category(&self) -> FunctionCategory {
+        FunctionCategory::Lambda
+    }
+
+    fn parameter_names(&self) -> Vec<&str> {
+        vec!["inputArray", "lambda"]
+    }
+
+    fn parameter_types(&self) -> Vec<Vec<AcceptedArgKind>> {
+        vec![
+            vec![AcceptedArgKind::Array],
+            vec![AcceptedArgKind::Lambda],
+        ]
+    }
+
+    fn return_types(&self) -> Vec<AcceptedArgKind> {
+        vec![AcceptedArgKind::Array]
+    }
+
+    fn invoke(&self, _args: &[Value], _context: &Context) -> Result<Value, DscError> {
+        Err(DscError::Parser("Function 'filter' requires a lambda as the second argument".to_string()))
+    }
+
+    fn invoke_with_lambdas(&self, args: &[Value], lambdas: &[LambdaArg], _context: &Context) -> Result<Value, DscError> {
+        debug!("filter function");
+        let (Some(array), [lambda]) = (args.first().and_then(Value::as_array), lambdas) else {
+            return Err(DscError::Parser("Function 'filter' requires an array and a lambda".to_string()));
+        };
+
+        let mut result = Vec::<Value>::new();
+        for (index, element) in array.iter().enumerate() {
+            // the lambda can optionally accept the index as second parameter
+            let Some(keep) = lambda(&[element.clone(), Value::Number(index.into())])?.as_bool() else {
+                return Err(DscError::Parser("Lambda for 'filter' must return a boolean".to_string()));
+            };
+            if keep {
+                result.push(element.clone());
+            }
+        }
+
+        Ok(Value::Array(result))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::configure::context::Context;
+    use crate::parser::Statement;
+
+    #[test]
+    fn numbers() {
+        let mut parser = Statement::new().unwrap();
+        let result = parser.parse_and_execute("[filter(range(1, 5), lambda('x', greater(lambdaVariables('x'), 2)))]", &Context::new()).unwrap();
+        assert_eq!(result.to_string(), "[3,4,5]");
+    }
+
+    #[test]
+    fn index() {
+        let mut parser = Statement::new().unwrap();
+        let result = parser.parse_and_execute("[filter(createArray('a', 'b', 'c'), lambda('x', 'i', not(equals(lambdaVariables('i'), 1))))]", &Context::new()).unwrap();
+        assert_eq!(result.to_string(), r#"["a","c"]"#);
+    }
+
+    #[test]
+    fn non_boolean_result() {
+        let mut parser = Statement::new().unwrap();
+        let result = parser.parse_and_execute("[filter(createArray('a'), lambda('x', lambdaVariables('x')))]", &Context::new());
+        assert!(result.is_err());
+    }
+
+    #[test]
+    fn missing_lambda() {
+        let mut parser = Statement::new().unwrap();
+        let result = parser.parse_and_execute("[filter(createArray('a'), 'a')]", &Context::new());
+        assert!(result.is_err());
+    }
+
+    #[test]
+    fn variable_out_of_scope() {
+        let mut parser = Statement::new().unwrap();
+        let result = parser.parse_and_execute("[concat(filter(createArray('a'), lambda('x', true())), createArray(lambdaVariables('x')))]", &Context::new());
+        assert!(result.is_err());
+    }
+}
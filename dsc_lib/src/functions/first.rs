@@ -0,0 +1,79 @@
+// Copyright (c) Microsoft Corporation.
+// Licensed under the MIT License.
+
+use crate::DscError;
+use crate::configure::context::Context;
+use crate::functions::{AcceptedArgKind, Function, FunctionCategory};
+use serde_json::Value;
+use tracing::debug;
+
+#[derive(Debug, Default)]
+pub struct First {}
+
+impl Function for First {
+    fn min_args(&self) -> usize {
+        1
+    }
+
+    fn max_args(&self) -> usize {
+        1
+    }
+
+    fn accepted_arg_types(&self) -> Vec<AcceptedArgKind> {
+        vec![AcceptedArgKind::Array, AcceptedArgKind::String]
+    }
+
+    fn description(&self) -> String {
+        "Returns the first element of an array or the first character of a string.".to_string()
+    }
+
+    fn category(&self) -> FunctionCategory {
+        FunctionCategory::Array
+    }
+
+    fn parameter_names(&self) -> Vec<&str> {
+        vec!["arg1"]
+    }
+
+    fn return_types(&self) -> Vec<AcceptedArgKind> {
+        vec![AcceptedArgKind::Array, AcceptedArgKind::Boolean, AcceptedArgKind::Number, AcceptedArgKind::Object, AcceptedArgKind::String]
+    }
+
+    fn invoke(&self, args: &[Value], _context: &Context) -> Result<Value, DscError> {
+        debug!("first function");
+        match &args[0] {
+            // an empty array returns null
+            Value::Array(array) => Ok(array.first().cloned().unwrap_or(Value::Null)),
+            Value::String(string) => Ok(Value::String(string.chars().next().map(String::from).unwrap_or_default())),
+            _ => Err(DscError::Parser("Invalid argument".to_string())),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::configure::context::Context;
+    use crate::parser::Statement;
+    use serde_json::Value;
+
+    #[test]
+    fn array() {
+        let mut parser = Statement::new().unwrap();
+        let result = parser.parse_and_execute("[first(createArray('a', 'b', 'c'))]", &Context::new()).unwrap();
+        assert_eq!(result, "a");
+    }
+
+    #[test]
+    fn string() {
+        let mut parser = Statement::new().unwrap();
+        let result = parser.parse_and_execute("[first('hello')]", &Context::new()).unwrap();
+        assert_eq!(result, "h");
+    }
+
+    #[test]
+    fn empty_array() {
+        let mut parser = Statement::new().unwrap();
+        let result = parser.parse_and_execute("[first(createArray())]", &Context::new()).unwrap();
+        assert_eq!(result, Value::Null);
+    }
+}
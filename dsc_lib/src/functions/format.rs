@@ -0,0 +1,142 @@
+// Copyright (c) Microsoft Corporation.
+// Licensed under the MIT License.
+
+use crate::DscError;
+use crate::configure::context::Context;
+use crate::functions::{AcceptedArgKind, Function, FunctionCategory};
+use serde_json::Value;
+use tracing::debug;
+
+#[derive(Debug, Default)]
+pub struct Format {}
+
+impl Function for Format {
+    fn min_args(&self) -> usize {
+        1
+    }
+
+    fn max_args(&self) -> usize {
+        usize::MAX
+    }
+
+    fn accepted_arg_types(&self) -> Vec<AcceptedArgKind> {
+        vec![AcceptedArgKind::String, AcceptedArgKind::Number, AcceptedArgKind::Boolean, AcceptedArgKind::Array, AcceptedArgKind::Object]
+    }
+
+    fn description(&self) -> String {
+        "Creates a formatted string from the input values.".to_string()
+    }
+
+    fn category(&self) -> FunctionCategory {
+        FunctionCategory::String
+    }
+
+    fn parameter_names(&self) -> Vec<&str> {
+        vec!["formatString", "arg1"]
+    }
+
+    fn parameter_types(&self) -> Vec<Vec<AcceptedArgKind>> {
+        vec![
+            vec![AcceptedArgKind::String],
+            vec![AcceptedArgKind::String, AcceptedArgKind::Number, AcceptedArgKind::Boolean, AcceptedArgKind::Array, AcceptedArgKind::Object],
+        ]
+    }
+
+    fn return_types(&self) -> Vec<AcceptedArgKind> {
+        vec![AcceptedArgKind::String]
+    }
+
+    fn invoke(&self, args: &[Value], _context: &Context) -> Result<Value, DscError> {
+        debug!("format function");
+        let Some(format_string) = args[0].as_str() else {
+            return Err(DscError::Parser("Format string must be a string".to_string()));
+        };
+        let values = &args[1..];
+
+        let mut result = String::new();
+        let mut chars = format_string.chars().peekable();
+        while let Some(c) = chars.next() {
+            match c {
+                '{' => {
+                    if chars.peek() == Some(&'{') {
+                        chars.next();
+                        result.push('{');
+                        continue;
+                    }
+                    let mut placeholder = String::new();
+                    loop {
+                        match chars.next() {
+                            Some('}') => break,
+                            Some(c) => placeholder.push(c),
+                            None => {
+                                return Err(DscError::Parser("Format string has an unterminated placeholder".to_string()));
+                            }
+                        }
+                    }
+                    let Ok(index) = placeholder.trim().parse::<usize>() else {
+                        return Err(DscError::Parser(format!("Invalid format placeholder '{{{placeholder}}}', only '{{index}}' is supported")));
+                    };
+                    let Some(value) = values.get(index) else {
+                        return Err(DscError::Parser(format!("Format placeholder index {index} is out of range")));
+                    };
+                    match value {
+                        Value::String(value) => result.push_str(value),
+                        _ => result.push_str(&value.to_string()),
+                    }
+                },
+                '}' => {
+                    if chars.peek() == Some(&'}') {
+                        chars.next();
+                        result.push('}');
+                    } else {
+                        return Err(DscError::Parser("Format string has an unescaped '}'".to_string()));
+                    }
+                },
+                _ => result.push(c),
+            }
+        }
+
+        Ok(Value::String(result))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::configure::context::Context;
+    use crate::parser::Statement;
+
+    #[test]
+    fn placeholders() {
+        let mut parser = Statement::new().unwrap();
+        let result = parser.parse_and_execute("[format('{0}/{1}/{0}', 'a', 2)]", &Context::new()).unwrap();
+        assert_eq!(result, "a/2/a");
+    }
+
+    #[test]
+    fn no_placeholders() {
+        let mut parser = Statement::new().unwrap();
+        let result = parser.parse_and_execute("[format('hello')]", &Context::new()).unwrap();
+        assert_eq!(result, "hello");
+    }
+
+    #[test]
+    fn escaped_braces() {
+        let mut parser = Statement::new().unwrap();
+        let result = parser.parse_and_execute("[format('{{{0}}}', true)]", &Context::new()).unwrap();
+        assert_eq!(result, "{true}");
+    }
+
+    #[test]
+    fn index_out_of_range() {
+        let mut parser = Statement::new().unwrap();
+        let result = parser.parse_and_execute("[format('{1}', 'a')]", &Context::new());
+        assert!(result.is_err());
+    }
+
+    #[test]
+    fn unterminated_placeholder() {
+        let mut parser = Statement::new().unwrap();
+        let result = parser.parse_and_execute("[format('{0', 'a')]", &Context::new());
+        assert!(result.is_err());
+    }
+}
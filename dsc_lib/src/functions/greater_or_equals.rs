@@ -0,0 +1,81 @@
+// Copyright (c) Microsoft Corporation.
+// Licensed under the MIT License.
+
+use crate::DscError;
+use crate::configure::context::Context;
+use crate::functions::{AcceptedArgKind, Function, FunctionCategory, compare_values};
+use serde_json::Value;
+use tracing::debug;
+
+#[derive(Debug, Default)]
+pub struct GreaterOrEquals {}
+
+impl Function for GreaterOrEquals {
+    fn min_args(&self) -> usize {
+        2
+    }
+
+    fn max_args(&self) -> usize {
+        2
+    }
+
+    fn accepted_arg_types(&self) -> Vec<AcceptedArgKind> {
+        vec![AcceptedArgKind::Number, AcceptedArgKind::String]
+    }
+
+    fn description(&self) -> String {
+        "Checks whether the first value is greater than or equal to the second value.".to_string()
+    }
+
+    fn category(&self) -> FunctionCategory {
+        FunctionCategory::Comparison
+    }
+
+    fn parameter_names(&self) -> Vec<&str> {
+        vec!["arg1", "arg2"]
+    }
+
+    fn return_types(&self) -> Vec<AcceptedArgKind> {
+        vec![AcceptedArgKind::Boolean]
+    }
+
+    fn invoke(&self, args: &[Value], _context: &Context) -> Result<Value, DscError> {
+        debug!("greaterOrEquals function");
+        let ordering = compare_values("greaterOrEquals", &args[0], &args[1])?;
+        Ok(Value::Bool(ordering.is_ge()))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::configure::context::Context;
+    use crate::parser::Statement;
+
+    #[test]
+    fn numbers() {
+        let mut parser = Statement::new().unwrap();
+        let result = parser.parse_and_execute("[greaterOrEquals(2, 1)]", &Context::new()).unwrap();
+        assert_eq!(result, true);
+    }
+
+    #[test]
+    fn equal_numbers() {
+        let mut parser = Statement::new().unwrap();
+        let result = parser.parse_and_execute("[greaterOrEquals(1, 1)]", &Context::new()).unwrap();
+        assert_eq!(result, true);
+    }
+
+    #[test]
+    fn strings() {
+        let mut parser = Statement::new().unwrap();
+        let result = parser.parse_and_execute("[greaterOrEquals('a', 'b')]", &Context::new()).unwrap();
+        assert_eq!(result, false);
+    }
+
+    #[test]
+    fn mixed_types() {
+        let mut parser = Statement::new().unwrap();
+        let result = parser.parse_and_execute("[greaterOrEquals(1, 'a')]", &Context::new());
+        assert!(result.is_err());
+    }
+}
@@ -0,0 +1,89 @@
+// Copyright (c) Microsoft Corporation.
+// Licensed under the MIT License.
+
+use crate::DscError;
+use crate::configure::context::Context;
+use crate::functions::{AcceptedArgKind, Function, FunctionCategory};
+use serde_json::Value;
+use tracing::debug;
+use uuid::Uuid;
+
+// same namespace as the ARM `guid()` function
+const GUID_NAMESPACE: Uuid = uuid::uuid!("11fb06fb-712d-4ddd-98c7-e71bbd588830");
+
+#[derive(Debug, Default)]
+pub struct Guid {}
+
+impl Function for Guid {
+    fn min_args(&self) -> usize {
+        1
+    }
+
+    fn max_args(&self) -> usize {
+        usize::MAX
+    }
+
+    fn accepted_arg_types(&self) -> Vec<AcceptedArgKind> {
+        vec![AcceptedArgKind::String]
+    }
+
+    fn description(&self) -> String {
+        "Creates a deterministic GUID from the values.".to_string()
+    }
+
+    fn category(&self) -> FunctionCategory {
+        FunctionCategory::String
+    }
+
+    fn parameter_names(&self) -> Vec<&str> {
+        vec!["baseString"]
+    }
+
+    fn return_types(&self) -> Vec<AcceptedArgKind> {
+        vec![AcceptedArgKind::String]
+    }
+
+    fn invoke(&self, args: &[Value], _context: &Context) -> Result<Value, DscError> {
+        debug!("guid function");
+        let mut seeds = Vec::<&str>::new();
+        for arg in args {
+            let Some(seed) = arg.as_str() else {
+                return Err(DscError::Parser("Arguments must all be strings".to_string()));
+            };
+            seeds.push(seed);
+        }
+
+        // a name-based UUID always returns the same value for the same seeds
+        Ok(Value::String(Uuid::new_v5(&GUID_NAMESPACE, seeds.join("-").as_bytes()).to_string()))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::configure::context::Context;
+    use crate::parser::Statement;
+
+    #[test]
+    fn deterministic() {
+        let mut parser = Statement::new().unwrap();
+        let first = parser.parse_and_execute("[guid('service', 'instance1')]", &Context::new()).unwrap();
+        let second = parser.parse_and_execute("[guid('service', 'instance1')]", &Context::new()).unwrap();
+        assert_eq!(first, second);
+        assert!(uuid::Uuid::parse_str(first.as_str().unwrap()).is_ok());
+    }
+
+    #[test]
+    fn different_seeds() {
+        let mut parser = Statement::new().unwrap();
+        let first = parser.parse_and_execute("[guid('service', 'instance1')]", &Context::new()).unwrap();
+        let second = parser.parse_and_execute("[guid('service', 'instance2')]", &Context::new()).unwrap();
+        assert_ne!(first, second);
+    }
+
+    #[test]
+    fn invalid_type() {
+        let mut parser = Statement::new().unwrap();
+        let result = parser.parse_and_execute("[guid(1)]", &Context::new());
+        assert!(result.is_err());
+    }
+}
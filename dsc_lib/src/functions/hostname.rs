@@ -0,0 +1,66 @@
+// Copyright (c) Microsoft Corporation.
+// Licensed under the MIT License.
+
+use crate::DscError;
+use crate::configure::context::Context;
+use crate::functions::{AcceptedArgKind, Function, FunctionCategory};
+use serde_json::Value;
+use std::process::Command;
+use tracing::debug;
+
+#[derive(Debug, Default)]
+pub struct Hostname {}
+
+impl Function for Hostname {
+    fn min_args(&self) -> usize {
+        0
+    }
+
+    fn max_args(&self) -> usize {
+        0
+    }
+
+    fn accepted_arg_types(&self) -> Vec<AcceptedArgKind> {
+        vec![]
+    }
+
+    fn description(&self) -> String {
+        "Returns the name of the host.".to_string()
+    }
+
+    fn category(&self) -> FunctionCategory {
+        FunctionCategory::System
+    }
+
+    fn parameter_names(&self) -> Vec<&str> {
+        vec![]
+    }
+
+    fn return_types(&self) -> Vec<AcceptedArgKind> {
+        vec![AcceptedArgKind::String]
+    }
+
+    fn invoke(&self, _args: &[Value], _context: &Context) -> Result<Value, DscError> {
+        debug!("hostname function");
+        // the `hostname` executable is available on Windows, Linux and macOS
+        let output = match Command::new("hostname").output() {
+            Ok(output) if output.status.success() => output,
+            Ok(output) => return Err(DscError::Function("hostname".to_string(), String::from_utf8_lossy(&output.stderr).trim().to_string())),
+            Err(err) => return Err(DscError::Function("hostname".to_string(), format!("Failed to run 'hostname': {err}"))),
+        };
+        Ok(Value::String(String::from_utf8_lossy(&output.stdout).trim().to_string()))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::configure::context::Context;
+    use crate::parser::Statement;
+
+    #[test]
+    fn not_empty() {
+        let mut parser = Statement::new().unwrap();
+        let result = parser.parse_and_execute("[hostname()]", &Context::new()).unwrap();
+        assert!(!result.as_str().unwrap().is_empty());
+    }
+}
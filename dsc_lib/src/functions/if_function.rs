@@ -0,0 +1,122 @@
+// Copyright (c) Microsoft Corporation.
+// Licensed under the MIT License.
+
+use crate::DscError;
+use crate::configure::context::Context;
+use crate::functions::{AcceptedArgKind, Function, FunctionCategory, LazyArg};
+use serde_json::Value;
+use tracing::debug;
+
+#[derive(Debug, Default)]
+pub struct If {}
+
+impl Function for If {
+    fn min_args(&self) -> usize {
+        3
+    }
+
+    fn max_args(&self) -> usize {
+        3
+    }
+
+    fn accepted_arg_types(&self) -> Vec<AcceptedArgKind> {
+        vec![AcceptedArgKind::Array, AcceptedArgKind::Boolean, AcceptedArgKind::Number, AcceptedArgKind::Object, AcceptedArgKind::String]
+    }
+
+    fn description(&self) -> String {
+        "Returns one of two values based on a condition.".to_string()
+    }
+
+    fn category(&self) -> FunctionCategory {
+        FunctionCategory::Logical
+    }
+
+    fn parameter_names(&self) -> Vec<&str> {
+        vec!["condition", "trueValue", "falseValue"]
+    }
+
+    fn parameter_types(&self) -> Vec<Vec<AcceptedArgKind>> {
+        vec![
+            vec![AcceptedArgKind::Boolean],
+            vec![AcceptedArgKind::Array, AcceptedArgKind::Boolean, AcceptedArgKind::Number, AcceptedArgKind::Object, AcceptedArgKind::String],
+            vec![AcceptedArgKind::Array, AcceptedArgKind::Boolean, AcceptedArgKind::Number, AcceptedArgKind::Object, AcceptedArgKind::String],
+        ]
+    }
+
+    fn return_types(&self) -> Vec<AcceptedArgKind> {
+        vec![AcceptedArgKind::Array, AcceptedArgKind::Boolean, AcceptedArgKind::Number, AcceptedArgKind::Object, AcceptedArgKind::String]
+    }
+
+    fn invoke(&self, args: &[Value], _context: &Context) -> Result<Value, DscError> {
+        debug!("if function");
+        let Some(condition) = args[0].as_bool() else {
+            return Err(DscError::Parser("Condition must be a boolean".to_string()));
+        };
+        Ok(if condition { args[1].clone() } else { args[2].clone() })
+    }
+
+    fn lazy_args(&self) -> bool {
+        true
+    }
+
+    fn invoke_lazy(&self, args: &[LazyArg], _context: &Context) -> Result<Value, DscError> {
+        debug!("if function");
+        let Some(condition) = args[0]()?.as_bool() else {
+            return Err(DscError::Parser("Condition must be a boolean".to_string()));
+        };
+        // only the branch that is taken gets evaluated
+        if condition {
+            args[1]()
+        } else {
+            args[2]()
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::configure::context::Context;
+    use crate::parser::Statement;
+
+    #[test]
+    fn true_condition() {
+        let mut parser = Statement::new().unwrap();
+        let result = parser.parse_and_execute("[if(true, 'a', 'b')]", &Context::new()).unwrap();
+        assert_eq!(result, "a");
+    }
+
+    #[test]
+    fn false_condition() {
+        let mut parser = Statement::new().unwrap();
+        let result = parser.parse_and_execute("[if(equals(1, 2), 'a', 'b')]", &Context::new()).unwrap();
+        assert_eq!(result, "b");
+    }
+
+    #[test]
+    fn untaken_branch_not_evaluated() {
+        let mut parser = Statement::new().unwrap();
+        let result = parser.parse_and_execute("[if(true, 'a', parameters('missing'))]", &Context::new()).unwrap();
+        assert_eq!(result, "a");
+    }
+
+    #[test]
+    fn taken_branch_error() {
+        let mut parser = Statement::new().unwrap();
+        let result = parser.parse_and_execute("[if(false, 'a', parameters('missing'))]", &Context::new());
+        assert!(result.is_err());
+    }
+
+    #[test]
+    fn non_boolean_condition() {
+        let mut parser = Statement::new().unwrap();
+        let result = parser.parse_and_execute("[if('true', 'a', 'b')]", &Context::new());
+        assert!(result.is_err());
+    }
+
+    #[test]
+    fn with_accessor() {
+        let mut parser = Statement::new().unwrap();
+        let result = parser.parse_and_execute("[if(true, createArray('a', 'b'), createArray('c'))[1]]", &Context::new()).unwrap();
+        assert_eq!(result, "b");
+    }
+}
@@ -3,7 +3,7 @@
 
 use crate::DscError;
 use crate::configure::context::Context;
-use crate::functions::AcceptedArgKind;
+use crate::functions::{AcceptedArgKind, FunctionCategory};
 use num_traits::cast::NumCast;
 use serde_json::Value;
 use super::Function;
@@ -16,6 +16,22 @@ impl Function for Int {
         vec![AcceptedArgKind::String, AcceptedArgKind::Number]
     }
 
+    fn description(&self) -> String {
+        "Converts the value to an integer.".to_string()
+    }
+
+    fn category(&self) -> FunctionCategory {
+        FunctionCategory::Numeric
+    }
+
+    fn parameter_names(&self) -> Vec<&str> {
+        vec!["valueToConvert"]
+    }
+
+    fn return_types(&self) -> Vec<AcceptedArgKind> {
+        vec![AcceptedArgKind::Number]
+    }
+
     fn min_args(&self) -> usize {
         1
     }
@@ -32,7 +48,11 @@ impl Function for Int {
             let result = input.parse::<f64>().map_err(|_| DscError::FunctionArg("int".to_string(), "unable to parse string to int".to_string()))?;
             value = NumCast::from(result).ok_or(DscError::FunctionArg("int".to_string(), "unable to cast to int".to_string()))?;
         } else if arg.is_number() {
-            value = arg.as_i64().ok_or(DscError::FunctionArg("int".to_string(), "unable to parse number to int".to_string()))?;
+            value = match arg.as_i64() {
+                Some(value) => value,
+                // decimals are truncated like strings with a decimal point
+                None => arg.as_f64().and_then(NumCast::from).ok_or(DscError::FunctionArg("int".to_string(), "unable to convert number to int".to_string()))?,
+            };
         } else {
             return Err(DscError::FunctionArg("int".to_string(), "Invalid argument type".to_string()));
         }
@@ -70,22 +90,36 @@ mod tests {
     #[test]
     fn float() {
         let mut parser = Statement::new().unwrap();
-        let err = parser.parse_and_execute("[int(1.0)]", &Context::new()).unwrap_err();
-        assert!(matches!(err, DscError::Parser(_)));
+        let result = parser.parse_and_execute("[int(1.9)]", &Context::new()).unwrap();
+        assert_eq!(result, 1);
+    }
+
+    #[test]
+    fn negative_float() {
+        let mut parser = Statement::new().unwrap();
+        let result = parser.parse_and_execute("[int(-2.5)]", &Context::new()).unwrap();
+        assert_eq!(result, -2);
+    }
+
+    #[test]
+    fn large_number() {
+        let mut parser = Statement::new().unwrap();
+        let result = parser.parse_and_execute("[int(9223372036854775807)]", &Context::new()).unwrap();
+        assert_eq!(result, i64::MAX);
     }
 
     #[test]
     fn incomplete_float_missing_digit() {
         let mut parser = Statement::new().unwrap();
         let err = parser.parse_and_execute("[int(.2)]", &Context::new()).unwrap_err();
-        assert!(matches!(err, DscError::Parser(_)));
+        assert!(matches!(err, DscError::ParserAt(_)));
     }
 
     #[test]
     fn incomplete_float_missing_decimal() {
         let mut parser = Statement::new().unwrap();
         let err = parser.parse_and_execute("[int(2.)]", &Context::new()).unwrap_err();
-        assert!(matches!(err, DscError::Parser(_)));
+        assert!(matches!(err, DscError::ParserAt(_)));
     }
 
     #[test]
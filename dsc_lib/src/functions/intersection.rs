@@ -0,0 +1,106 @@
+// Copyright (c) Microsoft Corporation.
+// Licensed under the MIT License.
+
+use crate::DscError;
+use crate::configure::context::Context;
+use crate::functions::{AcceptedArgKind, Function, FunctionCategory};
+use serde_json::{Map, Value};
+use tracing::debug;
+
+#[derive(Debug, Default)]
+pub struct Intersection {}
+
+impl Function for Intersection {
+    fn min_args(&self) -> usize {
+        2
+    }
+
+    fn max_args(&self) -> usize {
+        usize::MAX
+    }
+
+    fn accepted_arg_types(&self) -> Vec<AcceptedArgKind> {
+        vec![AcceptedArgKind::Array, AcceptedArgKind::Object]
+    }
+
+    fn description(&self) -> String {
+        "Returns the elements that are common to all arrays or objects.".to_string()
+    }
+
+    fn category(&self) -> FunctionCategory {
+        FunctionCategory::Array
+    }
+
+    fn parameter_names(&self) -> Vec<&str> {
+        vec!["arg1", "arg2"]
+    }
+
+    fn return_types(&self) -> Vec<AcceptedArgKind> {
+        vec![AcceptedArgKind::Array, AcceptedArgKind::Object]
+    }
+
+    fn invoke(&self, args: &[Value], _context: &Context) -> Result<Value, DscError> {
+        debug!("intersection function");
+        if let Some(first) = args[0].as_array() {
+            let mut others = Vec::<&Vec<Value>>::new();
+            for arg in &args[1..] {
+                let Some(array) = arg.as_array() else {
+                    return Err(DscError::Parser("Arguments must all be arrays".to_string()));
+                };
+                others.push(array);
+            }
+            let mut result = Vec::<Value>::new();
+            for element in first {
+                if !result.contains(element) && others.iter().all(|array| array.contains(element)) {
+                    result.push(element.clone());
+                }
+            }
+            Ok(Value::Array(result))
+        } else if let Some(first) = args[0].as_object() {
+            // only keys with the same value in every object are kept
+            let mut others = Vec::<&Map<String, Value>>::new();
+            for arg in &args[1..] {
+                let Some(object) = arg.as_object() else {
+                    return Err(DscError::Parser("Arguments must all be objects".to_string()));
+                };
+                others.push(object);
+            }
+            let mut result = Map::new();
+            for (key, value) in first {
+                if others.iter().all(|object| object.get(key) == Some(value)) {
+                    result.insert(key.clone(), value.clone());
+                }
+            }
+            Ok(Value::Object(result))
+        } else {
+            Err(DscError::Parser("Invalid argument(s)".to_string()))
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::configure::context::Context;
+    use crate::parser::Statement;
+
+    #[test]
+    fn arrays() {
+        let mut parser = Statement::new().unwrap();
+        let result = parser.parse_and_execute("[intersection(createArray('a', 'b', 'c'), createArray('b', 'c', 'd'), createArray('c', 'b'))]", &Context::new()).unwrap();
+        assert_eq!(result.to_string(), r#"["b","c"]"#);
+    }
+
+    #[test]
+    fn objects() {
+        let mut parser = Statement::new().unwrap();
+        let result = parser.parse_and_execute("[intersection(createObject('a', 1, 'b', 2), createObject('a', 1, 'b', 3))]", &Context::new()).unwrap();
+        assert_eq!(result.to_string(), r#"{"a":1}"#);
+    }
+
+    #[test]
+    fn mixed_types() {
+        let mut parser = Statement::new().unwrap();
+        let result = parser.parse_and_execute("[intersection(createObject('b', 1), createArray('a'))]", &Context::new());
+        assert!(result.is_err());
+    }
+}
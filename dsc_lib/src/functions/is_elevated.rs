@@ -0,0 +1,72 @@
+// Copyright (c) Microsoft Corporation.
+// Licensed under the MIT License.
+
+use crate::DscError;
+use crate::configure::config_doc::SecurityContextKind;
+use crate::configure::context::Context;
+use crate::functions::{AcceptedArgKind, Function, FunctionCategory};
+use serde_json::Value;
+use tracing::debug;
+
+#[derive(Debug, Default)]
+pub struct IsElevated {}
+
+impl Function for IsElevated {
+    fn min_args(&self) -> usize {
+        0
+    }
+
+    fn max_args(&self) -> usize {
+        0
+    }
+
+    fn accepted_arg_types(&self) -> Vec<AcceptedArgKind> {
+        vec![]
+    }
+
+    fn description(&self) -> String {
+        "Checks whether the process is running elevated.".to_string()
+    }
+
+    fn category(&self) -> FunctionCategory {
+        FunctionCategory::System
+    }
+
+    fn parameter_names(&self) -> Vec<&str> {
+        vec![]
+    }
+
+    fn return_types(&self) -> Vec<AcceptedArgKind> {
+        vec![AcceptedArgKind::Boolean]
+    }
+
+    fn invoke(&self, _args: &[Value], context: &Context) -> Result<Value, DscError> {
+        debug!("isElevated function");
+        Ok(Value::Bool(context.security_context == SecurityContextKind::Elevated))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::configure::config_doc::SecurityContextKind;
+    use crate::configure::context::Context;
+    use crate::parser::Statement;
+
+    #[test]
+    fn elevated() {
+        let mut parser = Statement::new().unwrap();
+        let mut context = Context::new();
+        context.security_context = SecurityContextKind::Elevated;
+        let result = parser.parse_and_execute("[isElevated()]", &context).unwrap();
+        assert_eq!(result, true);
+    }
+
+    #[test]
+    fn restricted() {
+        let mut parser = Statement::new().unwrap();
+        let mut context = Context::new();
+        context.security_context = SecurityContextKind::Restricted;
+        let result = parser.parse_and_execute("[isElevated()]", &context).unwrap();
+        assert_eq!(result, false);
+    }
+}
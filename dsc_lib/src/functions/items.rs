@@ -0,0 +1,88 @@
+// Copyright (c) Microsoft Corporation.
+// Licensed under the MIT License.
+
+use crate::DscError;
+use crate::configure::context::Context;
+use crate::functions::{AcceptedArgKind, Function, FunctionCategory};
+use serde_json::{Map, Value};
+use tracing::debug;
+
+#[derive(Debug, Default)]
+pub struct Items {}
+
+impl Function for Items {
+    fn min_args(&self) -> usize {
+        1
+    }
+
+    fn max_args(&self) -> usize {
+        1
+    }
+
+    fn accepted_arg_types(&self) -> Vec<AcceptedArgKind> {
+        vec![AcceptedArgKind::Object]
+    }
+
+    fn description(&self) -> String {
+        "Converts an object to an array of key and value objects.".to_string()
+    }
+
+    fn category(&self) -> FunctionCategory {
+        FunctionCategory::Object
+    }
+
+    fn parameter_names(&self) -> Vec<&str> {
+        vec!["object"]
+    }
+
+    fn return_types(&self) -> Vec<AcceptedArgKind> {
+        vec![AcceptedArgKind::Array]
+    }
+
+    fn invoke(&self, args: &[Value], _context: &Context) -> Result<Value, DscError> {
+        debug!("items function");
+        let Some(object) = args[0].as_object() else {
+            return Err(DscError::Parser("Invalid argument".to_string()));
+        };
+
+        // like ARM, the entries are sorted by key
+        let mut keys = object.keys().collect::<Vec<&String>>();
+        keys.sort();
+        let mut result = Vec::<Value>::new();
+        for key in keys {
+            let mut item = Map::new();
+            item.insert("key".to_string(), Value::String(key.clone()));
+            item.insert("value".to_string(), object[key].clone());
+            result.push(Value::Object(item));
+        }
+
+        Ok(Value::Array(result))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::configure::context::Context;
+    use crate::parser::Statement;
+
+    #[test]
+    fn object() {
+        let mut parser = Statement::new().unwrap();
+        let result = parser.parse_and_execute("[items(createObject('b', 2, 'a', 1))]", &Context::new()).unwrap();
+        assert_eq!(result.to_string(), r#"[{"key":"a","value":1},{"key":"b","value":2}]"#);
+    }
+
+    #[test]
+    fn with_accessor() {
+        let mut parser = Statement::new().unwrap();
+        let result = parser.parse_and_execute("[items(createObject('a', 'b'))[0].value]", &Context::new()).unwrap();
+        assert_eq!(result, "b");
+    }
+
+    #[test]
+    fn array() {
+        let mut parser = Statement::new().unwrap();
+        let result = parser.parse_and_execute("[items(createArray('a'))]", &Context::new());
+        assert!(result.is_err());
+    }
+}
@@ -0,0 +1,113 @@
+// Copyright (c) Microsoft Corporation.
+// Licensed under the MIT License.
+
+use crate::DscError;
+use crate::configure::context::Context;
+use crate::functions::{AcceptedArgKind, Function, FunctionCategory};
+use serde_json::Value;
+use tracing::debug;
+
+#[derive(Debug, Default)]
+pub struct Join {}
+
+impl Function for Join {
+    fn min_args(&self) -> usize {
+        2
+    }
+
+    fn max_args(&self) -> usize {
+        2
+    }
+
+    fn accepted_arg_types(&self) -> Vec<AcceptedArgKind> {
+        vec![AcceptedArgKind::Array, AcceptedArgKind::String]
+    }
+
+    fn description(&self) -> String {
+        "Combines the elements of an array into a string.".to_string()
+    }
+
+    fn category(&self) -> FunctionCategory {
+        FunctionCategory::Array
+    }
+
+    fn parameter_names(&self) -> Vec<&str> {
+        vec!["inputArray", "delimiter"]
+    }
+
+    fn parameter_types(&self) -> Vec<Vec<AcceptedArgKind>> {
+        vec![
+            vec![AcceptedArgKind::Array],
+            vec![AcceptedArgKind::String],
+        ]
+    }
+
+    fn return_types(&self) -> Vec<AcceptedArgKind> {
+        vec![AcceptedArgKind::String]
+    }
+
+    fn invoke(&self, args: &[Value], _context: &Context) -> Result<Value, DscError> {
+        debug!("join function");
+        let Some(array) = args[0].as_array() else {
+            return Err(DscError::Parser("First argument must be an array".to_string()));
+        };
+        let Some(delimiter) = args[1].as_str() else {
+            return Err(DscError::Parser("Delimiter must be a string".to_string()));
+        };
+
+        let mut elements = Vec::<String>::new();
+        for element in array {
+            match element {
+                Value::String(value) => elements.push(value.clone()),
+                Value::Number(value) => elements.push(value.to_string()),
+                Value::Bool(value) => elements.push(value.to_string()),
+                _ => {
+                    return Err(DscError::Parser("Array elements must be strings, numbers or booleans".to_string()));
+                }
+            }
+        }
+
+        Ok(Value::String(elements.join(delimiter)))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::configure::context::Context;
+    use crate::parser::Statement;
+
+    #[test]
+    fn strings() {
+        let mut parser = Statement::new().unwrap();
+        let result = parser.parse_and_execute("[join(createArray('a', 'b', 'c'), '-')]", &Context::new()).unwrap();
+        assert_eq!(result, "a-b-c");
+    }
+
+    #[test]
+    fn numbers() {
+        let mut parser = Statement::new().unwrap();
+        let result = parser.parse_and_execute("[join(createArray(1, 2, 3), ',')]", &Context::new()).unwrap();
+        assert_eq!(result, "1,2,3");
+    }
+
+    #[test]
+    fn empty_array() {
+        let mut parser = Statement::new().unwrap();
+        let result = parser.parse_and_execute("[join(createArray(), ',')]", &Context::new()).unwrap();
+        assert_eq!(result, "");
+    }
+
+    #[test]
+    fn nested_arrays() {
+        let mut parser = Statement::new().unwrap();
+        let result = parser.parse_and_execute("[join(createArray(createArray('a')), ',')]", &Context::new());
+        assert!(result.is_err());
+    }
+
+    #[test]
+    fn string_input() {
+        let mut parser = Statement::new().unwrap();
+        let result = parser.parse_and_execute("[join('abc', ',')]", &Context::new());
+        assert!(result.is_err());
+    }
+}
@@ -0,0 +1,79 @@
+// Copyright (c) Microsoft Corporation.
+// Licensed under the MIT License.
+
+use crate::DscError;
+use crate::configure::context::Context;
+use crate::functions::{AcceptedArgKind, Function, FunctionCategory};
+use serde_json::Value;
+use tracing::debug;
+
+#[derive(Debug, Default)]
+pub struct Json {}
+
+impl Function for Json {
+    fn min_args(&self) -> usize {
+        1
+    }
+
+    fn max_args(&self) -> usize {
+        1
+    }
+
+    fn accepted_arg_types(&self) -> Vec<AcceptedArgKind> {
+        vec![AcceptedArgKind::String]
+    }
+
+    fn description(&self) -> String {
+        "Converts a JSON string to a value.".to_string()
+    }
+
+    fn category(&self) -> FunctionCategory {
+        FunctionCategory::Object
+    }
+
+    fn parameter_names(&self) -> Vec<&str> {
+        vec!["arg1"]
+    }
+
+    fn return_types(&self) -> Vec<AcceptedArgKind> {
+        vec![AcceptedArgKind::Array, AcceptedArgKind::Boolean, AcceptedArgKind::Number, AcceptedArgKind::Object, AcceptedArgKind::String]
+    }
+
+    fn invoke(&self, args: &[Value], _context: &Context) -> Result<Value, DscError> {
+        debug!("json function");
+        let Some(json) = args[0].as_str() else {
+            return Err(DscError::Parser("Invalid argument".to_string()));
+        };
+        match serde_json::from_str(json) {
+            Ok(value) => Ok(value),
+            Err(err) => Err(DscError::FunctionArg("json".to_string(), format!("Invalid JSON: {err}"))),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::configure::context::Context;
+    use crate::parser::Statement;
+
+    #[test]
+    fn object() {
+        let mut parser = Statement::new().unwrap();
+        let result = parser.parse_and_execute(r#"[json('{"a": [1, 2]}')]"#, &Context::new()).unwrap();
+        assert_eq!(result.to_string(), r#"{"a":[1,2]}"#);
+    }
+
+    #[test]
+    fn with_accessor() {
+        let mut parser = Statement::new().unwrap();
+        let result = parser.parse_and_execute(r#"[json('{"a": [1, 2]}').a[1]]"#, &Context::new()).unwrap();
+        assert_eq!(result, 2);
+    }
+
+    #[test]
+    fn invalid_json() {
+        let mut parser = Statement::new().unwrap();
+        let result = parser.parse_and_execute("[json('{')]", &Context::new());
+        assert!(result.is_err());
+    }
+}
@@ -0,0 +1,75 @@
+// Copyright (c) Microsoft Corporation.
+// Licensed under the MIT License.
+
+use crate::DscError;
+use crate::configure::context::Context;
+use crate::functions::{AcceptedArgKind, Function, FunctionCategory};
+use serde_json::Value;
+use tracing::debug;
+
+#[derive(Debug, Default)]
+pub struct LambdaVariables {}
+
+impl Function for LambdaVariables {
+    fn min_args(&self) -> usize {
+        1
+    }
+
+    fn max_args(&self) -> usize {
+        1
+    }
+
+    fn accepted_arg_types(&self) -> Vec<AcceptedArgKind> {
+        vec![AcceptedArgKind::String]
+    }
+
+    fn description(&self) -> String {
+        "Returns the value of a lambda parameter.".to_string()
+    }
+
+    fn category(&self) -> FunctionCategory {
+        FunctionCategory::Lambda
+    }
+
+    fn parameter_names(&self) -> Vec<&str> {
+        vec!["lambdaVariableName"]
+    }
+
+    fn return_types(&self) -> Vec<AcceptedArgKind> {
+        vec![AcceptedArgKind::Array, AcceptedArgKind::Boolean, AcceptedArgKind::Number, AcceptedArgKind::Object, AcceptedArgKind::String]
+    }
+
+    fn invoke(&self, args: &[Value], context: &Context) -> Result<Value, DscError> {
+        debug!("lambdaVariables function");
+        if let Some(key) = args[0].as_str() {
+            match context.lambda_variables.borrow().get(key) {
+                Some(value) => Ok(value.clone()),
+                None => Err(DscError::Parser(format!("Lambda variable '{key}' does not exist or is used outside of a lambda"))),
+            }
+        } else {
+            Err(DscError::Parser("Invalid argument".to_string()))
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::configure::context::Context;
+    use crate::parser::Statement;
+
+    #[test]
+    fn valid_variable() {
+        let mut parser = Statement::new().unwrap();
+        let context = Context::new();
+        context.lambda_variables.borrow_mut().insert("x".to_string(), "a".into());
+        let result = parser.parse_and_execute("[lambdaVariables('x')]", &context).unwrap();
+        assert_eq!(result, "a");
+    }
+
+    #[test]
+    fn outside_lambda() {
+        let mut parser = Statement::new().unwrap();
+        let result = parser.parse_and_execute("[lambdaVariables('x')]", &Context::new());
+        assert!(result.is_err());
+    }
+}
@@ -0,0 +1,76 @@
+// Copyright (c) Microsoft Corporation.
+// Licensed under the MIT License.
+
+use crate::DscError;
+use crate::configure::context::Context;
+use crate::functions::{AcceptedArgKind, Function, FunctionCategory};
+use serde_json::Value;
+use tracing::debug;
+
+#[derive(Debug, Default)]
+pub struct LastIndexOf {}
+
+impl Function for LastIndexOf {
+    fn min_args(&self) -> usize {
+        2
+    }
+
+    fn max_args(&self) -> usize {
+        2
+    }
+
+    fn accepted_arg_types(&self) -> Vec<AcceptedArgKind> {
+        vec![AcceptedArgKind::String]
+    }
+
+    fn description(&self) -> String {
+        "Returns the last position of a value within a string.".to_string()
+    }
+
+    fn category(&self) -> FunctionCategory {
+        FunctionCategory::String
+    }
+
+    fn parameter_names(&self) -> Vec<&str> {
+        vec!["stringToSearch", "stringToFind"]
+    }
+
+    fn return_types(&self) -> Vec<AcceptedArgKind> {
+        vec![AcceptedArgKind::Number]
+    }
+
+    fn invoke(&self, args: &[Value], _context: &Context) -> Result<Value, DscError> {
+        debug!("lastIndexOf function");
+        if let (Some(value), Some(search)) = (args[0].as_str(), args[1].as_str()) {
+            // comparison is case-insensitive to match ARM, the result is the character index or -1 if not found
+            let value = value.to_lowercase();
+            let index = match value.rfind(&search.to_lowercase()) {
+                Some(byte_index) => i64::try_from(value[..byte_index].chars().count())?,
+                None => -1,
+            };
+            Ok(Value::Number(index.into()))
+        } else {
+            Err(DscError::Parser("Invalid argument(s)".to_string()))
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::configure::context::Context;
+    use crate::parser::Statement;
+
+    #[test]
+    fn found() {
+        let mut parser = Statement::new().unwrap();
+        let result = parser.parse_and_execute("[lastIndexOf('abcabc', 'B')]", &Context::new()).unwrap();
+        assert_eq!(result, 4);
+    }
+
+    #[test]
+    fn not_found() {
+        let mut parser = Statement::new().unwrap();
+        let result = parser.parse_and_execute("[lastIndexOf('abcdef', 'z')]", &Context::new()).unwrap();
+        assert_eq!(result, -1);
+    }
+}
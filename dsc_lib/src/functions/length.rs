@@ -0,0 +1,88 @@
+// Copyright (c) Microsoft Corporation.
+// Licensed under the MIT License.
+
+use crate::DscError;
+use crate::configure::context::Context;
+use crate::functions::{AcceptedArgKind, Function, FunctionCategory};
+use serde_json::Value;
+use tracing::debug;
+
+#[derive(Debug, Default)]
+pub struct Length {}
+
+impl Function for Length {
+    fn min_args(&self) -> usize {
+        1
+    }
+
+    fn max_args(&self) -> usize {
+        1
+    }
+
+    fn accepted_arg_types(&self) -> Vec<AcceptedArgKind> {
+        vec![AcceptedArgKind::Array, AcceptedArgKind::Object, AcceptedArgKind::String]
+    }
+
+    fn description(&self) -> String {
+        "Returns the number of elements in an array, properties in an object or characters in a string.".to_string()
+    }
+
+    fn category(&self) -> FunctionCategory {
+        FunctionCategory::Array
+    }
+
+    fn parameter_names(&self) -> Vec<&str> {
+        vec!["arg1"]
+    }
+
+    fn return_types(&self) -> Vec<AcceptedArgKind> {
+        vec![AcceptedArgKind::Number]
+    }
+
+    fn invoke(&self, args: &[Value], _context: &Context) -> Result<Value, DscError> {
+        debug!("length function");
+        let length = match &args[0] {
+            Value::Array(array) => array.len(),
+            Value::Object(object) => object.len(),
+            Value::String(string) => string.chars().count(),
+            _ => {
+                return Err(DscError::Parser("Invalid argument".to_string()));
+            }
+        };
+        Ok(Value::Number(i64::try_from(length)?.into()))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::configure::context::Context;
+    use crate::parser::Statement;
+
+    #[test]
+    fn array() {
+        let mut parser = Statement::new().unwrap();
+        let result = parser.parse_and_execute("[length(createArray('a', 'b', 'c'))]", &Context::new()).unwrap();
+        assert_eq!(result, 3);
+    }
+
+    #[test]
+    fn string() {
+        let mut parser = Statement::new().unwrap();
+        let result = parser.parse_and_execute("[length('hello')]", &Context::new()).unwrap();
+        assert_eq!(result, 5);
+    }
+
+    #[test]
+    fn object() {
+        let mut parser = Statement::new().unwrap();
+        let result = parser.parse_and_execute("[length(createObject('a', 1, 'b', 2))]", &Context::new()).unwrap();
+        assert_eq!(result, 2);
+    }
+
+    #[test]
+    fn number() {
+        let mut parser = Statement::new().unwrap();
+        let result = parser.parse_and_execute("[length(1)]", &Context::new());
+        assert!(result.is_err());
+    }
+}
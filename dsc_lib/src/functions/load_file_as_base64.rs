@@ -0,0 +1,82 @@
+// Copyright (c) Microsoft Corporation.
+// Licensed under the MIT License.
+
+use base64::{Engine as _, engine::general_purpose};
+
+use crate::DscError;
+use crate::configure::context::Context;
+use crate::functions::{AcceptedArgKind, Function, FunctionCategory, resolve_config_path};
+use serde_json::Value;
+use tracing::debug;
+
+#[derive(Debug, Default)]
+pub struct LoadFileAsBase64 {}
+
+impl Function for LoadFileAsBase64 {
+    fn min_args(&self) -> usize {
+        1
+    }
+
+    fn max_args(&self) -> usize {
+        1
+    }
+
+    fn accepted_arg_types(&self) -> Vec<AcceptedArgKind> {
+        vec![AcceptedArgKind::String]
+    }
+
+    fn description(&self) -> String {
+        "Returns the content of a file relative to the configuration as base64.".to_string()
+    }
+
+    fn category(&self) -> FunctionCategory {
+        FunctionCategory::File
+    }
+
+    fn parameter_names(&self) -> Vec<&str> {
+        vec!["filePath"]
+    }
+
+    fn return_types(&self) -> Vec<AcceptedArgKind> {
+        vec![AcceptedArgKind::String]
+    }
+
+    fn invoke(&self, args: &[Value], context: &Context) -> Result<Value, DscError> {
+        debug!("loadFileAsBase64 function");
+        let path = resolve_config_path("loadFileAsBase64", args[0].as_str().unwrap_or_default(), context)?;
+        match std::fs::read(&path) {
+            Ok(bytes) => Ok(Value::String(general_purpose::STANDARD.encode(bytes))),
+            Err(err) => Err(DscError::Function("loadFileAsBase64".to_string(), format!("Failed to read file '{}': {err}", path.display()))),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::configure::context::Context;
+    use crate::parser::Statement;
+
+    // each test has its own directory so tests running in parallel don't write the same files
+    fn context(test_name: &str) -> Context {
+        let config_root = std::env::temp_dir().join(format!("dsc_load_file_as_base64_{test_name}_{}", std::process::id()));
+        std::fs::create_dir_all(&config_root).unwrap();
+        std::fs::write(config_root.join("data.bin"), [0x00, 0xff, 0x10]).unwrap();
+        let mut context = Context::new();
+        context.config_root = Some(config_root);
+        context
+    }
+
+    #[test]
+    fn binary() {
+        let mut parser = Statement::new().unwrap();
+        let result = parser.parse_and_execute("[loadFileAsBase64('data.bin')]", &context("binary")).unwrap();
+        assert_eq!(result, "AP8Q");
+    }
+
+    #[test]
+    fn missing_file() {
+        let mut parser = Statement::new().unwrap();
+        let result = parser.parse_and_execute("[loadFileAsBase64('missing.bin')]", &context("missing_file"));
+        assert!(result.is_err());
+    }
+}
@@ -0,0 +1,99 @@
+// Copyright (c) Microsoft Corporation.
+// Licensed under the MIT License.
+
+use crate::DscError;
+use crate::configure::context::Context;
+use crate::functions::{AcceptedArgKind, Function, FunctionCategory, resolve_config_path};
+use serde_json::Value;
+use tracing::debug;
+
+#[derive(Debug, Default)]
+pub struct LoadJsonContent {}
+
+impl Function for LoadJsonContent {
+    fn min_args(&self) -> usize {
+        1
+    }
+
+    fn max_args(&self) -> usize {
+        1
+    }
+
+    fn accepted_arg_types(&self) -> Vec<AcceptedArgKind> {
+        vec![AcceptedArgKind::String]
+    }
+
+    fn description(&self) -> String {
+        "Returns the parsed JSON content of a file relative to the configuration.".to_string()
+    }
+
+    fn category(&self) -> FunctionCategory {
+        FunctionCategory::File
+    }
+
+    fn parameter_names(&self) -> Vec<&str> {
+        vec!["filePath"]
+    }
+
+    fn return_types(&self) -> Vec<AcceptedArgKind> {
+        vec![AcceptedArgKind::Array, AcceptedArgKind::Boolean, AcceptedArgKind::Number, AcceptedArgKind::Object, AcceptedArgKind::String]
+    }
+
+    fn invoke(&self, args: &[Value], context: &Context) -> Result<Value, DscError> {
+        debug!("loadJsonContent function");
+        let path = resolve_config_path("loadJsonContent", args[0].as_str().unwrap_or_default(), context)?;
+        let json = match std::fs::read_to_string(&path) {
+            Ok(json) => json,
+            Err(err) => return Err(DscError::Function("loadJsonContent".to_string(), format!("Failed to read file '{}': {err}", path.display()))),
+        };
+        match serde_json::from_str(&json) {
+            Ok(value) => Ok(value),
+            Err(err) => Err(DscError::Function("loadJsonContent".to_string(), format!("File '{}' is not valid JSON: {err}", path.display()))),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::configure::context::Context;
+    use crate::parser::Statement;
+
+    // each test has its own directory so tests running in parallel don't write the same files
+    fn context(test_name: &str) -> Context {
+        let config_root = std::env::temp_dir().join(format!("dsc_load_json_content_{test_name}_{}", std::process::id()));
+        std::fs::create_dir_all(&config_root).unwrap();
+        std::fs::write(config_root.join("settings.json"), r#"{"port": 8080, "hosts": ["a", "b"]}"#).unwrap();
+        std::fs::write(config_root.join("invalid.json"), "port: 8080").unwrap();
+        let mut context = Context::new();
+        context.config_root = Some(config_root);
+        context
+    }
+
+    #[test]
+    fn object() {
+        let mut parser = Statement::new().unwrap();
+        let result = parser.parse_and_execute("[loadJsonContent('settings.json')]", &context("object")).unwrap();
+        assert_eq!(result.to_string(), r#"{"port":8080,"hosts":["a","b"]}"#);
+    }
+
+    #[test]
+    fn accessor() {
+        let mut parser = Statement::new().unwrap();
+        let result = parser.parse_and_execute("[loadJsonContent('settings.json').hosts[1]]", &context("accessor")).unwrap();
+        assert_eq!(result, "b");
+    }
+
+    #[test]
+    fn invalid_json() {
+        let mut parser = Statement::new().unwrap();
+        let result = parser.parse_and_execute("[loadJsonContent('invalid.json')]", &context("invalid_json"));
+        assert!(result.is_err());
+    }
+
+    #[test]
+    fn parent_directory() {
+        let mut parser = Statement::new().unwrap();
+        let result = parser.parse_and_execute("[loadJsonContent('../settings.json')]", &context("parent_directory"));
+        assert!(result.is_err());
+    }
+}
@@ -0,0 +1,105 @@
+// Copyright (c) Microsoft Corporation.
+// Licensed under the MIT License.
+
+use crate::DscError;
+use crate::configure::context::Context;
+use crate::functions::{AcceptedArgKind, Function, FunctionCategory, resolve_config_path};
+use serde_json::Value;
+use tracing::debug;
+
+#[derive(Debug, Default)]
+pub struct LoadTextContent {}
+
+impl Function for LoadTextContent {
+    fn min_args(&self) -> usize {
+        1
+    }
+
+    fn max_args(&self) -> usize {
+        1
+    }
+
+    fn accepted_arg_types(&self) -> Vec<AcceptedArgKind> {
+        vec![AcceptedArgKind::String]
+    }
+
+    fn description(&self) -> String {
+        "Returns the text content of a file relative to the configuration.".to_string()
+    }
+
+    fn category(&self) -> FunctionCategory {
+        FunctionCategory::File
+    }
+
+    fn parameter_names(&self) -> Vec<&str> {
+        vec!["filePath"]
+    }
+
+    fn return_types(&self) -> Vec<AcceptedArgKind> {
+        vec![AcceptedArgKind::String]
+    }
+
+    fn invoke(&self, args: &[Value], context: &Context) -> Result<Value, DscError> {
+        debug!("loadTextContent function");
+        let path = resolve_config_path("loadTextContent", args[0].as_str().unwrap_or_default(), context)?;
+        match std::fs::read_to_string(&path) {
+            Ok(text) => Ok(Value::String(text)),
+            Err(err) => Err(DscError::Function("loadTextContent".to_string(), format!("Failed to read file '{}': {err}", path.display()))),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::configure::context::Context;
+    use crate::parser::Statement;
+
+    // each test has its own directory so tests running in parallel don't write the same files
+    fn context(test_name: &str) -> Context {
+        let config_root = std::env::temp_dir().join(format!("dsc_load_text_content_{test_name}_{}", std::process::id()));
+        std::fs::create_dir_all(config_root.join("files")).unwrap();
+        std::fs::write(config_root.join("files").join("motd.txt"), "Hello\nWorld").unwrap();
+        let mut context = Context::new();
+        context.config_root = Some(config_root);
+        context
+    }
+
+    #[test]
+    fn relative_path() {
+        let mut parser = Statement::new().unwrap();
+        let result = parser.parse_and_execute("[loadTextContent('files/motd.txt')]", &context("relative_path")).unwrap();
+        assert_eq!(result, "Hello\nWorld");
+    }
+
+    #[test]
+    fn absolute_path() {
+        let mut parser = Statement::new().unwrap();
+        let context = context("absolute_path");
+        let path = context.config_root.clone().unwrap().join("files").join("motd.txt");
+        let result = parser.parse_and_execute(&format!("[loadTextContent('{}')]", path.to_string_lossy()), &context).unwrap();
+        assert_eq!(result, "Hello\nWorld");
+    }
+
+    #[test]
+    fn absolute_path_outside_root() {
+        let mut parser = Statement::new().unwrap();
+        let context = context("absolute_path_outside_root");
+        let path = std::env::temp_dir().join("motd.txt");
+        let result = parser.parse_and_execute(&format!("[loadTextContent('{}')]", path.to_string_lossy()), &context);
+        assert!(result.unwrap_err().to_string().contains("is not within the configuration root"));
+    }
+
+    #[test]
+    fn parent_directory() {
+        let mut parser = Statement::new().unwrap();
+        let result = parser.parse_and_execute("[loadTextContent('../files/motd.txt')]", &context("parent_directory"));
+        assert!(result.is_err());
+    }
+
+    #[test]
+    fn missing_file() {
+        let mut parser = Statement::new().unwrap();
+        let result = parser.parse_and_execute("[loadTextContent('files/missing.txt')]", &context("missing_file"));
+        assert!(result.is_err());
+    }
+}
@@ -0,0 +1,108 @@
+// Copyright (c) Microsoft Corporation.
+// Licensed under the MIT License.
+
+use crate::DscError;
+use crate::configure::context::Context;
+use crate::functions::{AcceptedArgKind, Function, FunctionCategory, LambdaArg};
+use serde_json::Value;
+use tracing::debug;
+
+#[derive(Debug, Default)]
+pub struct Map {}
+
+impl Function for Map {
+    fn min_args(&self) -> usize {
+        2
+    }
+
+    fn max_args(&self) -> usize {
+        2
+    }
+
+    fn accepted_arg_types(&self) -> Vec<AcceptedArgKind> {
+        vec![AcceptedArgKind::Array]
+    }
+
+    fn description(&self) -> String {
+        "Applies the lambda to each element of an array.".to_string()
+    }
+
+    fn category(&self) -> FunctionCategory {
+        FunctionCategory::Lambda
+    }
+
+    fn parameter_names(&self) -> Vec<&str> {
+        vec!["inputArray", "lambda"]
+    }
+
+    fn parameter_types(&self) -> Vec<Vec<AcceptedArgKind>> {
+        vec![
+            vec![AcceptedArgKind::Array],
+            vec![AcceptedArgKind::Lambda],
+        ]
+    }
+
+    fn return_types(&self) -> Vec<AcceptedArgKind> {
+        vec![AcceptedArgKind::Array]
+    }
+
+    fn invoke(&self, _args: &[Value], _context: &Context) -> Result<Value, DscError> {
+        Err(DscError::Parser("Function 'map' requires a lambda as the second argument".to_string()))
+    }
+
+    fn invoke_with_lambdas(&self, args: &[Value], lambdas: &[LambdaArg], _context: &Context) -> Result<Value, DscError> {
+        debug!("map function");
+        let (Some(array), [lambda]) = (args.first().and_then(Value::as_array), lambdas) else {
+            return Err(DscError::Parser("Function 'map' requires an array and a lambda".to_string()));
+        };
+
+        let mut result = Vec::<Value>::new();
+        for (index, element) in array.iter().enumerate() {
+            // the lambda can optionally accept the index as second parameter
+            result.push(lambda(&[element.clone(), Value::Number(index.into())])?);
+        }
+
+        Ok(Value::Array(result))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::configure::context::Context;
+    use crate::parser::Statement;
+
+    #[test]
+    fn objects() {
+        let mut parser = Statement::new().unwrap();
+        let result = parser.parse_and_execute("[map(createArray(createObject('name', 'a'), createObject('name', 'b')), lambda('user', concat('user-', lambdaVariables('user').name)))]", &Context::new()).unwrap();
+        assert_eq!(result.to_string(), r#"["user-a","user-b"]"#);
+    }
+
+    #[test]
+    fn index() {
+        let mut parser = Statement::new().unwrap();
+        let result = parser.parse_and_execute("[map(createArray('a', 'b'), lambda('x', 'i', lambdaVariables('i')))]", &Context::new()).unwrap();
+        assert_eq!(result.to_string(), "[0,1]");
+    }
+
+    #[test]
+    fn nested() {
+        let mut parser = Statement::new().unwrap();
+        let result = parser.parse_and_execute("[map(range(1, 2), lambda('x', map(range(1, 2), lambda('y', mul(lambdaVariables('x'), lambdaVariables('y'))))))]", &Context::new()).unwrap();
+        assert_eq!(result.to_string(), "[[1,2],[2,4]]");
+    }
+
+    #[test]
+    fn nested_same_name() {
+        let mut parser = Statement::new().unwrap();
+        let result = parser.parse_and_execute("[map(range(1, 2), lambda('x', add(first(map(createArray(10), lambda('x', lambdaVariables('x')))), lambdaVariables('x'))))]", &Context::new()).unwrap();
+        assert_eq!(result.to_string(), "[11,12]");
+    }
+
+    #[test]
+    fn too_many_parameters() {
+        let mut parser = Statement::new().unwrap();
+        let result = parser.parse_and_execute("[map(createArray('a'), lambda('x', 'i', 'z', lambdaVariables('x')))]", &Context::new());
+        assert!(result.is_err());
+    }
+}
@@ -3,8 +3,9 @@
 
 use crate::DscError;
 use crate::configure::context::Context;
-use crate::functions::{AcceptedArgKind, Function};
+use crate::functions::{AcceptedArgKind, Function, FunctionCategory, compare_values};
 use serde_json::Value;
+use std::cmp::Ordering;
 use tracing::debug;
 
 #[derive(Debug, Default)]
@@ -23,6 +24,22 @@ impl Function for Max {
         vec![AcceptedArgKind::Number, AcceptedArgKind::Array]
     }
 
+    fn description(&self) -> String {
+        "Returns the maximum of the numbers or an array of numbers.".to_string()
+    }
+
+    fn category(&self) -> FunctionCategory {
+        FunctionCategory::Numeric
+    }
+
+    fn parameter_names(&self) -> Vec<&str> {
+        vec!["arg1"]
+    }
+
+    fn return_types(&self) -> Vec<AcceptedArgKind> {
+        vec![AcceptedArgKind::Number]
+    }
+
     fn invoke(&self, args: &[Value], _context: &Context) -> Result<Value, DscError> {
         debug!("max function");
         if args.len() == 1 {
@@ -40,9 +57,18 @@ impl Function for Max {
 }
 
 fn find_max(args: &[Value]) -> Result<Value, DscError> {
-    let array = args.iter().map(|v| v.as_i64().ok_or(DscError::Parser("Input must only contain integers".to_string()))).collect::<Result<Vec<i64>, DscError>>()?;
-    let value = array.iter().max().ok_or(DscError::Parser("Unable to find max value".to_string()))?;
-    Ok(Value::Number((*value).into()))
+    if !args.iter().all(Value::is_number) {
+        return Err(DscError::Parser("Input must only contain numbers".to_string()));
+    }
+    let Some(mut result) = args.first() else {
+        return Err(DscError::Parser("Unable to find max value".to_string()));
+    };
+    for value in &args[1..] {
+        if compare_values("max", value, result)? == Ordering::Greater {
+            result = value;
+        }
+    }
+    Ok(result.clone())
 }
 
 #[cfg(test)]
@@ -112,4 +138,18 @@ mod tests {
         let result = parser.parse_and_execute("[max(createArray(0,2), 1)]", &Context::new());
         assert!(result.is_err());
     }
+
+    #[test]
+    fn decimals() {
+        let mut parser = Statement::new().unwrap();
+        let result = parser.parse_and_execute("[max(2, -1.5, 3.25)]", &Context::new()).unwrap();
+        assert_eq!(result, 3.25);
+    }
+
+    #[test]
+    fn large_numbers() {
+        let mut parser = Statement::new().unwrap();
+        let result = parser.parse_and_execute("[max(createArray(5000000000, -5000000000))]", &Context::new()).unwrap();
+        assert_eq!(result, 5_000_000_000_i64);
+    }
 }
@@ -1,37 +1,179 @@
 // Copyright (c) Microsoft Corporation.
 // Licensed under the MIT License.
 
+use std::cell::RefCell;
+use std::cmp::Ordering;
 use std::collections::HashMap;
+use std::path::{Component, Path, PathBuf};
+use std::rc::Rc;
 
 use crate::DscError;
 use crate::configure::context::Context;
+use crate::secrets::{SecretProvider, SecretStore};
+use serde::{Deserialize, Serialize};
 use serde_json::Value;
 
 pub mod add;
+pub mod and;
+pub mod architecture;
+pub mod array;
 pub mod base64;
+pub mod bool;
+pub mod coalesce;
 pub mod concat;
+pub mod config_root;
+pub mod contains;
+pub mod context_function;
 pub mod create_array;
+pub mod create_object;
+pub mod date_time_add;
+pub mod date_time_from_epoch;
+pub mod date_time_to_epoch;
 pub mod div;
+pub mod empty;
+pub mod ends_with;
 pub mod envvar;
+pub mod equals;
+pub mod external_function;
+pub mod false_function;
+pub mod filter;
+pub mod first;
+pub mod format;
+pub mod greater;
+pub mod greater_or_equals;
+pub mod guid;
+pub mod hostname;
+pub mod if_function;
+pub mod index_of;
 pub mod int;
+pub mod intersection;
+pub mod is_elevated;
+pub mod items;
+pub mod join;
+pub mod json;
+pub mod lambda_variables;
+pub mod last;
+pub mod last_index_of;
+pub mod length;
+pub mod less;
+pub mod less_or_equals;
+pub mod load_file_as_base64;
+pub mod load_json_content;
+pub mod load_text_content;
+pub mod map;
 pub mod max;
 pub mod min;
 pub mod mod_function;
 pub mod mul;
+pub mod new_guid;
+pub mod not;
+pub mod or;
+pub mod os;
+pub mod pad_left;
 pub mod parameters;
+pub mod path;
+pub mod range;
+pub mod reduce;
 pub mod reference;
+pub mod replace;
 pub mod resource_id;
+pub mod secret;
+pub mod skip;
+pub mod sort;
+pub mod split;
+pub mod starts_with;
+pub mod string;
 pub mod sub;
+pub mod substring;
+pub mod take;
+pub mod to_lower;
+pub mod to_object;
+pub mod to_upper;
+pub mod trim;
+pub mod true_function;
+pub mod union;
+pub mod unique_string;
+pub mod user_function;
+pub mod utc_now;
 pub mod variables;
 
 /// The kind of argument that a function accepts.
-#[derive(Debug, PartialEq)]
+#[derive(Debug, Clone, PartialEq, Deserialize, Serialize)]
 pub enum AcceptedArgKind {
     Array,
     Boolean,
     Number,
     Object,
     String,
+    /// A lambda, only used to describe the parameters of a function.
+    Lambda,
+}
+
+impl AcceptedArgKind {
+    /// The kind of a value, `None` for null.
+    #[must_use]
+    pub fn from_value(value: &Value) -> Option<Self> {
+        match value {
+            Value::Array(_) => Some(AcceptedArgKind::Array),
+            Value::Bool(_) => Some(AcceptedArgKind::Boolean),
+            Value::Number(_) => Some(AcceptedArgKind::Number),
+            Value::Object(_) => Some(AcceptedArgKind::Object),
+            Value::String(_) => Some(AcceptedArgKind::String),
+            Value::Null => None,
+        }
+    }
+}
+
+/// The category of a function.
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub enum FunctionCategory {
+    Array,
+    Comparison,
+    Date,
+    Deployment,
+    External,
+    File,
+    Lambda,
+    Logical,
+    Numeric,
+    Object,
+    Resource,
+    String,
+    System,
+}
+
+/// A parameter of a function as shown by `dsc function list`.
+#[derive(Debug, Clone, Serialize)]
+pub struct FunctionParameter {
+    /// The name of the parameter.
+    pub name: String,
+    /// The types of arguments that the parameter accepts.
+    pub types: Vec<AcceptedArgKind>,
+}
+
+/// The description of a function as shown by `dsc function list`.
+#[derive(Debug, Clone, Serialize)]
+pub struct FunctionMetadata {
+    /// The name of the function.
+    pub name: String,
+    /// A short description of the function.
+    pub description: String,
+    /// The category of the function.
+    pub category: FunctionCategory,
+    /// The minimum number of arguments that the function accepts.
+    #[serde(rename = "minArgs")]
+    pub min_args: usize,
+    /// The maximum number of arguments that the function accepts.
+    #[serde(rename = "maxArgs")]
+    pub max_args: usize,
+    /// The parameters, the last one can be repeated up to the maximum number of arguments.
+    pub parameters: Vec<FunctionParameter>,
+    /// The types of arguments that the function accepts.
+    #[serde(rename = "acceptedArgTypes")]
+    pub accepted_arg_types: Vec<AcceptedArgKind>,
+    /// The types of values that the function returns.
+    #[serde(rename = "returnTypes")]
+    pub return_types: Vec<AcceptedArgKind>,
 }
 
 /// A function that can be invoked.
@@ -42,6 +184,18 @@ pub trait Function {
     fn max_args(&self) -> usize;
     /// The types of arguments that the function accepts.
     fn accepted_arg_types(&self) -> Vec<AcceptedArgKind>;
+    /// A short description of the function.
+    fn description(&self) -> String;
+    /// The category of the function.
+    fn category(&self) -> FunctionCategory;
+    /// The names of the parameters, the last one can be repeated up to `max_args()`.
+    fn parameter_names(&self) -> Vec<&str>;
+    /// The types of arguments that each parameter accepts, in the same order as `parameter_names()`.
+    fn parameter_types(&self) -> Vec<Vec<AcceptedArgKind>> {
+        self.parameter_names().iter().map(|_| self.accepted_arg_types()).collect()
+    }
+    /// The types of values that the function returns.
+    fn return_types(&self) -> Vec<AcceptedArgKind>;
     /// Invoke the function.
     ///
     /// # Arguments
@@ -52,36 +206,144 @@ pub trait Function {
     ///
     /// This function will return an error if the function fails to execute.
     fn invoke(&self, args: &[Value], context: &Context) -> Result<Value, DscError>;
+    /// Whether the arguments are evaluated on demand by `invoke_lazy()` instead of before `invoke()`.
+    fn lazy_args(&self) -> bool {
+        false
+    }
+    /// Invoke the function with arguments that are only evaluated when requested.
+    ///
+    /// # Arguments
+    ///
+    /// * `args` - The deferred arguments to the function.
+    ///
+    /// # Errors
+    ///
+    /// This function will return an error if the function fails to execute.
+    fn invoke_lazy(&self, args: &[LazyArg], context: &Context) -> Result<Value, DscError> {
+        let args = args.iter().map(|arg| arg()).collect::<Result<Vec<Value>, DscError>>()?;
+        self.invoke(&args, context)
+    }
+    /// Invoke the function with lambda arguments, which always follow the other arguments.
+    ///
+    /// # Arguments
+    ///
+    /// * `args` - The evaluated arguments to the function.
+    /// * `lambdas` - The lambda arguments to the function.
+    ///
+    /// # Errors
+    ///
+    /// This function will return an error if the function fails to execute or doesn't accept lambdas.
+    fn invoke_with_lambdas(&self, _args: &[Value], _lambdas: &[LambdaArg], _context: &Context) -> Result<Value, DscError> {
+        Err(DscError::Parser("Function does not accept lambda arguments".to_string()))
+    }
 }
 
+/// A function argument that is evaluated when called.
+pub type LazyArg<'a> = Box<dyn Fn() -> Result<Value, DscError> + 'a>;
+
+/// A lambda function argument that is invoked with values for its parameters.
+pub type LambdaArg<'a> = Box<dyn Fn(&[Value]) -> Result<Value, DscError> + 'a>;
+
 /// A dispatcher for functions.
 pub struct FunctionDispatcher {
     functions: HashMap<String, Box<dyn Function>>,
+    user_functions: HashMap<String, user_function::UserFunction>,
+    secret_store: Rc<RefCell<SecretStore>>,
 }
 
 impl FunctionDispatcher {
     /// Create a new `FunctionDispatcher` instance.
     #[must_use]
     pub fn new() -> Self {
+        // the store is shared with `secret()` so providers can be added after it's registered
+        let secret_store = Rc::new(RefCell::new(SecretStore::new()));
         let mut functions: HashMap<String, Box<dyn Function>> = HashMap::new();
         functions.insert("add".to_string(), Box::new(add::Add{}));
+        functions.insert("and".to_string(), Box::new(and::And{}));
+        functions.insert("architecture".to_string(), Box::new(architecture::Architecture{}));
+        functions.insert("array".to_string(), Box::new(array::Array{}));
         functions.insert("base64".to_string(), Box::new(base64::Base64{}));
+        functions.insert("bool".to_string(), Box::new(bool::Bool{}));
+        functions.insert("coalesce".to_string(), Box::new(coalesce::Coalesce{}));
         functions.insert("concat".to_string(), Box::new(concat::Concat{}));
+        functions.insert("configRoot".to_string(), Box::new(config_root::ConfigRoot{}));
+        functions.insert("contains".to_string(), Box::new(contains::Contains{}));
+        functions.insert("context".to_string(), Box::new(context_function::ContextFn{}));
         functions.insert("createArray".to_string(), Box::new(create_array::CreateArray{}));
+        functions.insert("createObject".to_string(), Box::new(create_object::CreateObject{}));
+        functions.insert("dateTimeAdd".to_string(), Box::new(date_time_add::DateTimeAdd{}));
+        functions.insert("dateTimeFromEpoch".to_string(), Box::new(date_time_from_epoch::DateTimeFromEpoch{}));
+        functions.insert("dateTimeToEpoch".to_string(), Box::new(date_time_to_epoch::DateTimeToEpoch{}));
         functions.insert("div".to_string(), Box::new(div::Div{}));
+        functions.insert("empty".to_string(), Box::new(empty::Empty{}));
+        functions.insert("endsWith".to_string(), Box::new(ends_with::EndsWith{}));
         functions.insert("envvar".to_string(), Box::new(envvar::Envvar{}));
+        functions.insert("equals".to_string(), Box::new(equals::Equals{}));
+        functions.insert("false".to_string(), Box::new(false_function::False{}));
+        functions.insert("filter".to_string(), Box::new(filter::Filter{}));
+        functions.insert("first".to_string(), Box::new(first::First{}));
+        functions.insert("format".to_string(), Box::new(format::Format{}));
+        functions.insert("greater".to_string(), Box::new(greater::Greater{}));
+        functions.insert("greaterOrEquals".to_string(), Box::new(greater_or_equals::GreaterOrEquals{}));
+        functions.insert("guid".to_string(), Box::new(guid::Guid{}));
+        functions.insert("hostname".to_string(), Box::new(hostname::Hostname{}));
+        functions.insert("if".to_string(), Box::new(if_function::If{}));
+        functions.insert("indexOf".to_string(), Box::new(index_of::IndexOf{}));
         functions.insert("int".to_string(), Box::new(int::Int{}));
+        functions.insert("intersection".to_string(), Box::new(intersection::Intersection{}));
+        functions.insert("isElevated".to_string(), Box::new(is_elevated::IsElevated{}));
+        functions.insert("items".to_string(), Box::new(items::Items{}));
+        functions.insert("join".to_string(), Box::new(join::Join{}));
+        functions.insert("joinPath".to_string(), Box::new(path::PathFn{}));
+        functions.insert("json".to_string(), Box::new(json::Json{}));
+        functions.insert("lambdaVariables".to_string(), Box::new(lambda_variables::LambdaVariables{}));
+        functions.insert("last".to_string(), Box::new(last::Last{}));
+        functions.insert("lastIndexOf".to_string(), Box::new(last_index_of::LastIndexOf{}));
+        functions.insert("length".to_string(), Box::new(length::Length{}));
+        functions.insert("less".to_string(), Box::new(less::Less{}));
+        functions.insert("lessOrEquals".to_string(), Box::new(less_or_equals::LessOrEquals{}));
+        functions.insert("loadFileAsBase64".to_string(), Box::new(load_file_as_base64::LoadFileAsBase64{}));
+        functions.insert("loadJsonContent".to_string(), Box::new(load_json_content::LoadJsonContent{}));
+        functions.insert("loadTextContent".to_string(), Box::new(load_text_content::LoadTextContent{}));
+        functions.insert("map".to_string(), Box::new(map::Map{}));
         functions.insert("max".to_string(), Box::new(max::Max{}));
         functions.insert("min".to_string(), Box::new(min::Min{}));
         functions.insert("mod".to_string(), Box::new(mod_function::Mod{}));
         functions.insert("mul".to_string(), Box::new(mul::Mul{}));
+        functions.insert("newGuid".to_string(), Box::new(new_guid::NewGuid{}));
+        functions.insert("not".to_string(), Box::new(not::Not{}));
+        functions.insert("or".to_string(), Box::new(or::Or{}));
+        functions.insert("os".to_string(), Box::new(os::Os{}));
+        functions.insert("padLeft".to_string(), Box::new(pad_left::PadLeft{}));
         functions.insert("parameters".to_string(), Box::new(parameters::Parameters{}));
+        functions.insert("path".to_string(), Box::new(path::PathFn{}));
+        functions.insert("range".to_string(), Box::new(range::Range{}));
+        functions.insert("reduce".to_string(), Box::new(reduce::Reduce{}));
         functions.insert("reference".to_string(), Box::new(reference::Reference{}));
+        functions.insert("replace".to_string(), Box::new(replace::Replace{}));
         functions.insert("resourceId".to_string(), Box::new(resource_id::ResourceId{}));
+        functions.insert("secret".to_string(), Box::new(secret::Secret::new(Rc::clone(&secret_store))));
+        functions.insert("skip".to_string(), Box::new(skip::Skip{}));
+        functions.insert("sort".to_string(), Box::new(sort::Sort{}));
+        functions.insert("split".to_string(), Box::new(split::Split{}));
+        functions.insert("startsWith".to_string(), Box::new(starts_with::StartsWith{}));
+        functions.insert("string".to_string(), Box::new(string::StringFn{}));
         functions.insert("sub".to_string(), Box::new(sub::Sub{}));
+        functions.insert("substring".to_string(), Box::new(substring::Substring{}));
+        functions.insert("take".to_string(), Box::new(take::Take{}));
+        functions.insert("toLower".to_string(), Box::new(to_lower::ToLower{}));
+        functions.insert("toObject".to_string(), Box::new(to_object::ToObject{}));
+        functions.insert("toUpper".to_string(), Box::new(to_upper::ToUpper{}));
+        functions.insert("trim".to_string(), Box::new(trim::Trim{}));
+        functions.insert("true".to_string(), Box::new(true_function::True{}));
+        functions.insert("union".to_string(), Box::new(union::Union{}));
+        functions.insert("uniqueString".to_string(), Box::new(unique_string::UniqueString{}));
+        functions.insert("utcNow".to_string(), Box::new(utc_now::UtcNow{}));
         functions.insert("variables".to_string(), Box::new(variables::Variables{}));
         Self {
             functions,
+            user_functions: HashMap::new(),
+            secret_store,
         }
     }
 
@@ -96,6 +358,189 @@ impl FunctionDispatcher {
     ///
     /// This function will return an error if the function fails to execute.
     pub fn invoke(&self, name: &str, args: &Vec<Value>, context: &Context) -> Result<Value, DscError> {
+        if let Some(user_function) = self.user_functions.get(name) {
+            return user_function.invoke(args, self, context);
+        }
+
+        let function = self.get_function(name, args.len())?;
+
+        // check if arg types are valid
+        let accepted_arg_types = function.accepted_arg_types();
+        for value in args {
+            validate_arg_type(name, &accepted_arg_types, value)?;
+        }
+
+        function.invoke(args, context)
+    }
+
+    /// Add a secret provider that `secret()` can get secrets from.
+    ///
+    /// # Arguments
+    ///
+    /// * `vault` - The name of the vault used to refer to the provider.
+    /// * `provider` - The provider.
+    ///
+    /// # Errors
+    ///
+    /// This function will return an error if a provider with the same vault name exists.
+    pub fn add_secret_provider(&mut self, vault: &str, provider: Box<dyn SecretProvider>) -> Result<(), DscError> {
+        self.secret_store.borrow_mut().add_provider(vault, provider)
+    }
+
+    /// Add a function defined in the configuration.
+    ///
+    /// # Arguments
+    ///
+    /// * `function` - The user function to add.
+    ///
+    /// # Errors
+    ///
+    /// This function will return an error if a function with the same name already exists.
+    pub fn add_user_function(&mut self, function: user_function::UserFunction) -> Result<(), DscError> {
+        let name = function.name().to_string();
+        if self.functions.contains_key(&name) || self.user_functions.contains_key(&name) {
+            return Err(DscError::Validation(format!("Function '{name}' is already defined")));
+        }
+        self.user_functions.insert(name, function);
+        Ok(())
+    }
+
+    /// Add a function supplied by a function provider.
+    ///
+    /// # Arguments
+    ///
+    /// * `function` - The external function to add.
+    ///
+    /// # Errors
+    ///
+    /// This function will return an error if a function with the same name already exists.
+    pub fn add_external_function(&mut self, function: external_function::ExternalFunction) -> Result<(), DscError> {
+        let name = function.name().to_string();
+        if self.functions.contains_key(&name) || self.user_functions.contains_key(&name) {
+            return Err(DscError::Validation(format!("Function '{name}' is already defined")));
+        }
+        self.functions.insert(name, Box::new(function));
+        Ok(())
+    }
+
+    /// Check a function call without invoking it.
+    ///
+    /// # Arguments
+    ///
+    /// * `name` - The name of the function.
+    /// * `arg_types` - The possible types of each argument that isn't a lambda, `None` if they are only known when executed.
+    /// * `lambda_count` - The number of lambda arguments.
+    ///
+    /// # Returns
+    ///
+    /// The types that the function can return.
+    ///
+    /// # Errors
+    ///
+    /// This function will return an error if the function is unknown or the arguments are invalid.
+    pub fn validate(&self, name: &str, arg_types: &[Option<Vec<AcceptedArgKind>>], lambda_count: usize) -> Result<Vec<AcceptedArgKind>, DscError> {
+        if let Some(user_function) = self.user_functions.get(name) {
+            return user_function.validate(arg_types, lambda_count);
+        }
+
+        let function = self.get_function(name, arg_types.len() + lambda_count)?;
+        if lambda_count > 0 && function.category() != FunctionCategory::Lambda {
+            return Err(DscError::Parser(format!("Function '{name}' does not accept lambda arguments")));
+        }
+
+        // an argument is only invalid if none of the types it can have are accepted
+        let accepted_arg_types = function.accepted_arg_types();
+        for kinds in arg_types.iter().flatten() {
+            if !kinds.iter().any(|kind| accepted_arg_types.contains(kind)) {
+                let kinds_string = kinds.iter().map(|x| format!("{x:?}")).collect::<Vec<String>>().join(", ");
+                let accepted_args_string = accepted_arg_types.iter().map(|x| format!("{x:?}")).collect::<Vec<String>>().join(", ");
+                return Err(DscError::Parser(format!("Function '{name}' does not accept {kinds_string} arguments, accepted types are: {accepted_args_string}")));
+            }
+        }
+
+        Ok(function.return_types())
+    }
+
+    /// List the registered functions sorted by name.
+    #[must_use]
+    pub fn list(&self) -> Vec<FunctionMetadata> {
+        let mut functions = self.functions.iter().map(|(name, function)| FunctionMetadata {
+            name: name.clone(),
+            description: function.description(),
+            category: function.category(),
+            min_args: function.min_args(),
+            max_args: function.max_args(),
+            parameters: function.parameter_names().iter().zip(function.parameter_types()).map(|(name, types)| FunctionParameter {
+                name: (*name).to_string(),
+                types,
+            }).collect(),
+            accepted_arg_types: function.accepted_arg_types(),
+            return_types: function.return_types(),
+        }).collect::<Vec<FunctionMetadata>>();
+        functions.sort_by(|a, b| a.name.cmp(&b.name));
+        functions
+    }
+
+    /// Check if a function evaluates its arguments on demand.
+    ///
+    /// # Arguments
+    ///
+    /// * `name` - The name of the function.
+    #[must_use]
+    pub fn is_lazy(&self, name: &str) -> bool {
+        self.functions.get(name).is_some_and(|function| function.lazy_args())
+    }
+
+    /// Invoke a function whose arguments are evaluated on demand.
+    ///
+    /// # Arguments
+    ///
+    /// * `name` - The name of the function to invoke.
+    /// * `args` - The deferred arguments to the function.
+    ///
+    /// # Errors
+    ///
+    /// This function will return an error if the function fails to execute.
+    pub fn invoke_lazy(&self, name: &str, args: &[LazyArg], context: &Context) -> Result<Value, DscError> {
+        let function = self.get_function(name, args.len())?;
+
+        // arg types can only be checked once the function requests the value
+        let accepted_arg_types = function.accepted_arg_types();
+        let checked_args = args.iter().map(|arg| -> LazyArg {
+            let accepted_arg_types = &accepted_arg_types;
+            Box::new(move || {
+                let value = arg()?;
+                validate_arg_type(name, accepted_arg_types, &value)?;
+                Ok(value)
+            })
+        }).collect::<Vec<LazyArg>>();
+
+        function.invoke_lazy(&checked_args, context)
+    }
+
+    /// Invoke a function that has lambda arguments.
+    ///
+    /// # Arguments
+    ///
+    /// * `name` - The name of the function to invoke.
+    /// * `args` - The evaluated arguments to the function.
+    /// * `lambdas` - The lambda arguments to the function.
+    ///
+    /// # Errors
+    ///
+    /// This function will return an error if the function fails to execute.
+    pub fn invoke_with_lambdas(&self, name: &str, args: &[Value], lambdas: &[LambdaArg], context: &Context) -> Result<Value, DscError> {
+        let function = self.get_function(name, args.len() + lambdas.len())?;
+
+        let accepted_arg_types = function.accepted_arg_types();
+        for value in args {
+            validate_arg_type(name, &accepted_arg_types, value)?;
+        }
+
+        function.invoke_with_lambdas(args, lambdas, context)
+    }
+
+    fn get_function(&self, name: &str, arg_count: usize) -> Result<&dyn Function, DscError> {
         let Some(function) = self.functions.get(name) else {
             return Err(DscError::Parser(format!("Unknown function '{name}'")));
         };
@@ -103,7 +548,7 @@ impl FunctionDispatcher {
         // check if arg number are valid
         let min_args = function.min_args();
         let max_args = function.max_args();
-        if args.len() < min_args || args.len() > max_args {
+        if arg_count < min_args || arg_count > max_args {
             if max_args == 0 {
                 return Err(DscError::Parser(format!("Function '{name}' does not accept arguments")));
             }
@@ -116,29 +561,175 @@ impl FunctionDispatcher {
 
             return Err(DscError::Parser(format!("Function '{name}' requires between {min_args} and {max_args} arguments")));
         }
-        // check if arg types are valid
-        let accepted_arg_types = function.accepted_arg_types();
-        let accepted_args_string = accepted_arg_types.iter().map(|x| format!("{x:?}")).collect::<Vec<String>>().join(", ");
-        for value in args {
-            if value.is_array() && !accepted_arg_types.contains(&AcceptedArgKind::Array) {
-                return Err(DscError::Parser(format!("Function '{name}' does not accept array arguments, accepted types are: {accepted_args_string}")));
-            } else if value.is_boolean() && !accepted_arg_types.contains(&AcceptedArgKind::Boolean) {
-                return Err(DscError::Parser(format!("Function '{name}' does not accept boolean arguments, accepted types are: {accepted_args_string}")));
-            } else if value.is_number() && !accepted_arg_types.contains(&AcceptedArgKind::Number) {
-                return Err(DscError::Parser(format!("Function '{name}' does not accept number arguments, accepted types are: {accepted_args_string}")));
-            } else if value.is_object() && !accepted_arg_types.contains(&AcceptedArgKind::Object) {
-                return Err(DscError::Parser(format!("Function '{name}' does not accept object arguments, accepted types are: {accepted_args_string}")));
-            } else if value.is_string() && !accepted_arg_types.contains(&AcceptedArgKind::String) {
-                return Err(DscError::Parser(format!("Function '{name}' does not accept string argument, accepted types are: {accepted_args_string}")));
-            }
-        }
 
-        function.invoke(args, context)
+        Ok(function.as_ref())
+    }
+}
+
+fn validate_arg_type(name: &str, accepted_arg_types: &[AcceptedArgKind], value: &Value) -> Result<(), DscError> {
+    let accepted_args_string = accepted_arg_types.iter().map(|x| format!("{x:?}")).collect::<Vec<String>>().join(", ");
+    if value.is_array() && !accepted_arg_types.contains(&AcceptedArgKind::Array) {
+        return Err(DscError::Parser(format!("Function '{name}' does not accept array arguments, accepted types are: {accepted_args_string}")));
+    } else if value.is_boolean() && !accepted_arg_types.contains(&AcceptedArgKind::Boolean) {
+        return Err(DscError::Parser(format!("Function '{name}' does not accept boolean arguments, accepted types are: {accepted_args_string}")));
+    } else if value.is_number() && !accepted_arg_types.contains(&AcceptedArgKind::Number) {
+        return Err(DscError::Parser(format!("Function '{name}' does not accept number arguments, accepted types are: {accepted_args_string}")));
+    } else if value.is_object() && !accepted_arg_types.contains(&AcceptedArgKind::Object) {
+        return Err(DscError::Parser(format!("Function '{name}' does not accept object arguments, accepted types are: {accepted_args_string}")));
+    } else if value.is_string() && !accepted_arg_types.contains(&AcceptedArgKind::String) {
+        return Err(DscError::Parser(format!("Function '{name}' does not accept string argument, accepted types are: {accepted_args_string}")));
+    }
+
+    Ok(())
+}
+
+/// Compare two values that are either both numbers or both strings.
+///
+/// # Arguments
+///
+/// * `name` - The name of the function performing the comparison.
+/// * `left` - The first value.
+/// * `right` - The second value.
+///
+/// # Errors
+///
+/// This function will return an error if the values are not both numbers or both strings.
+pub fn compare_values(name: &str, left: &Value, right: &Value) -> Result<Ordering, DscError> {
+    if let (Some(left), Some(right)) = (left.as_i64(), right.as_i64()) {
+        return Ok(left.cmp(&right));
+    }
+    if let (Some(left), Some(right)) = (left.as_f64(), right.as_f64()) {
+        return left.partial_cmp(&right).ok_or(DscError::Function(name.to_string(), "Numbers cannot be compared".to_string()));
+    }
+    if let (Some(left), Some(right)) = (left.as_str(), right.as_str()) {
+        return Ok(left.cmp(right));
+    }
+    Err(DscError::Function(name.to_string(), "Arguments must both be numbers or both be strings".to_string()))
+}
+
+/// Apply an arithmetic operation to two numbers, the result is only a decimal if either number is a decimal.
+///
+/// # Arguments
+///
+/// * `name` - The name of the function performing the operation.
+/// * `left` - The first number.
+/// * `right` - The second number.
+/// * `integer_op` - The operation for two integers, which returns `None` on overflow.
+/// * `decimal_op` - The operation for decimals.
+///
+/// # Errors
+///
+/// This function will return an error if the values are not numbers or the result overflows.
+pub fn arithmetic(name: &str, left: &Value, right: &Value, integer_op: fn(i64, i64) -> Option<i64>, decimal_op: fn(f64, f64) -> f64) -> Result<Value, DscError> {
+    if let (Some(left), Some(right)) = (left.as_i64(), right.as_i64()) {
+        let Some(result) = integer_op(left, right) else {
+            return Err(DscError::Function(name.to_string(), format!("Result of {left} and {right} overflows a 64-bit integer")));
+        };
+        return Ok(Value::Number(result.into()));
+    }
+
+    let (Some(left_decimal), Some(right_decimal)) = (left.as_f64(), right.as_f64()) else {
+        return Err(DscError::Parser("Invalid argument(s)".to_string()));
+    };
+    // an unsigned integer beyond the range of i64 would otherwise silently lose precision
+    if !left.is_f64() && !right.is_f64() {
+        return Err(DscError::Function(name.to_string(), "Integer is outside the range of a 64-bit integer".to_string()));
+    }
+    let Some(result) = serde_json::Number::from_f64(decimal_op(left_decimal, right_decimal)) else {
+        return Err(DscError::Function(name.to_string(), "Result is not a finite number".to_string()));
+    };
+    Ok(Value::Number(result))
+}
+
+/// Get the directory that relative paths in expressions are resolved against.
+///
+/// # Arguments
+///
+/// * `name` - The name of the function resolving the path.
+/// * `context` - The context with the `DSC_CONFIG_ROOT` of the configuration.
+///
+/// # Errors
+///
+/// This function will return an error if the current directory is used and can't be retrieved.
+pub fn get_config_root(name: &str, context: &Context) -> Result<PathBuf, DscError> {
+    match &context.config_root {
+        Some(config_root) => Ok(config_root.clone()),
+        None => std::env::current_dir().map_err(|err| DscError::Function(name.to_string(), format!("Failed to get current directory: {err}"))),
     }
 }
 
+/// Resolve a path relative to the configuration root, like include paths.
+///
+/// # Arguments
+///
+/// * `name` - The name of the function resolving the path.
+/// * `path` - The path relative to the configuration root or an absolute path within it.
+/// * `context` - The context with the `DSC_CONFIG_ROOT` of the configuration.
+///
+/// # Errors
+///
+/// This function will return an error if the path contains `..` or is outside of the configuration root.
+pub fn resolve_config_path(name: &str, path: &str, context: &Context) -> Result<PathBuf, DscError> {
+    let path = Path::new(path);
+    // files outside of the configuration root can't be reached, whether the path is relative or absolute
+    if path.components().any(|component| component == Component::ParentDir) {
+        return Err(DscError::Function(name.to_string(), format!("Path must not contain '..': {}", path.display())));
+    }
+    let config_root = get_config_root(name, context)?;
+    let resolved = config_root.join(path);
+    if !resolved.starts_with(&config_root) {
+        return Err(DscError::Function(name.to_string(), format!("Path '{}' is not within the configuration root '{}'", path.display(), config_root.display())));
+    }
+    Ok(resolved)
+}
+
 impl Default for FunctionDispatcher {
     fn default() -> Self {
         Self::new()
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn metadata_matches_arguments() {
+        let dispatcher = FunctionDispatcher::new();
+        for function in dispatcher.list() {
+            assert!(!function.description.is_empty(), "{0} has no description", function.name);
+            assert!(function.parameters.len() >= function.min_args, "{0} has fewer parameters than its minimum arguments", function.name);
+            assert!(function.parameters.len() <= function.max_args, "{0} has more parameters than its maximum arguments", function.name);
+            assert!(!function.return_types.is_empty(), "{0} has no return types", function.name);
+            for parameter in &function.parameters {
+                assert!(!parameter.types.is_empty(), "{0} parameter {1} has no types", function.name, parameter.name);
+            }
+        }
+    }
+
+    #[test]
+    fn list_is_sorted() {
+        let names = FunctionDispatcher::new().list().into_iter().map(|function| function.name).collect::<Vec<String>>();
+        let mut sorted = names.clone();
+        sorted.sort();
+        assert_eq!(names, sorted);
+        assert!(names.contains(&"concat".to_string()));
+    }
+
+    #[test]
+    fn compare_mixed_types() {
+        assert_eq!(compare_values("less", &Value::from(1.5), &Value::from(2)).unwrap(), Ordering::Less);
+        let err = compare_values("less", &Value::from(1.5), &Value::from("a")).unwrap_err();
+        assert!(err.to_string().contains("Arguments must both be numbers or both be strings"));
+    }
+
+    #[test]
+    fn parameter_types() {
+        let functions = FunctionDispatcher::new().list();
+        let join = functions.iter().find(|function| function.name == "join").unwrap();
+        assert_eq!(join.parameters[0].types, vec![AcceptedArgKind::Array]);
+        assert_eq!(join.parameters[1].types, vec![AcceptedArgKind::String]);
+        let map = functions.iter().find(|function| function.name == "map").unwrap();
+        assert_eq!(map.parameters[1].types, vec![AcceptedArgKind::Lambda]);
+    }
+}
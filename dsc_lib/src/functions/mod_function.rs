@@ -3,7 +3,7 @@
 
 use crate::DscError;
 use crate::configure::context::Context;
-use crate::functions::{AcceptedArgKind, Function};
+use crate::functions::{AcceptedArgKind, Function, FunctionCategory, arithmetic};
 use serde_json::Value;
 use tracing::debug;
 
@@ -23,16 +23,28 @@ impl Function for Mod {
         vec![AcceptedArgKind::Number]
     }
 
+    fn description(&self) -> String {
+        "Returns the remainder of dividing two numbers.".to_string()
+    }
+
+    fn category(&self) -> FunctionCategory {
+        FunctionCategory::Numeric
+    }
+
+    fn parameter_names(&self) -> Vec<&str> {
+        vec!["operand1", "operand2"]
+    }
+
+    fn return_types(&self) -> Vec<AcceptedArgKind> {
+        vec![AcceptedArgKind::Number]
+    }
+
     fn invoke(&self, args: &[Value], _context: &Context) -> Result<Value, DscError> {
         debug!("mod function");
-        if let (Some(arg1), Some(arg2)) = (args[0].as_i64(), args[1].as_i64()) {
-            if arg2 == 0 {
-                return Err(DscError::Parser("Cannot divide by zero".to_string()));
-            }
-            Ok(Value::Number((arg1 % arg2).into()))
-        } else {
-            Err(DscError::Parser("Invalid argument(s)".to_string()))
+        if args[1].as_f64().is_some_and(|divisor| divisor == 0.0) {
+            return Err(DscError::Parser("Cannot divide by zero".to_string()));
         }
+        arithmetic("mod", &args[0], &args[1], i64::checked_rem, |left, right| left % right)
     }
 }
 
@@ -76,4 +88,11 @@ mod tests {
         let result = parser.parse_and_execute("[mod(9223372036854775808, 2)]", &Context::new());
         assert!(result.is_err());
     }
+
+    #[test]
+    fn decimals() {
+        let mut parser = Statement::new().unwrap();
+        let result = parser.parse_and_execute("[mod(7.5, 2)]", &Context::new()).unwrap();
+        assert_eq!(result, 1.5);
+    }
 }
@@ -3,7 +3,7 @@
 
 use crate::DscError;
 use crate::configure::context::Context;
-use crate::functions::{AcceptedArgKind, Function};
+use crate::functions::{AcceptedArgKind, Function, FunctionCategory, arithmetic};
 use serde_json::Value;
 use tracing::debug;
 
@@ -23,13 +23,25 @@ impl Function for Mul {
         vec![AcceptedArgKind::Number]
     }
 
+    fn description(&self) -> String {
+        "Returns the product of two numbers.".to_string()
+    }
+
+    fn category(&self) -> FunctionCategory {
+        FunctionCategory::Numeric
+    }
+
+    fn parameter_names(&self) -> Vec<&str> {
+        vec!["operand1", "operand2"]
+    }
+
+    fn return_types(&self) -> Vec<AcceptedArgKind> {
+        vec![AcceptedArgKind::Number]
+    }
+
     fn invoke(&self, args: &[Value], _context: &Context) -> Result<Value, DscError> {
         debug!("mul function");
-        if let (Some(arg1), Some(arg2)) = (args[0].as_i64(), args[1].as_i64()) {
-            Ok(Value::Number((arg1 * arg2).into()))
-        } else {
-            Err(DscError::Parser("Invalid argument(s)".to_string()))
-        }
+        arithmetic("mul", &args[0], &args[1], i64::checked_mul, |left, right| left * right)
     }
 }
 
@@ -73,4 +85,18 @@ mod tests {
         let result = parser.parse_and_execute("[mul(9223372036854775808, 2)]", &Context::new());
         assert!(result.is_err());
     }
+
+    #[test]
+    fn large_numbers() {
+        let mut parser = Statement::new().unwrap();
+        let result = parser.parse_and_execute("[mul(3000000000, 4)]", &Context::new()).unwrap();
+        assert_eq!(result, 12_000_000_000_i64);
+    }
+
+    #[test]
+    fn decimals() {
+        let mut parser = Statement::new().unwrap();
+        let result = parser.parse_and_execute("[mul(1.5, 3)]", &Context::new()).unwrap();
+        assert_eq!(result, 4.5);
+    }
 }
@@ -0,0 +1,69 @@
+// Copyright (c) Microsoft Corporation.
+// Licensed under the MIT License.
+
+use crate::DscError;
+use crate::configure::context::Context;
+use crate::functions::{AcceptedArgKind, Function, FunctionCategory};
+use serde_json::Value;
+use tracing::debug;
+use uuid::Uuid;
+
+#[derive(Debug, Default)]
+pub struct NewGuid {}
+
+impl Function for NewGuid {
+    fn min_args(&self) -> usize {
+        0
+    }
+
+    fn max_args(&self) -> usize {
+        0
+    }
+
+    fn accepted_arg_types(&self) -> Vec<AcceptedArgKind> {
+        vec![]
+    }
+
+    fn description(&self) -> String {
+        "Creates a new random GUID.".to_string()
+    }
+
+    fn category(&self) -> FunctionCategory {
+        FunctionCategory::String
+    }
+
+    fn parameter_names(&self) -> Vec<&str> {
+        vec![]
+    }
+
+    fn return_types(&self) -> Vec<AcceptedArgKind> {
+        vec![AcceptedArgKind::String]
+    }
+
+    fn invoke(&self, _args: &[Value], _context: &Context) -> Result<Value, DscError> {
+        debug!("newGuid function");
+        Ok(Value::String(Uuid::new_v4().to_string()))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::configure::context::Context;
+    use crate::parser::Statement;
+
+    #[test]
+    fn unique() {
+        let mut parser = Statement::new().unwrap();
+        let first = parser.parse_and_execute("[newGuid()]", &Context::new()).unwrap();
+        let second = parser.parse_and_execute("[newGuid()]", &Context::new()).unwrap();
+        assert_ne!(first, second);
+        assert!(uuid::Uuid::parse_str(first.as_str().unwrap()).is_ok());
+    }
+
+    #[test]
+    fn with_args() {
+        let mut parser = Statement::new().unwrap();
+        let result = parser.parse_and_execute("[newGuid('a')]", &Context::new());
+        assert!(result.is_err());
+    }
+}
@@ -0,0 +1,102 @@
+// Copyright (c) Microsoft Corporation.
+// Licensed under the MIT License.
+
+use crate::DscError;
+use crate::configure::context::Context;
+use crate::functions::{AcceptedArgKind, Function, FunctionCategory};
+use serde_json::{Map, Value};
+use tracing::debug;
+
+// same shape as the output of the `Microsoft/OSInfo` resource
+const OS_INFO_ID: &str = "https://developer.microsoft.com/json-schemas/dsc/os_info/20230303/Microsoft.Dsc.OS_Info.schema.json";
+
+#[derive(Debug, Default)]
+pub struct Os {}
+
+impl Function for Os {
+    fn min_args(&self) -> usize {
+        0
+    }
+
+    fn max_args(&self) -> usize {
+        0
+    }
+
+    fn accepted_arg_types(&self) -> Vec<AcceptedArgKind> {
+        vec![]
+    }
+
+    fn description(&self) -> String {
+        "Returns information about the operating system of the host.".to_string()
+    }
+
+    fn category(&self) -> FunctionCategory {
+        FunctionCategory::System
+    }
+
+    fn parameter_names(&self) -> Vec<&str> {
+        vec![]
+    }
+
+    fn return_types(&self) -> Vec<AcceptedArgKind> {
+        vec![AcceptedArgKind::Object]
+    }
+
+    fn invoke(&self, _args: &[Value], _context: &Context) -> Result<Value, DscError> {
+        debug!("os function");
+        let os_info = os_info::get();
+        let family = match os_info.os_type() {
+            os_info::Type::Macos => "macOS",
+            os_info::Type::Windows => "Windows",
+            _ => "Linux",
+        };
+        let bitness = match os_info.bitness() {
+            os_info::Bitness::X32 => "32",
+            os_info::Bitness::X64 => "64",
+            _ => "unknown",
+        };
+
+        let mut result = Map::new();
+        result.insert("$id".to_string(), Value::String(OS_INFO_ID.to_string()));
+        result.insert("family".to_string(), Value::String(family.to_string()));
+        result.insert("version".to_string(), Value::String(os_info.version().to_string()));
+        if let Some(edition) = os_info.edition() {
+            result.insert("edition".to_string(), Value::String(edition.to_string()));
+        }
+        if let Some(codename) = os_info.codename() {
+            result.insert("codename".to_string(), Value::String(codename.to_string()));
+        }
+        result.insert("bitness".to_string(), Value::String(bitness.to_string()));
+        if let Some(architecture) = os_info.architecture() {
+            result.insert("architecture".to_string(), Value::String(architecture.to_string()));
+        }
+        Ok(Value::Object(result))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::configure::context::Context;
+    use crate::parser::Statement;
+
+    #[test]
+    fn family() {
+        let mut parser = Statement::new().unwrap();
+        let result = parser.parse_and_execute("[os().family]", &Context::new()).unwrap();
+        let expected = if cfg!(target_os = "windows") {
+            "Windows"
+        } else if cfg!(target_os = "macos") {
+            "macOS"
+        } else {
+            "Linux"
+        };
+        assert_eq!(result, expected);
+    }
+
+    #[test]
+    fn version() {
+        let mut parser = Statement::new().unwrap();
+        let result = parser.parse_and_execute("[os().version]", &Context::new()).unwrap();
+        assert!(result.is_string());
+    }
+}
@@ -0,0 +1,123 @@
+// Copyright (c) Microsoft Corporation.
+// Licensed under the MIT License.
+
+use crate::DscError;
+use crate::configure::context::Context;
+use crate::functions::{AcceptedArgKind, Function, FunctionCategory};
+use serde_json::Value;
+use tracing::debug;
+
+#[derive(Debug, Default)]
+pub struct PadLeft {}
+
+impl Function for PadLeft {
+    fn min_args(&self) -> usize {
+        2
+    }
+
+    fn max_args(&self) -> usize {
+        3
+    }
+
+    fn accepted_arg_types(&self) -> Vec<AcceptedArgKind> {
+        vec![AcceptedArgKind::String, AcceptedArgKind::Number]
+    }
+
+    fn description(&self) -> String {
+        "Pads a string on the left to a total length.".to_string()
+    }
+
+    fn category(&self) -> FunctionCategory {
+        FunctionCategory::String
+    }
+
+    fn parameter_names(&self) -> Vec<&str> {
+        vec!["valueToPad", "totalLength", "paddingCharacter"]
+    }
+
+    fn parameter_types(&self) -> Vec<Vec<AcceptedArgKind>> {
+        vec![
+            vec![AcceptedArgKind::String, AcceptedArgKind::Number],
+            vec![AcceptedArgKind::Number],
+            vec![AcceptedArgKind::String],
+        ]
+    }
+
+    fn return_types(&self) -> Vec<AcceptedArgKind> {
+        vec![AcceptedArgKind::String]
+    }
+
+    fn invoke(&self, args: &[Value], _context: &Context) -> Result<Value, DscError> {
+        debug!("padLeft function");
+        let value = match &args[0] {
+            Value::String(value) => value.clone(),
+            Value::Number(value) => value.to_string(),
+            _ => {
+                return Err(DscError::Parser("Value to pad must be a string or number".to_string()));
+            }
+        };
+        let Some(total_length) = args[1].as_i64() else {
+            return Err(DscError::Parser("Total length must be an integer".to_string()));
+        };
+        let Ok(total_length) = usize::try_from(total_length) else {
+            return Err(DscError::Parser("Total length cannot be negative".to_string()));
+        };
+        let padding = if args.len() == 3 {
+            let Some(padding) = args[2].as_str() else {
+                return Err(DscError::Parser("Padding character must be a string".to_string()));
+            };
+            let mut chars = padding.chars();
+            match (chars.next(), chars.next()) {
+                (Some(padding), None) => padding,
+                _ => {
+                    return Err(DscError::Parser("Padding character must be a single character".to_string()));
+                }
+            }
+        } else {
+            ' '
+        };
+
+        let length = value.chars().count();
+        if length >= total_length {
+            return Ok(Value::String(value));
+        }
+
+        let mut result = padding.to_string().repeat(total_length - length);
+        result.push_str(&value);
+        Ok(Value::String(result))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::configure::context::Context;
+    use crate::parser::Statement;
+
+    #[test]
+    fn string() {
+        let mut parser = Statement::new().unwrap();
+        let result = parser.parse_and_execute("[padLeft('abc', 5)]", &Context::new()).unwrap();
+        assert_eq!(result, "  abc");
+    }
+
+    #[test]
+    fn number_with_padding_character() {
+        let mut parser = Statement::new().unwrap();
+        let result = parser.parse_and_execute("[padLeft(42, 5, '0')]", &Context::new()).unwrap();
+        assert_eq!(result, "00042");
+    }
+
+    #[test]
+    fn already_long_enough() {
+        let mut parser = Statement::new().unwrap();
+        let result = parser.parse_and_execute("[padLeft('abcdef', 3, '0')]", &Context::new()).unwrap();
+        assert_eq!(result, "abcdef");
+    }
+
+    #[test]
+    fn multiple_padding_characters() {
+        let mut parser = Statement::new().unwrap();
+        let result = parser.parse_and_execute("[padLeft('abc', 5, '00')]", &Context::new());
+        assert!(result.is_err());
+    }
+}
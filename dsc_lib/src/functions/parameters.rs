@@ -5,7 +5,7 @@ use crate::configure::config_doc::DataType;
 use crate::configure::parameters::SecureKind;
 use crate::DscError;
 use crate::configure::context::Context;
-use crate::functions::{AcceptedArgKind, Function};
+use crate::functions::{AcceptedArgKind, Function, FunctionCategory};
 use serde_json::Value;
 use tracing::{debug, trace};
 
@@ -25,6 +25,22 @@ impl Function for Parameters {
         vec![AcceptedArgKind::String]
     }
 
+    fn description(&self) -> String {
+        "Returns the value of a parameter.".to_string()
+    }
+
+    fn category(&self) -> FunctionCategory {
+        FunctionCategory::Deployment
+    }
+
+    fn parameter_names(&self) -> Vec<&str> {
+        vec!["parameterName"]
+    }
+
+    fn return_types(&self) -> Vec<AcceptedArgKind> {
+        vec![AcceptedArgKind::Array, AcceptedArgKind::Boolean, AcceptedArgKind::Number, AcceptedArgKind::Object, AcceptedArgKind::String]
+    }
+
     fn invoke(&self, args: &[Value], context: &Context) -> Result<Value, DscError> {
         debug!("Invoke parameters function");
         if let Some(key) = args[0].as_str() {
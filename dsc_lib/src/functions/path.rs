@@ -0,0 +1,93 @@
+// Copyright (c) Microsoft Corporation.
+// Licensed under the MIT License.
+
+use crate::DscError;
+use crate::configure::context::Context;
+use crate::functions::{AcceptedArgKind, Function, FunctionCategory};
+use serde_json::Value;
+use std::path::PathBuf;
+use tracing::debug;
+
+#[derive(Debug, Default)]
+pub struct PathFn {}
+
+impl Function for PathFn {
+    fn min_args(&self) -> usize {
+        1
+    }
+
+    fn max_args(&self) -> usize {
+        usize::MAX
+    }
+
+    fn accepted_arg_types(&self) -> Vec<AcceptedArgKind> {
+        vec![AcceptedArgKind::String]
+    }
+
+    fn description(&self) -> String {
+        "Combines path segments using the separator of the host.".to_string()
+    }
+
+    fn category(&self) -> FunctionCategory {
+        FunctionCategory::File
+    }
+
+    fn parameter_names(&self) -> Vec<&str> {
+        vec!["path1"]
+    }
+
+    fn return_types(&self) -> Vec<AcceptedArgKind> {
+        vec![AcceptedArgKind::String]
+    }
+
+    fn invoke(&self, args: &[Value], _context: &Context) -> Result<Value, DscError> {
+        debug!("path function");
+        let mut path = PathBuf::new();
+        for arg in args {
+            let Some(segment) = arg.as_str() else {
+                return Err(DscError::Parser("Arguments must all be strings".to_string()));
+            };
+            // an absolute segment replaces the path so far, like `Join-Path` with an absolute child
+            path.push(segment);
+        }
+
+        Ok(Value::String(path.to_string_lossy().into_owned()))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::configure::context::Context;
+    use crate::parser::Statement;
+    use std::path::PathBuf;
+
+    #[test]
+    fn segments() {
+        let mut parser = Statement::new().unwrap();
+        let result = parser.parse_and_execute("[path('a', 'b', 'c.txt')]", &Context::new()).unwrap();
+        assert_eq!(result, PathBuf::from("a").join("b").join("c.txt").to_string_lossy().as_ref());
+    }
+
+    #[test]
+    fn join_path_alias() {
+        let mut parser = Statement::new().unwrap();
+        let result = parser.parse_and_execute("[joinPath('a', 'b')]", &Context::new()).unwrap();
+        assert_eq!(result, PathBuf::from("a").join("b").to_string_lossy().as_ref());
+    }
+
+    #[test]
+    fn with_config_root() {
+        let mut parser = Statement::new().unwrap();
+        let mut context = Context::new();
+        context.config_root = Some(PathBuf::from("root"));
+        let result = parser.parse_and_execute("[path(configRoot(), 'file.txt')]", &context).unwrap();
+        assert_eq!(result, PathBuf::from("root").join("file.txt").to_string_lossy().as_ref());
+    }
+
+    #[test]
+    fn invalid_type() {
+        let mut parser = Statement::new().unwrap();
+        let result = parser.parse_and_execute("[path('a', 1)]", &Context::new());
+        assert!(result.is_err());
+    }
+}
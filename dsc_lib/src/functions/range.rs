@@ -0,0 +1,93 @@
+// Copyright (c) Microsoft Corporation.
+// Licensed under the MIT License.
+
+use crate::DscError;
+use crate::configure::context::Context;
+use crate::functions::{AcceptedArgKind, Function, FunctionCategory};
+use serde_json::Value;
+use tracing::debug;
+
+// same limit as ARM to avoid accidentally creating huge arrays
+const MAX_COUNT: i64 = 10000;
+
+#[derive(Debug, Default)]
+pub struct Range {}
+
+impl Function for Range {
+    fn min_args(&self) -> usize {
+        2
+    }
+
+    fn max_args(&self) -> usize {
+        2
+    }
+
+    fn accepted_arg_types(&self) -> Vec<AcceptedArgKind> {
+        vec![AcceptedArgKind::Number]
+    }
+
+    fn description(&self) -> String {
+        "Creates an array of consecutive integers.".to_string()
+    }
+
+    fn category(&self) -> FunctionCategory {
+        FunctionCategory::Array
+    }
+
+    fn parameter_names(&self) -> Vec<&str> {
+        vec!["startIndex", "count"]
+    }
+
+    fn return_types(&self) -> Vec<AcceptedArgKind> {
+        vec![AcceptedArgKind::Array]
+    }
+
+    fn invoke(&self, args: &[Value], _context: &Context) -> Result<Value, DscError> {
+        debug!("range function");
+        let (Some(start), Some(count)) = (args[0].as_i64(), args[1].as_i64()) else {
+            return Err(DscError::Parser("Invalid argument(s)".to_string()));
+        };
+        if !(0..=MAX_COUNT).contains(&count) {
+            return Err(DscError::Parser(format!("Count must be between 0 and {MAX_COUNT}")));
+        }
+        let Some(end) = start.checked_add(count) else {
+            return Err(DscError::Parser("Start index and count result in an overflow".to_string()));
+        };
+
+        Ok(Value::Array((start..end).map(|i| Value::Number(i.into())).collect()))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::configure::context::Context;
+    use crate::parser::Statement;
+
+    #[test]
+    fn numbers() {
+        let mut parser = Statement::new().unwrap();
+        let result = parser.parse_and_execute("[range(1, 3)]", &Context::new()).unwrap();
+        assert_eq!(result.to_string(), "[1,2,3]");
+    }
+
+    #[test]
+    fn negative_start() {
+        let mut parser = Statement::new().unwrap();
+        let result = parser.parse_and_execute("[range(-1, 2)]", &Context::new()).unwrap();
+        assert_eq!(result.to_string(), "[-1,0]");
+    }
+
+    #[test]
+    fn zero_count() {
+        let mut parser = Statement::new().unwrap();
+        let result = parser.parse_and_execute("[range(5, 0)]", &Context::new()).unwrap();
+        assert_eq!(result.to_string(), "[]");
+    }
+
+    #[test]
+    fn count_too_large() {
+        let mut parser = Statement::new().unwrap();
+        let result = parser.parse_and_execute("[range(0, 10001)]", &Context::new());
+        assert!(result.is_err());
+    }
+}
@@ -0,0 +1,105 @@
+// Copyright (c) Microsoft Corporation.
+// Licensed under the MIT License.
+
+use crate::DscError;
+use crate::configure::context::Context;
+use crate::functions::{AcceptedArgKind, Function, FunctionCategory, LambdaArg};
+use serde_json::Value;
+use tracing::debug;
+
+#[derive(Debug, Default)]
+pub struct Reduce {}
+
+impl Function for Reduce {
+    fn min_args(&self) -> usize {
+        3
+    }
+
+    fn max_args(&self) -> usize {
+        3
+    }
+
+    fn accepted_arg_types(&self) -> Vec<AcceptedArgKind> {
+        vec![AcceptedArgKind::Array, AcceptedArgKind::Boolean, AcceptedArgKind::Number, AcceptedArgKind::Object, AcceptedArgKind::String]
+    }
+
+    fn description(&self) -> String {
+        "Combines the elements of an array using the lambda.".to_string()
+    }
+
+    fn category(&self) -> FunctionCategory {
+        FunctionCategory::Lambda
+    }
+
+    fn parameter_names(&self) -> Vec<&str> {
+        vec!["inputArray", "initialValue", "lambda"]
+    }
+
+    fn parameter_types(&self) -> Vec<Vec<AcceptedArgKind>> {
+        vec![
+            vec![AcceptedArgKind::Array],
+            vec![AcceptedArgKind::Array, AcceptedArgKind::Boolean, AcceptedArgKind::Number, AcceptedArgKind::Object, AcceptedArgKind::String],
+            vec![AcceptedArgKind::Lambda],
+        ]
+    }
+
+    fn return_types(&self) -> Vec<AcceptedArgKind> {
+        vec![AcceptedArgKind::Array, AcceptedArgKind::Boolean, AcceptedArgKind::Number, AcceptedArgKind::Object, AcceptedArgKind::String]
+    }
+
+    fn invoke(&self, _args: &[Value], _context: &Context) -> Result<Value, DscError> {
+        Err(DscError::Parser("Function 'reduce' requires a lambda as the third argument".to_string()))
+    }
+
+    fn invoke_with_lambdas(&self, args: &[Value], lambdas: &[LambdaArg], _context: &Context) -> Result<Value, DscError> {
+        debug!("reduce function");
+        let ([array, initial_value], [lambda]) = (args, lambdas) else {
+            return Err(DscError::Parser("Function 'reduce' requires an array, an initial value and a lambda".to_string()));
+        };
+        let Some(array) = array.as_array() else {
+            return Err(DscError::Parser("First argument must be an array".to_string()));
+        };
+
+        let mut accumulator = initial_value.clone();
+        for (index, element) in array.iter().enumerate() {
+            // the lambda can optionally accept the index as third parameter
+            accumulator = lambda(&[accumulator, element.clone(), Value::Number(index.into())])?;
+        }
+
+        Ok(accumulator)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::configure::context::Context;
+    use crate::parser::Statement;
+
+    #[test]
+    fn sum() {
+        let mut parser = Statement::new().unwrap();
+        let result = parser.parse_and_execute("[reduce(range(1, 4), 0, lambda('cur', 'next', add(lambdaVariables('cur'), lambdaVariables('next'))))]", &Context::new()).unwrap();
+        assert_eq!(result, 10);
+    }
+
+    #[test]
+    fn strings() {
+        let mut parser = Statement::new().unwrap();
+        let result = parser.parse_and_execute("[reduce(createArray('a', 'b'), 'x', lambda('cur', 'next', concat(lambdaVariables('cur'), lambdaVariables('next'))))]", &Context::new()).unwrap();
+        assert_eq!(result, "xab");
+    }
+
+    #[test]
+    fn empty_array() {
+        let mut parser = Statement::new().unwrap();
+        let result = parser.parse_and_execute("[reduce(createArray(), 5, lambda('cur', 'next', lambdaVariables('next')))]", &Context::new()).unwrap();
+        assert_eq!(result, 5);
+    }
+
+    #[test]
+    fn lambda_not_last() {
+        let mut parser = Statement::new().unwrap();
+        let result = parser.parse_and_execute("[reduce(createArray(1), lambda('cur', 'next', lambdaVariables('next')), 0)]", &Context::new());
+        assert!(result.is_err());
+    }
+}
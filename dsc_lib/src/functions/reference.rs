@@ -3,7 +3,7 @@
 
 use crate::DscError;
 use crate::configure::context::Context;
-use crate::functions::{AcceptedArgKind, Function};
+use crate::functions::{AcceptedArgKind, Function, FunctionCategory};
 use serde_json::Value;
 use tracing::debug;
 
@@ -23,6 +23,22 @@ impl Function for Reference {
         vec![AcceptedArgKind::String]
     }
 
+    fn description(&self) -> String {
+        "Returns the output of a resource that was already processed.".to_string()
+    }
+
+    fn category(&self) -> FunctionCategory {
+        FunctionCategory::Resource
+    }
+
+    fn parameter_names(&self) -> Vec<&str> {
+        vec!["resourceId"]
+    }
+
+    fn return_types(&self) -> Vec<AcceptedArgKind> {
+        vec![AcceptedArgKind::Array, AcceptedArgKind::Boolean, AcceptedArgKind::Number, AcceptedArgKind::Object, AcceptedArgKind::String]
+    }
+
     fn invoke(&self, args: &[Value], context: &Context) -> Result<Value, DscError> {
         debug!("reference function");
         if let Some(key) = args[0].as_str() {
@@ -0,0 +1,80 @@
+// Copyright (c) Microsoft Corporation.
+// Licensed under the MIT License.
+
+use crate::DscError;
+use crate::configure::context::Context;
+use crate::functions::{AcceptedArgKind, Function, FunctionCategory};
+use serde_json::Value;
+use tracing::debug;
+
+#[derive(Debug, Default)]
+pub struct Replace {}
+
+impl Function for Replace {
+    fn min_args(&self) -> usize {
+        3
+    }
+
+    fn max_args(&self) -> usize {
+        3
+    }
+
+    fn accepted_arg_types(&self) -> Vec<AcceptedArgKind> {
+        vec![AcceptedArgKind::String]
+    }
+
+    fn description(&self) -> String {
+        "Replaces all occurrences of a string with another string.".to_string()
+    }
+
+    fn category(&self) -> FunctionCategory {
+        FunctionCategory::String
+    }
+
+    fn parameter_names(&self) -> Vec<&str> {
+        vec!["originalString", "oldString", "newString"]
+    }
+
+    fn return_types(&self) -> Vec<AcceptedArgKind> {
+        vec![AcceptedArgKind::String]
+    }
+
+    fn invoke(&self, args: &[Value], _context: &Context) -> Result<Value, DscError> {
+        debug!("replace function");
+        if let (Some(original), Some(old), Some(new)) = (args[0].as_str(), args[1].as_str(), args[2].as_str()) {
+            if old.is_empty() {
+                return Err(DscError::Parser("String to replace cannot be empty".to_string()));
+            }
+            Ok(Value::String(original.replace(old, new)))
+        } else {
+            Err(DscError::Parser("Invalid argument(s)".to_string()))
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::configure::context::Context;
+    use crate::parser::Statement;
+
+    #[test]
+    fn strings() {
+        let mut parser = Statement::new().unwrap();
+        let result = parser.parse_and_execute("[replace('C:\\temp\\file.txt', '\\', '/')]", &Context::new()).unwrap();
+        assert_eq!(result, "C:/temp/file.txt");
+    }
+
+    #[test]
+    fn not_found() {
+        let mut parser = Statement::new().unwrap();
+        let result = parser.parse_and_execute("[replace('hello', 'x', 'y')]", &Context::new()).unwrap();
+        assert_eq!(result, "hello");
+    }
+
+    #[test]
+    fn empty_old_value() {
+        let mut parser = Statement::new().unwrap();
+        let result = parser.parse_and_execute("[replace('hello', '', 'y')]", &Context::new());
+        assert!(result.is_err());
+    }
+}
@@ -3,7 +3,7 @@
 
 use crate::DscError;
 use crate::configure::context::Context;
-use crate::functions::{AcceptedArgKind, Function};
+use crate::functions::{AcceptedArgKind, Function, FunctionCategory};
 use serde_json::Value;
 
 #[derive(Debug, Default)]
@@ -22,6 +22,22 @@ impl Function for ResourceId {
         vec![AcceptedArgKind::String]
     }
 
+    fn description(&self) -> String {
+        "Returns the identifier of a resource used by `dependsOn` and `reference()`.".to_string()
+    }
+
+    fn category(&self) -> FunctionCategory {
+        FunctionCategory::Resource
+    }
+
+    fn parameter_names(&self) -> Vec<&str> {
+        vec!["resourceType", "resourceName"]
+    }
+
+    fn return_types(&self) -> Vec<AcceptedArgKind> {
+        vec![AcceptedArgKind::String]
+    }
+
     fn invoke(&self, args: &[Value], _context: &Context) -> Result<Value, DscError> {
         let mut result = String::new();
         // first argument is the type and must contain only 1 slash
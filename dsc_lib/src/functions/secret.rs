@@ -0,0 +1,117 @@
+// Copyright (c) Microsoft Corporation.
+// Licensed under the MIT License.
+
+use crate::DscError;
+use crate::configure::context::Context;
+use crate::configure::redaction::add_secure_value;
+use crate::functions::{AcceptedArgKind, Function, FunctionCategory};
+use crate::secrets::SecretStore;
+use serde_json::Value;
+use std::cell::RefCell;
+use std::rc::Rc;
+use tracing::debug;
+
+pub struct Secret {
+    store: Rc<RefCell<SecretStore>>,
+}
+
+impl Secret {
+    /// Create a new `Secret` function that gets secrets from a store.
+    ///
+    /// # Arguments
+    ///
+    /// * `store` - The store with the secret providers.
+    #[must_use]
+    pub fn new(store: Rc<RefCell<SecretStore>>) -> Self {
+        Self {
+            store,
+        }
+    }
+}
+
+impl Function for Secret {
+    fn min_args(&self) -> usize {
+        2
+    }
+
+    fn max_args(&self) -> usize {
+        2
+    }
+
+    fn accepted_arg_types(&self) -> Vec<AcceptedArgKind> {
+        vec![AcceptedArgKind::String]
+    }
+
+    fn description(&self) -> String {
+        "Returns a secret from a vault, which is treated as a secure string.".to_string()
+    }
+
+    fn category(&self) -> FunctionCategory {
+        FunctionCategory::Deployment
+    }
+
+    fn parameter_names(&self) -> Vec<&str> {
+        vec!["vault", "name"]
+    }
+
+    fn return_types(&self) -> Vec<AcceptedArgKind> {
+        vec![AcceptedArgKind::String]
+    }
+
+    fn invoke(&self, args: &[Value], _context: &Context) -> Result<Value, DscError> {
+        debug!("secret function");
+        let (Some(vault), Some(name)) = (args[0].as_str(), args[1].as_str()) else {
+            return Err(DscError::Parser("Invalid argument types".to_string()));
+        };
+        let secret = Value::String(self.store.borrow().get_secret(vault, name)?);
+        add_secure_value(&secret);
+        Ok(secret)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::DscError;
+    use crate::configure::context::Context;
+    use crate::parser::Statement;
+    use crate::secrets::SecretProvider;
+
+    struct TestProvider {}
+
+    impl SecretProvider for TestProvider {
+        fn get_secret(&self, name: &str) -> Result<Option<String>, DscError> {
+            Ok((name == "password").then(|| "hunter2".to_string()))
+        }
+    }
+
+    #[test]
+    fn env_vault() {
+        std::env::set_var("DSC_SECRET_FUNCTION_TEST", "hunter2");
+        let mut parser = Statement::new().unwrap();
+        let result = parser.parse_and_execute("[secret('env', 'DSC_SECRET_FUNCTION_TEST')]", &Context::new()).unwrap();
+        assert_eq!(result, "hunter2");
+    }
+
+    #[test]
+    fn missing_secret() {
+        let mut parser = Statement::new().unwrap();
+        let result = parser.parse_and_execute("[secret('env', 'DSC_SECRET_FUNCTION_MISSING')]", &Context::new());
+        assert!(result.is_err());
+    }
+
+    #[test]
+    fn unknown_vault() {
+        let mut parser = Statement::new().unwrap();
+        let result = parser.parse_and_execute("[secret('unknown', 'name')]", &Context::new());
+        assert!(result.is_err());
+    }
+
+    #[test]
+    fn added_provider() {
+        let mut parser = Statement::new().unwrap();
+        parser.add_secret_provider("test", Box::new(TestProvider{})).unwrap();
+        let result = parser.parse_and_execute("[secret('test', 'password')]", &Context::new()).unwrap();
+        assert_eq!(result, "hunter2");
+        assert!(parser.add_secret_provider("env", Box::new(TestProvider{})).is_err());
+    }
+}
@@ -0,0 +1,96 @@
+// Copyright (c) Microsoft Corporation.
+// Licensed under the MIT License.
+
+use crate::DscError;
+use crate::configure::context::Context;
+use crate::functions::{AcceptedArgKind, Function, FunctionCategory};
+use serde_json::Value;
+use tracing::debug;
+
+#[derive(Debug, Default)]
+pub struct Skip {}
+
+impl Function for Skip {
+    fn min_args(&self) -> usize {
+        2
+    }
+
+    fn max_args(&self) -> usize {
+        2
+    }
+
+    fn accepted_arg_types(&self) -> Vec<AcceptedArgKind> {
+        vec![AcceptedArgKind::Array, AcceptedArgKind::Number, AcceptedArgKind::String]
+    }
+
+    fn description(&self) -> String {
+        "Skips a number of elements of an array or characters of a string.".to_string()
+    }
+
+    fn category(&self) -> FunctionCategory {
+        FunctionCategory::Array
+    }
+
+    fn parameter_names(&self) -> Vec<&str> {
+        vec!["originalValue", "numberToSkip"]
+    }
+
+    fn parameter_types(&self) -> Vec<Vec<AcceptedArgKind>> {
+        vec![
+            vec![AcceptedArgKind::Array, AcceptedArgKind::String],
+            vec![AcceptedArgKind::Number],
+        ]
+    }
+
+    fn return_types(&self) -> Vec<AcceptedArgKind> {
+        vec![AcceptedArgKind::Array, AcceptedArgKind::String]
+    }
+
+    fn invoke(&self, args: &[Value], _context: &Context) -> Result<Value, DscError> {
+        debug!("skip function");
+        let Some(count) = args[1].as_i64() else {
+            return Err(DscError::Parser("The number to skip must be an integer".to_string()));
+        };
+        // negative counts are treated as zero
+        let count = usize::try_from(count.max(0))?;
+        match &args[0] {
+            Value::Array(array) => Ok(Value::Array(array.iter().skip(count).cloned().collect())),
+            Value::String(string) => Ok(Value::String(string.chars().skip(count).collect())),
+            _ => Err(DscError::Parser("First argument must be an array or a string".to_string())),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::configure::context::Context;
+    use crate::parser::Statement;
+
+    #[test]
+    fn array() {
+        let mut parser = Statement::new().unwrap();
+        let result = parser.parse_and_execute("[skip(createArray('a', 'b', 'c'), 2)]", &Context::new()).unwrap();
+        assert_eq!(result.to_string(), r#"["c"]"#);
+    }
+
+    #[test]
+    fn string() {
+        let mut parser = Statement::new().unwrap();
+        let result = parser.parse_and_execute("[skip('hello', 2)]", &Context::new()).unwrap();
+        assert_eq!(result, "llo");
+    }
+
+    #[test]
+    fn invalid_count() {
+        let mut parser = Statement::new().unwrap();
+        let result = parser.parse_and_execute("[skip('hello', 'a')]", &Context::new());
+        assert!(result.is_err());
+    }
+
+    #[test]
+    fn negative_count() {
+        let mut parser = Statement::new().unwrap();
+        let result = parser.parse_and_execute("[skip('hello', -1)]", &Context::new()).unwrap();
+        assert_eq!(result, "hello");
+    }
+}
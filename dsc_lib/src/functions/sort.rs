@@ -0,0 +1,123 @@
+// Copyright (c) Microsoft Corporation.
+// Licensed under the MIT License.
+
+use crate::DscError;
+use crate::configure::context::Context;
+use crate::functions::{AcceptedArgKind, Function, FunctionCategory, LambdaArg};
+use serde_json::Value;
+use tracing::debug;
+
+#[derive(Debug, Default)]
+pub struct Sort {}
+
+impl Function for Sort {
+    fn min_args(&self) -> usize {
+        2
+    }
+
+    fn max_args(&self) -> usize {
+        2
+    }
+
+    fn accepted_arg_types(&self) -> Vec<AcceptedArgKind> {
+        vec![AcceptedArgKind::Array]
+    }
+
+    fn description(&self) -> String {
+        "Sorts an array using the lambda to compare elements.".to_string()
+    }
+
+    fn category(&self) -> FunctionCategory {
+        FunctionCategory::Lambda
+    }
+
+    fn parameter_names(&self) -> Vec<&str> {
+        vec!["inputArray", "lambda"]
+    }
+
+    fn parameter_types(&self) -> Vec<Vec<AcceptedArgKind>> {
+        vec![
+            vec![AcceptedArgKind::Array],
+            vec![AcceptedArgKind::Lambda],
+        ]
+    }
+
+    fn return_types(&self) -> Vec<AcceptedArgKind> {
+        vec![AcceptedArgKind::Array]
+    }
+
+    fn invoke(&self, _args: &[Value], _context: &Context) -> Result<Value, DscError> {
+        Err(DscError::Parser("Function 'sort' requires a lambda as the second argument".to_string()))
+    }
+
+    fn invoke_with_lambdas(&self, args: &[Value], lambdas: &[LambdaArg], _context: &Context) -> Result<Value, DscError> {
+        debug!("sort function");
+        let (Some(array), [lambda]) = (args.first().and_then(Value::as_array), lambdas) else {
+            return Err(DscError::Parser("Function 'sort' requires an array and a lambda".to_string()));
+        };
+
+        // the lambda returns true if the first value should be placed before the second value
+        let is_before = |first: &Value, second: &Value| -> Result<bool, DscError> {
+            lambda(&[first.clone(), second.clone()])?.as_bool().ok_or(DscError::Parser("Lambda for 'sort' must return a boolean".to_string()))
+        };
+        Ok(Value::Array(merge_sort(array, &is_before)?))
+    }
+}
+
+// `slice::sort_by()` can't propagate errors from the lambda, so use a stable merge sort instead
+fn merge_sort(array: &[Value], is_before: &dyn Fn(&Value, &Value) -> Result<bool, DscError>) -> Result<Vec<Value>, DscError> {
+    if array.len() <= 1 {
+        return Ok(array.to_vec());
+    }
+
+    let (left, right) = array.split_at(array.len() / 2);
+    let left = merge_sort(left, is_before)?;
+    let right = merge_sort(right, is_before)?;
+    let mut result = Vec::with_capacity(array.len());
+    let mut left = left.into_iter().peekable();
+    let mut right = right.into_iter().peekable();
+    while let (Some(left_value), Some(right_value)) = (left.peek(), right.peek()) {
+        if is_before(right_value, left_value)? {
+            result.extend(right.next());
+        } else {
+            result.extend(left.next());
+        }
+    }
+    result.extend(left);
+    result.extend(right);
+    Ok(result)
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::configure::context::Context;
+    use crate::parser::Statement;
+
+    #[test]
+    fn numbers() {
+        let mut parser = Statement::new().unwrap();
+        let result = parser.parse_and_execute("[sort(createArray(3, 1, 2, 5, 4), lambda('a', 'b', less(lambdaVariables('a'), lambdaVariables('b'))))]", &Context::new()).unwrap();
+        assert_eq!(result.to_string(), "[1,2,3,4,5]");
+    }
+
+    #[test]
+    fn objects_by_property() {
+        let mut parser = Statement::new().unwrap();
+        let result = parser.parse_and_execute("[sort(createArray(createObject('n', 'b'), createObject('n', 'a')), lambda('a', 'b', less(lambdaVariables('a').n, lambdaVariables('b').n)))]", &Context::new()).unwrap();
+        assert_eq!(result.to_string(), r#"[{"n":"a"},{"n":"b"}]"#);
+    }
+
+    #[test]
+    fn stable() {
+        let mut parser = Statement::new().unwrap();
+        let result = parser.parse_and_execute("[sort(createArray(createObject('k', 1, 'v', 'a'), createObject('k', 0, 'v', 'b'), createObject('k', 1, 'v', 'c')), lambda('a', 'b', less(lambdaVariables('a').k, lambdaVariables('b').k)))]", &Context::new()).unwrap();
+        assert_eq!(result.to_string(), r#"[{"k":0,"v":"b"},{"k":1,"v":"a"},{"k":1,"v":"c"}]"#);
+    }
+
+    #[test]
+    fn non_boolean_result() {
+        let mut parser = Statement::new().unwrap();
+        let result = parser.parse_and_execute("[sort(createArray(2, 1), lambda('a', 'b', lambdaVariables('a')))]", &Context::new());
+        assert!(result.is_err());
+    }
+}
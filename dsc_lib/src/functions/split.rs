@@ -0,0 +1,140 @@
+// Copyright (c) Microsoft Corporation.
+// Licensed under the MIT License.
+
+use crate::DscError;
+use crate::configure::context::Context;
+use crate::functions::{AcceptedArgKind, Function, FunctionCategory};
+use serde_json::Value;
+use tracing::debug;
+
+#[derive(Debug, Default)]
+pub struct Split {}
+
+impl Function for Split {
+    fn min_args(&self) -> usize {
+        2
+    }
+
+    fn max_args(&self) -> usize {
+        2
+    }
+
+    fn accepted_arg_types(&self) -> Vec<AcceptedArgKind> {
+        vec![AcceptedArgKind::String, AcceptedArgKind::Array]
+    }
+
+    fn description(&self) -> String {
+        "Splits a string into an array using the delimiters.".to_string()
+    }
+
+    fn category(&self) -> FunctionCategory {
+        FunctionCategory::String
+    }
+
+    fn parameter_names(&self) -> Vec<&str> {
+        vec!["inputString", "delimiter"]
+    }
+
+    fn parameter_types(&self) -> Vec<Vec<AcceptedArgKind>> {
+        vec![
+            vec![AcceptedArgKind::String],
+            vec![AcceptedArgKind::String, AcceptedArgKind::Array],
+        ]
+    }
+
+    fn return_types(&self) -> Vec<AcceptedArgKind> {
+        vec![AcceptedArgKind::Array]
+    }
+
+    fn invoke(&self, args: &[Value], _context: &Context) -> Result<Value, DscError> {
+        debug!("split function");
+        let Some(value) = args[0].as_str() else {
+            return Err(DscError::Parser("First argument must be a string".to_string()));
+        };
+
+        let delimiters = match &args[1] {
+            Value::String(delimiter) => vec![delimiter.clone()],
+            Value::Array(array) => {
+                let mut delimiters = Vec::<String>::new();
+                for delimiter in array {
+                    let Some(delimiter) = delimiter.as_str() else {
+                        return Err(DscError::Parser("Delimiter array must only contain strings".to_string()));
+                    };
+                    delimiters.push(delimiter.to_string());
+                }
+                delimiters
+            },
+            _ => {
+                return Err(DscError::Parser("Delimiter must be a string or an array of strings".to_string()));
+            }
+        };
+        if delimiters.iter().any(String::is_empty) {
+            return Err(DscError::Parser("Delimiter cannot be empty".to_string()));
+        }
+
+        let mut result = Vec::<Value>::new();
+        let mut remaining = value;
+        loop {
+            // find the earliest occurrence of any of the delimiters
+            let next = delimiters.iter()
+                .filter_map(|delimiter| remaining.find(delimiter.as_str()).map(|index| (index, delimiter.len())))
+                .min_by_key(|(index, _)| *index);
+            let Some((index, length)) = next else {
+                result.push(Value::String(remaining.to_string()));
+                break;
+            };
+            result.push(Value::String(remaining[..index].to_string()));
+            remaining = &remaining[index + length..];
+        }
+
+        Ok(Value::Array(result))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::configure::context::Context;
+    use crate::parser::Statement;
+
+    #[test]
+    fn string_delimiter() {
+        let mut parser = Statement::new().unwrap();
+        let result = parser.parse_and_execute("[split('a,b,c', ',')]", &Context::new()).unwrap();
+        assert_eq!(result.to_string(), r#"["a","b","c"]"#);
+    }
+
+    #[test]
+    fn array_delimiter() {
+        let mut parser = Statement::new().unwrap();
+        let result = parser.parse_and_execute("[split('a,b;c', createArray(',', ';'))]", &Context::new()).unwrap();
+        assert_eq!(result.to_string(), r#"["a","b","c"]"#);
+    }
+
+    #[test]
+    fn delimiter_not_found() {
+        let mut parser = Statement::new().unwrap();
+        let result = parser.parse_and_execute("[split('abc', ',')]", &Context::new()).unwrap();
+        assert_eq!(result.to_string(), r#"["abc"]"#);
+    }
+
+    #[test]
+    fn empty_entries() {
+        let mut parser = Statement::new().unwrap();
+        let result = parser.parse_and_execute("[split(',a,', ',')]", &Context::new()).unwrap();
+        assert_eq!(result.to_string(), r#"["","a",""]"#);
+    }
+
+    #[test]
+    fn empty_delimiter() {
+        let mut parser = Statement::new().unwrap();
+        let result = parser.parse_and_execute("[split('abc', '')]", &Context::new());
+        assert!(result.is_err());
+    }
+
+    #[test]
+    fn array_input() {
+        let mut parser = Statement::new().unwrap();
+        let result = parser.parse_and_execute("[split(createArray('a'), ',')]", &Context::new());
+        assert!(result.is_err());
+    }
+}
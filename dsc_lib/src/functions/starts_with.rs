@@ -0,0 +1,78 @@
+// Copyright (c) Microsoft Corporation.
+// Licensed under the MIT License.
+
+use crate::DscError;
+use crate::configure::context::Context;
+use crate::functions::{AcceptedArgKind, Function, FunctionCategory};
+use serde_json::Value;
+use tracing::debug;
+
+#[derive(Debug, Default)]
+pub struct StartsWith {}
+
+impl Function for StartsWith {
+    fn min_args(&self) -> usize {
+        2
+    }
+
+    fn max_args(&self) -> usize {
+        2
+    }
+
+    fn accepted_arg_types(&self) -> Vec<AcceptedArgKind> {
+        vec![AcceptedArgKind::String]
+    }
+
+    fn description(&self) -> String {
+        "Checks whether a string starts with a value.".to_string()
+    }
+
+    fn category(&self) -> FunctionCategory {
+        FunctionCategory::String
+    }
+
+    fn parameter_names(&self) -> Vec<&str> {
+        vec!["stringToSearch", "stringToFind"]
+    }
+
+    fn return_types(&self) -> Vec<AcceptedArgKind> {
+        vec![AcceptedArgKind::Boolean]
+    }
+
+    fn invoke(&self, args: &[Value], _context: &Context) -> Result<Value, DscError> {
+        debug!("startsWith function");
+        if let (Some(value), Some(prefix)) = (args[0].as_str(), args[1].as_str()) {
+            // comparison is case-insensitive to match ARM
+            Ok(Value::Bool(value.to_lowercase().starts_with(&prefix.to_lowercase())))
+        } else {
+            Err(DscError::Parser("Invalid argument(s)".to_string()))
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::configure::context::Context;
+    use crate::parser::Statement;
+
+    #[test]
+    fn starts_with() {
+        let mut parser = Statement::new().unwrap();
+        let result = parser.parse_and_execute("[startsWith('hello world', 'hello')]", &Context::new()).unwrap();
+        assert_eq!(result, true);
+    }
+
+    #[test]
+    fn case_insensitive() {
+        let mut parser = Statement::new().unwrap();
+        let result = parser.parse_and_execute("[startsWith('Hello World', 'hELLO')]", &Context::new()).unwrap();
+        assert_eq!(result, true);
+    }
+
+    #[test]
+    fn does_not_start_with() {
+        let mut parser = Statement::new().unwrap();
+        let result = parser.parse_and_execute("[startsWith('hello world', 'world')]", &Context::new()).unwrap();
+        assert_eq!(result, false);
+    }
+}
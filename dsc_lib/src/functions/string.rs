@@ -0,0 +1,84 @@
+// Copyright (c) Microsoft Corporation.
+// Licensed under the MIT License.
+
+use crate::DscError;
+use crate::configure::context::Context;
+use crate::functions::{AcceptedArgKind, Function, FunctionCategory};
+use serde_json::Value;
+use tracing::debug;
+
+#[derive(Debug, Default)]
+pub struct StringFn {}
+
+impl Function for StringFn {
+    fn min_args(&self) -> usize {
+        1
+    }
+
+    fn max_args(&self) -> usize {
+        1
+    }
+
+    fn accepted_arg_types(&self) -> Vec<AcceptedArgKind> {
+        vec![AcceptedArgKind::String, AcceptedArgKind::Number, AcceptedArgKind::Boolean, AcceptedArgKind::Array, AcceptedArgKind::Object]
+    }
+
+    fn description(&self) -> String {
+        "Converts the value to a string.".to_string()
+    }
+
+    fn category(&self) -> FunctionCategory {
+        FunctionCategory::String
+    }
+
+    fn parameter_names(&self) -> Vec<&str> {
+        vec!["valueToConvert"]
+    }
+
+    fn return_types(&self) -> Vec<AcceptedArgKind> {
+        vec![AcceptedArgKind::String]
+    }
+
+    fn invoke(&self, args: &[Value], _context: &Context) -> Result<Value, DscError> {
+        debug!("string function");
+        match &args[0] {
+            Value::String(value) => Ok(Value::String(value.clone())),
+            // numbers, booleans, arrays and objects are converted to compact JSON
+            value => Ok(Value::String(serde_json::to_string(value)?)),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::configure::context::Context;
+    use crate::parser::Statement;
+
+    #[test]
+    fn string() {
+        let mut parser = Statement::new().unwrap();
+        let result = parser.parse_and_execute("[string('abc')]", &Context::new()).unwrap();
+        assert_eq!(result, "abc");
+    }
+
+    #[test]
+    fn number() {
+        let mut parser = Statement::new().unwrap();
+        let result = parser.parse_and_execute("[string(123)]", &Context::new()).unwrap();
+        assert_eq!(result, "123");
+    }
+
+    #[test]
+    fn boolean() {
+        let mut parser = Statement::new().unwrap();
+        let result = parser.parse_and_execute("[string(true)]", &Context::new()).unwrap();
+        assert_eq!(result, "true");
+    }
+
+    #[test]
+    fn array() {
+        let mut parser = Statement::new().unwrap();
+        let result = parser.parse_and_execute("[string(createArray('a', 'b'))]", &Context::new()).unwrap();
+        assert_eq!(result, r#"["a","b"]"#);
+    }
+}
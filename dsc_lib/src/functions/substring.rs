@@ -0,0 +1,131 @@
+// Copyright (c) Microsoft Corporation.
+// Licensed under the MIT License.
+
+use crate::DscError;
+use crate::configure::context::Context;
+use crate::functions::{AcceptedArgKind, Function, FunctionCategory};
+use serde_json::Value;
+use tracing::debug;
+
+#[derive(Debug, Default)]
+pub struct Substring {}
+
+impl Function for Substring {
+    fn min_args(&self) -> usize {
+        2
+    }
+
+    fn max_args(&self) -> usize {
+        3
+    }
+
+    fn accepted_arg_types(&self) -> Vec<AcceptedArgKind> {
+        vec![AcceptedArgKind::String, AcceptedArgKind::Number]
+    }
+
+    fn description(&self) -> String {
+        "Returns part of a string.".to_string()
+    }
+
+    fn category(&self) -> FunctionCategory {
+        FunctionCategory::String
+    }
+
+    fn parameter_names(&self) -> Vec<&str> {
+        vec!["stringToParse", "startIndex", "length"]
+    }
+
+    fn parameter_types(&self) -> Vec<Vec<AcceptedArgKind>> {
+        vec![
+            vec![AcceptedArgKind::String],
+            vec![AcceptedArgKind::Number],
+            vec![AcceptedArgKind::Number],
+        ]
+    }
+
+    fn return_types(&self) -> Vec<AcceptedArgKind> {
+        vec![AcceptedArgKind::String]
+    }
+
+    fn invoke(&self, args: &[Value], _context: &Context) -> Result<Value, DscError> {
+        debug!("substring function");
+        let Some(value) = args[0].as_str() else {
+            return Err(DscError::Parser("First argument must be a string".to_string()));
+        };
+        let Some(start) = args[1].as_i64() else {
+            return Err(DscError::Parser("Start index must be an integer".to_string()));
+        };
+        let chars = value.chars().collect::<Vec<char>>();
+        let Ok(start) = usize::try_from(start) else {
+            return Err(DscError::Parser("Start index cannot be negative".to_string()));
+        };
+        if start > chars.len() {
+            return Err(DscError::Parser(format!("Start index {start} is beyond the length of the string")));
+        }
+
+        let length = if args.len() == 3 {
+            let Some(length) = args[2].as_i64() else {
+                return Err(DscError::Parser("Length must be an integer".to_string()));
+            };
+            let Ok(length) = usize::try_from(length) else {
+                return Err(DscError::Parser("Length cannot be negative".to_string()));
+            };
+            if start + length > chars.len() {
+                return Err(DscError::Parser(format!("Start index {start} and length {length} are beyond the length of the string")));
+            }
+            length
+        } else {
+            chars.len() - start
+        };
+
+        Ok(Value::String(chars[start..start + length].iter().collect()))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::configure::context::Context;
+    use crate::parser::Statement;
+
+    #[test]
+    fn start_and_length() {
+        let mut parser = Statement::new().unwrap();
+        let result = parser.parse_and_execute("[substring('hello world', 6, 5)]", &Context::new()).unwrap();
+        assert_eq!(result, "world");
+    }
+
+    #[test]
+    fn start_only() {
+        let mut parser = Statement::new().unwrap();
+        let result = parser.parse_and_execute("[substring('hello world', 6)]", &Context::new()).unwrap();
+        assert_eq!(result, "world");
+    }
+
+    #[test]
+    fn start_at_end() {
+        let mut parser = Statement::new().unwrap();
+        let result = parser.parse_and_execute("[substring('hello', 5)]", &Context::new()).unwrap();
+        assert_eq!(result, "");
+    }
+
+    #[test]
+    fn out_of_range() {
+        let mut parser = Statement::new().unwrap();
+        let result = parser.parse_and_execute("[substring('hello', 2, 10)]", &Context::new());
+        assert!(result.is_err());
+    }
+
+    #[test]
+    fn negative_start() {
+        let mut parser = Statement::new().unwrap();
+        let result = parser.parse_and_execute("[substring('hello', -1)]", &Context::new());
+        assert!(result.is_err());
+    }
+
+    #[test]
+    fn invalid_string() {
+        let mut parser = Statement::new().unwrap();
+        let result = parser.parse_and_execute("[substring(1, 2)]", &Context::new());
+        assert!(result.is_err());
+    }
+}
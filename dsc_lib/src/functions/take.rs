@@ -0,0 +1,96 @@
+// Copyright (c) Microsoft Corporation.
+// Licensed under the MIT License.
+
+use crate::DscError;
+use crate::configure::context::Context;
+use crate::functions::{AcceptedArgKind, Function, FunctionCategory};
+use serde_json::Value;
+use tracing::debug;
+
+#[derive(Debug, Default)]
+pub struct Take {}
+
+impl Function for Take {
+    fn min_args(&self) -> usize {
+        2
+    }
+
+    fn max_args(&self) -> usize {
+        2
+    }
+
+    fn accepted_arg_types(&self) -> Vec<AcceptedArgKind> {
+        vec![AcceptedArgKind::Array, AcceptedArgKind::Number, AcceptedArgKind::String]
+    }
+
+    fn description(&self) -> String {
+        "Returns a number of elements from the start of an array or characters of a string.".to_string()
+    }
+
+    fn category(&self) -> FunctionCategory {
+        FunctionCategory::Array
+    }
+
+    fn parameter_names(&self) -> Vec<&str> {
+        vec!["originalValue", "numberToTake"]
+    }
+
+    fn parameter_types(&self) -> Vec<Vec<AcceptedArgKind>> {
+        vec![
+            vec![AcceptedArgKind::Array, AcceptedArgKind::String],
+            vec![AcceptedArgKind::Number],
+        ]
+    }
+
+    fn return_types(&self) -> Vec<AcceptedArgKind> {
+        vec![AcceptedArgKind::Array, AcceptedArgKind::String]
+    }
+
+    fn invoke(&self, args: &[Value], _context: &Context) -> Result<Value, DscError> {
+        debug!("take function");
+        let Some(count) = args[1].as_i64() else {
+            return Err(DscError::Parser("The number to take must be an integer".to_string()));
+        };
+        // negative counts are treated as zero
+        let count = usize::try_from(count.max(0))?;
+        match &args[0] {
+            Value::Array(array) => Ok(Value::Array(array.iter().take(count).cloned().collect())),
+            Value::String(string) => Ok(Value::String(string.chars().take(count).collect())),
+            _ => Err(DscError::Parser("First argument must be an array or a string".to_string())),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::configure::context::Context;
+    use crate::parser::Statement;
+
+    #[test]
+    fn array() {
+        let mut parser = Statement::new().unwrap();
+        let result = parser.parse_and_execute("[take(createArray('a', 'b', 'c'), 2)]", &Context::new()).unwrap();
+        assert_eq!(result.to_string(), r#"["a","b"]"#);
+    }
+
+    #[test]
+    fn string() {
+        let mut parser = Statement::new().unwrap();
+        let result = parser.parse_and_execute("[take('hello', 2)]", &Context::new()).unwrap();
+        assert_eq!(result, "he");
+    }
+
+    #[test]
+    fn invalid_count() {
+        let mut parser = Statement::new().unwrap();
+        let result = parser.parse_and_execute("[take('hello', 'a')]", &Context::new());
+        assert!(result.is_err());
+    }
+
+    #[test]
+    fn count_larger_than_length() {
+        let mut parser = Statement::new().unwrap();
+        let result = parser.parse_and_execute("[take(createArray('a'), 5)]", &Context::new()).unwrap();
+        assert_eq!(result.to_string(), r#"["a"]"#);
+    }
+}
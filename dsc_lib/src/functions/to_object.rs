@@ -0,0 +1,106 @@
+// Copyright (c) Microsoft Corporation.
+// Licensed under the MIT License.
+
+use crate::DscError;
+use crate::configure::context::Context;
+use crate::functions::{AcceptedArgKind, Function, FunctionCategory, LambdaArg};
+use serde_json::{Map, Value};
+use tracing::debug;
+
+#[derive(Debug, Default)]
+pub struct ToObject {}
+
+impl Function for ToObject {
+    fn min_args(&self) -> usize {
+        2
+    }
+
+    fn max_args(&self) -> usize {
+        3
+    }
+
+    fn accepted_arg_types(&self) -> Vec<AcceptedArgKind> {
+        vec![AcceptedArgKind::Array]
+    }
+
+    fn description(&self) -> String {
+        "Converts an array to an object using lambdas for the keys and values.".to_string()
+    }
+
+    fn category(&self) -> FunctionCategory {
+        FunctionCategory::Lambda
+    }
+
+    fn parameter_names(&self) -> Vec<&str> {
+        vec!["entries", "keyLambda", "valueLambda"]
+    }
+
+    fn parameter_types(&self) -> Vec<Vec<AcceptedArgKind>> {
+        vec![
+            vec![AcceptedArgKind::Array],
+            vec![AcceptedArgKind::Lambda],
+            vec![AcceptedArgKind::Lambda],
+        ]
+    }
+
+    fn return_types(&self) -> Vec<AcceptedArgKind> {
+        vec![AcceptedArgKind::Object]
+    }
+
+    fn invoke(&self, _args: &[Value], _context: &Context) -> Result<Value, DscError> {
+        Err(DscError::Parser("Function 'toObject' requires a lambda as the second argument".to_string()))
+    }
+
+    fn invoke_with_lambdas(&self, args: &[Value], lambdas: &[LambdaArg], _context: &Context) -> Result<Value, DscError> {
+        debug!("toObject function");
+        let ([array], [key_lambda, value_lambda @ ..]) = (args, lambdas) else {
+            return Err(DscError::Parser("Function 'toObject' requires an array, a key lambda and an optional value lambda".to_string()));
+        };
+        let Some(array) = array.as_array() else {
+            return Err(DscError::Parser("First argument must be an array".to_string()));
+        };
+
+        let mut result = Map::new();
+        for element in array {
+            let key = key_lambda(std::slice::from_ref(element))?;
+            let Some(key) = key.as_str() else {
+                return Err(DscError::Parser("Key lambda for 'toObject' must return a string".to_string()));
+            };
+            // without a value lambda, the element itself is the value
+            let value = match value_lambda.first() {
+                Some(value_lambda) => value_lambda(std::slice::from_ref(element))?,
+                None => element.clone(),
+            };
+            result.insert(key.to_string(), value);
+        }
+
+        Ok(Value::Object(result))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::configure::context::Context;
+    use crate::parser::Statement;
+
+    #[test]
+    fn key_only() {
+        let mut parser = Statement::new().unwrap();
+        let result = parser.parse_and_execute("[toObject(createArray(createObject('name', 'a', 'id', 1)), lambda('x', lambdaVariables('x').name))]", &Context::new()).unwrap();
+        assert_eq!(result.to_string(), r#"{"a":{"name":"a","id":1}}"#);
+    }
+
+    #[test]
+    fn key_and_value() {
+        let mut parser = Statement::new().unwrap();
+        let result = parser.parse_and_execute("[toObject(createArray('a', 'b'), lambda('x', lambdaVariables('x')), lambda('x', toUpper(lambdaVariables('x'))))]", &Context::new()).unwrap();
+        assert_eq!(result.to_string(), r#"{"a":"A","b":"B"}"#);
+    }
+
+    #[test]
+    fn non_string_key() {
+        let mut parser = Statement::new().unwrap();
+        let result = parser.parse_and_execute("[toObject(range(1, 2), lambda('x', lambdaVariables('x')))]", &Context::new());
+        assert!(result.is_err());
+    }
+}
@@ -0,0 +1,98 @@
+// Copyright (c) Microsoft Corporation.
+// Licensed under the MIT License.
+
+use crate::DscError;
+use crate::configure::context::Context;
+use crate::functions::{AcceptedArgKind, Function, FunctionCategory};
+use serde_json::{Map, Value};
+use tracing::debug;
+
+#[derive(Debug, Default)]
+pub struct Union {}
+
+impl Function for Union {
+    fn min_args(&self) -> usize {
+        2
+    }
+
+    fn max_args(&self) -> usize {
+        usize::MAX
+    }
+
+    fn accepted_arg_types(&self) -> Vec<AcceptedArgKind> {
+        vec![AcceptedArgKind::Array, AcceptedArgKind::Object]
+    }
+
+    fn description(&self) -> String {
+        "Returns the combined elements of all arrays or objects.".to_string()
+    }
+
+    fn category(&self) -> FunctionCategory {
+        FunctionCategory::Array
+    }
+
+    fn parameter_names(&self) -> Vec<&str> {
+        vec!["arg1", "arg2"]
+    }
+
+    fn return_types(&self) -> Vec<AcceptedArgKind> {
+        vec![AcceptedArgKind::Array, AcceptedArgKind::Object]
+    }
+
+    fn invoke(&self, args: &[Value], _context: &Context) -> Result<Value, DscError> {
+        debug!("union function");
+        if args[0].is_array() {
+            let mut result = Vec::<Value>::new();
+            for arg in args {
+                let Some(array) = arg.as_array() else {
+                    return Err(DscError::Parser("Arguments must all be arrays".to_string()));
+                };
+                for element in array {
+                    if !result.contains(element) {
+                        result.push(element.clone());
+                    }
+                }
+            }
+            Ok(Value::Array(result))
+        } else {
+            // later objects overwrite the values of earlier objects
+            let mut result = Map::new();
+            for arg in args {
+                let Some(object) = arg.as_object() else {
+                    return Err(DscError::Parser("Arguments must all be objects".to_string()));
+                };
+                for (key, value) in object {
+                    result.insert(key.clone(), value.clone());
+                }
+            }
+            Ok(Value::Object(result))
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::configure::context::Context;
+    use crate::parser::Statement;
+
+    #[test]
+    fn arrays() {
+        let mut parser = Statement::new().unwrap();
+        let result = parser.parse_and_execute("[union(createArray('a', 'b'), createArray('b', 'c'))]", &Context::new()).unwrap();
+        assert_eq!(result.to_string(), r#"["a","b","c"]"#);
+    }
+
+    #[test]
+    fn objects() {
+        let mut parser = Statement::new().unwrap();
+        let result = parser.parse_and_execute("[union(createObject('a', 1, 'b', 2), createObject('b', 3, 'c', 4))]", &Context::new()).unwrap();
+        assert_eq!(result.to_string(), r#"{"a":1,"b":3,"c":4}"#);
+    }
+
+    #[test]
+    fn mixed_types() {
+        let mut parser = Statement::new().unwrap();
+        let result = parser.parse_and_execute("[union(createArray('a'), createObject('b', 1))]", &Context::new());
+        assert!(result.is_err());
+    }
+}
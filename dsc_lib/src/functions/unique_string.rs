@@ -0,0 +1,136 @@
+// Copyright (c) Microsoft Corporation.
+// Licensed under the MIT License.
+
+use crate::DscError;
+use crate::configure::context::Context;
+use crate::functions::{AcceptedArgKind, Function, FunctionCategory};
+use serde_json::Value;
+use tracing::debug;
+
+const BASE32_ALPHABET: &[u8; 32] = b"abcdefghijklmnopqrstuvwxyz234567";
+const UNIQUE_STRING_LENGTH: usize = 13;
+
+#[derive(Debug, Default)]
+pub struct UniqueString {}
+
+impl Function for UniqueString {
+    fn min_args(&self) -> usize {
+        1
+    }
+
+    fn max_args(&self) -> usize {
+        usize::MAX
+    }
+
+    fn accepted_arg_types(&self) -> Vec<AcceptedArgKind> {
+        vec![AcceptedArgKind::String]
+    }
+
+    fn description(&self) -> String {
+        "Creates a deterministic hash string from the values.".to_string()
+    }
+
+    fn category(&self) -> FunctionCategory {
+        FunctionCategory::String
+    }
+
+    fn parameter_names(&self) -> Vec<&str> {
+        vec!["baseString"]
+    }
+
+    fn return_types(&self) -> Vec<AcceptedArgKind> {
+        vec![AcceptedArgKind::String]
+    }
+
+    fn invoke(&self, args: &[Value], _context: &Context) -> Result<Value, DscError> {
+        debug!("uniqueString function");
+        let mut hasher = Fnv128::new();
+        for arg in args {
+            let Some(value) = arg.as_str() else {
+                return Err(DscError::Parser("Invalid argument(s)".to_string()));
+            };
+            // the length is hashed before each value so `('a-b')` and `('a', 'b')` don't hash the same bytes
+            hasher.write(&(value.len() as u64).to_le_bytes());
+            hasher.write(value.as_bytes());
+        }
+
+        Ok(Value::String(encode_base32(hasher.finish())))
+    }
+}
+
+/// 128-bit FNV-1a hash, used so the result is stable across runs and platforms.
+struct Fnv128 {
+    hash: u128,
+}
+
+impl Fnv128 {
+    fn new() -> Self {
+        Self {
+            hash: 0x6c62_272e_07bb_0142_62b8_2175_6295_c58d,
+        }
+    }
+
+    fn write(&mut self, bytes: &[u8]) {
+        for byte in bytes {
+            self.hash ^= u128::from(*byte);
+            self.hash = self.hash.wrapping_mul(0x0000_0000_0100_0000_0000_0000_0000_013b);
+        }
+    }
+
+    fn finish(&self) -> u128 {
+        self.hash
+    }
+}
+
+// the 13 characters encode the top 65 bits of the hash
+fn encode_base32(mut value: u128) -> String {
+    let mut result = String::with_capacity(UNIQUE_STRING_LENGTH);
+    for _ in 0..UNIQUE_STRING_LENGTH {
+        // take the top 5 bits each time
+        let index = (value >> 123) as usize;
+        result.push(char::from(BASE32_ALPHABET[index]));
+        value <<= 5;
+    }
+    result
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::configure::context::Context;
+    use crate::parser::Statement;
+
+    #[test]
+    fn deterministic() {
+        let mut parser = Statement::new().unwrap();
+        let first = parser.parse_and_execute("[uniqueString('a', 'b')]", &Context::new()).unwrap();
+        let second = parser.parse_and_execute("[uniqueString('a', 'b')]", &Context::new()).unwrap();
+        assert_eq!(first, second);
+        assert_eq!(first.as_str().unwrap().len(), 13);
+    }
+
+    #[test]
+    fn different_input() {
+        let mut parser = Statement::new().unwrap();
+        let first = parser.parse_and_execute("[uniqueString('a', 'b')]", &Context::new()).unwrap();
+        let second = parser.parse_and_execute("[uniqueString('a', 'c')]", &Context::new()).unwrap();
+        assert_ne!(first, second);
+    }
+
+    #[test]
+    fn arguments_are_not_joined() {
+        let mut parser = Statement::new().unwrap();
+        let joined = parser.parse_and_execute("[uniqueString('a-b')]", &Context::new()).unwrap();
+        let separate = parser.parse_and_execute("[uniqueString('a', 'b')]", &Context::new()).unwrap();
+        assert_ne!(joined, separate);
+        let moved = parser.parse_and_execute("[uniqueString('ab', '')]", &Context::new()).unwrap();
+        let other = parser.parse_and_execute("[uniqueString('a', 'b')]", &Context::new()).unwrap();
+        assert_ne!(moved, other);
+    }
+
+    #[test]
+    fn number() {
+        let mut parser = Statement::new().unwrap();
+        let result = parser.parse_and_execute("[uniqueString(1)]", &Context::new());
+        assert!(result.is_err());
+    }
+}
@@ -0,0 +1,208 @@
+// Copyright (c) Microsoft Corporation.
+// Licensed under the MIT License.
+
+use crate::DscError;
+use crate::configure::Configurator;
+use crate::configure::config_doc::{DataType, UserFunctionDefinition};
+use crate::configure::context::Context;
+use crate::functions::{AcceptedArgKind, FunctionDispatcher};
+use crate::parser::ParsedStatement;
+use serde_json::Value;
+use std::cell::RefCell;
+use std::collections::HashMap;
+use tracing::debug;
+
+/// A function defined in the `functions` section of a configuration.
+pub struct UserFunction {
+    name: String,
+    definition: UserFunctionDefinition,
+    body: ParsedStatement,
+}
+
+impl UserFunction {
+    /// Create a new `UserFunction` instance.
+    ///
+    /// # Arguments
+    ///
+    /// * `name` - The name of the function including the namespace, like `contoso.path`.
+    /// * `definition` - The parameters and output of the function.
+    /// * `body` - The parsed output value of the function.
+    #[must_use]
+    pub fn new(name: &str, definition: &UserFunctionDefinition, body: ParsedStatement) -> Self {
+        Self {
+            name: name.to_string(),
+            definition: definition.clone(),
+            body,
+        }
+    }
+
+    /// The name of the function including the namespace.
+    #[must_use]
+    pub fn name(&self) -> &str {
+        &self.name
+    }
+
+    /// Invoke the function.
+    ///
+    /// # Arguments
+    ///
+    /// * `args` - The arguments to the function.
+    /// * `function_dispatcher` - The function dispatcher used to execute the output expression.
+    /// * `context` - The context of the caller.
+    ///
+    /// # Errors
+    ///
+    /// This function will return an error if the arguments don't match the parameters, the function calls itself or the output fails to execute.
+    pub fn invoke(&self, args: &[Value], function_dispatcher: &FunctionDispatcher, context: &Context) -> Result<Value, DscError> {
+        debug!("user function '{0}'", self.name);
+        let parameters = self.definition.parameters.as_deref().unwrap_or_default();
+        if args.len() != parameters.len() {
+            return Err(DscError::Parser(format!("Function '{0}' requires exactly {1} arguments", self.name, parameters.len())));
+        }
+        if context.user_function_stack.contains(&self.name) {
+            return Err(DscError::Function(self.name.clone(), format!("Recursive call through '{0}'", context.user_function_stack.join("' -> '"))));
+        }
+
+        // like ARM, the output can only use the parameters of the function and not those of the configuration
+        let mut function_context = context.clone();
+        function_context.parameters = HashMap::new();
+        function_context.variables = HashMap::new();
+        function_context.lambda_variables = RefCell::new(HashMap::new());
+        function_context.user_function_stack.push(self.name.clone());
+        for (parameter, value) in parameters.iter().zip(args) {
+            Configurator::validate_parameter_type(&parameter.name, value, &parameter.parameter_type)?;
+            function_context.parameters.insert(parameter.name.clone(), (value.clone(), parameter.parameter_type.clone()));
+        }
+
+        let result = self.body.execute(function_dispatcher, &function_context)?;
+        Configurator::validate_parameter_type(&format!("{0} output", self.name), &result, &self.definition.output.output_type)?;
+        Ok(result)
+    }
+
+    /// Check a call of the function without invoking it.
+    ///
+    /// # Arguments
+    ///
+    /// * `arg_types` - The possible types of each argument, `None` if they are only known when executed.
+    /// * `lambda_count` - The number of lambda arguments.
+    ///
+    /// # Returns
+    ///
+    /// The type that the function returns.
+    ///
+    /// # Errors
+    ///
+    /// This function will return an error if the arguments don't match the parameters.
+    pub fn validate(&self, arg_types: &[Option<Vec<AcceptedArgKind>>], lambda_count: usize) -> Result<Vec<AcceptedArgKind>, DscError> {
+        if lambda_count > 0 {
+            return Err(DscError::Parser(format!("Function '{0}' does not accept lambda arguments", self.name)));
+        }
+        let parameters = self.definition.parameters.as_deref().unwrap_or_default();
+        if arg_types.len() != parameters.len() {
+            return Err(DscError::Parser(format!("Function '{0}' requires exactly {1} arguments", self.name, parameters.len())));
+        }
+        for (parameter, kinds) in parameters.iter().zip(arg_types) {
+            let parameter_kind = to_arg_kind(&parameter.parameter_type);
+            if kinds.as_ref().is_some_and(|kinds| !kinds.contains(&parameter_kind)) {
+                return Err(DscError::Parser(format!("Function '{0}' requires parameter '{1}' to be of type {parameter_kind:?}", self.name, parameter.name)));
+            }
+        }
+        Ok(vec![to_arg_kind(&self.definition.output.output_type)])
+    }
+}
+
+fn to_arg_kind(data_type: &DataType) -> AcceptedArgKind {
+    match data_type {
+        DataType::Array => AcceptedArgKind::Array,
+        DataType::Bool => AcceptedArgKind::Boolean,
+        DataType::Int => AcceptedArgKind::Number,
+        DataType::Object | DataType::SecureObject => AcceptedArgKind::Object,
+        DataType::String | DataType::SecureString => AcceptedArgKind::String,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::configure::config_doc::UserFunctionNamespace;
+    use crate::configure::context::Context;
+    use crate::parser::Statement;
+
+    fn parser(functions: &str) -> Statement {
+        let mut parser = Statement::new().unwrap();
+        let namespaces: Vec<UserFunctionNamespace> = serde_json::from_str(functions).unwrap();
+        parser.add_user_functions(&namespaces).unwrap();
+        parser
+    }
+
+    #[test]
+    fn parameters() {
+        let mut parser = parser(r#"[{"namespace": "contoso", "members": {"path": {
+            "parameters": [{"name": "app", "type": "string"}, {"name": "file", "type": "string"}],
+            "output": {"type": "string", "value": "[concat('/opt/', parameters('app'), '/', parameters('file'))]"}
+        }}}]"#);
+        let result = parser.parse_and_execute("[contoso.path('web', 'app.conf')]", &Context::new()).unwrap();
+        assert_eq!(result, "/opt/web/app.conf");
+    }
+
+    #[test]
+    fn calls_other_user_function() {
+        let mut parser = parser(r#"[{"namespace": "contoso", "members": {
+            "root": {"output": {"type": "string", "value": "/opt"}},
+            "path": {
+                "parameters": [{"name": "app", "type": "string"}],
+                "output": {"type": "string", "value": "[concat(contoso.root(), '/', parameters('app'))]"}
+            }
+        }}]"#);
+        let result = parser.parse_and_execute("[contoso.path('web')]", &Context::new()).unwrap();
+        assert_eq!(result, "/opt/web");
+    }
+
+    #[test]
+    fn recursion() {
+        let mut parser = parser(r#"[{"namespace": "contoso", "members": {
+            "first": {"output": {"type": "string", "value": "[contoso.second()]"}},
+            "second": {"output": {"type": "string", "value": "[contoso.first()]"}}
+        }}]"#);
+        let result = parser.parse_and_execute("[contoso.first()]", &Context::new());
+        assert!(result.is_err());
+    }
+
+    #[test]
+    fn configuration_parameters_not_visible() {
+        let mut parser = parser(r#"[{"namespace": "contoso", "members": {
+            "name": {"output": {"type": "string", "value": "[parameters('name')]"}}
+        }}]"#);
+        let mut context = Context::new();
+        context.parameters.insert("name".to_string(), ("a".into(), crate::configure::config_doc::DataType::String));
+        let result = parser.parse_and_execute("[contoso.name()]", &context);
+        assert!(result.is_err());
+    }
+
+    #[test]
+    fn invalid_parameter_type() {
+        let mut parser = parser(r#"[{"namespace": "contoso", "members": {"double": {
+            "parameters": [{"name": "value", "type": "int"}],
+            "output": {"type": "int", "value": "[mul(parameters('value'), 2)]"}
+        }}}]"#);
+        assert_eq!(parser.parse_and_execute("[contoso.double(4)]", &Context::new()).unwrap(), 8);
+        assert!(parser.parse_and_execute("[contoso.double('4')]", &Context::new()).is_err());
+    }
+
+    #[test]
+    fn invalid_output_type() {
+        let mut parser = parser(r#"[{"namespace": "contoso", "members": {
+            "name": {"output": {"type": "int", "value": "abc"}}
+        }}]"#);
+        let result = parser.parse_and_execute("[contoso.name()]", &Context::new());
+        assert!(result.is_err());
+    }
+
+    #[test]
+    fn wrong_argument_count() {
+        let mut parser = parser(r#"[{"namespace": "contoso", "members": {
+            "name": {"output": {"type": "string", "value": "abc"}}
+        }}]"#);
+        let result = parser.parse_and_execute("[contoso.name('extra')]", &Context::new());
+        assert!(result.is_err());
+    }
+}
@@ -0,0 +1,228 @@
+// Copyright (c) Microsoft Corporation.
+// Licensed under the MIT License.
+
+use crate::DscError;
+use crate::configure::context::Context;
+use crate::functions::{AcceptedArgKind, Function, FunctionCategory};
+use chrono::{DateTime, Datelike, FixedOffset, Timelike, Utc};
+use serde_json::Value;
+use tracing::debug;
+
+// same default as ARM
+const DEFAULT_FORMAT: &str = "yyyyMMddTHHmmssZ";
+
+#[derive(Debug, Default)]
+pub struct UtcNow {}
+
+impl Function for UtcNow {
+    fn min_args(&self) -> usize {
+        0
+    }
+
+    fn max_args(&self) -> usize {
+        1
+    }
+
+    fn accepted_arg_types(&self) -> Vec<AcceptedArgKind> {
+        vec![AcceptedArgKind::String]
+    }
+
+    fn description(&self) -> String {
+        "Returns the start time of the operation in UTC.".to_string()
+    }
+
+    fn category(&self) -> FunctionCategory {
+        FunctionCategory::Date
+    }
+
+    fn parameter_names(&self) -> Vec<&str> {
+        vec!["format"]
+    }
+
+    fn return_types(&self) -> Vec<AcceptedArgKind> {
+        vec![AcceptedArgKind::String]
+    }
+
+    fn invoke(&self, args: &[Value], context: &Context) -> Result<Value, DscError> {
+        debug!("utcNow function");
+        let format = match args.first() {
+            Some(format) => format.as_str().unwrap_or_default(),
+            None => DEFAULT_FORMAT,
+        };
+
+        // use the start time so that every call within the same run returns the same value
+        let now = context.start_datetime.with_timezone(&Utc).fixed_offset();
+        Ok(Value::String(format_datetime(&now, format)?))
+    }
+}
+
+/// Format a date and time using a .NET style format string, like ARM.
+///
+/// # Arguments
+///
+/// * `datetime` - The date and time to format.
+/// * `format` - A standard format like `u` or a custom format like `yyyy-MM-dd`.
+///
+/// # Errors
+///
+/// This function will return an error if the format is not valid.
+pub(crate) fn format_datetime(datetime: &DateTime<FixedOffset>, format: &str) -> Result<String, DscError> {
+    let format = match format {
+        "d" => "MM/dd/yyyy",
+        "D" => "dddd, dd MMMM yyyy",
+        "g" => "MM/dd/yyyy HH:mm",
+        "G" => "MM/dd/yyyy HH:mm:ss",
+        "o" | "O" => "yyyy'-'MM'-'dd'T'HH':'mm':'ss'.'fffffffK",
+        "s" => "yyyy'-'MM'-'dd'T'HH':'mm':'ss",
+        "t" => "HH:mm",
+        "T" => "HH:mm:ss",
+        "u" => "yyyy'-'MM'-'dd HH':'mm':'ss'Z'",
+        _ if format.chars().count() == 1 => {
+            return Err(DscError::Parser(format!("Unsupported standard date and time format '{format}'")));
+        },
+        _ => format,
+    };
+
+    let mut result = String::new();
+    let mut chars = format.chars().peekable();
+    while let Some(c) = chars.next() {
+        match c {
+            '\'' | '"' => {
+                let mut closed = false;
+                for literal in chars.by_ref() {
+                    if literal == c {
+                        closed = true;
+                        break;
+                    }
+                    result.push(literal);
+                }
+                if !closed {
+                    return Err(DscError::Parser(format!("Unterminated quoted text in date and time format '{format}'")));
+                }
+            },
+            '\\' => {
+                let Some(escaped) = chars.next() else {
+                    return Err(DscError::Parser(format!("Trailing escape character in date and time format '{format}'")));
+                };
+                result.push(escaped);
+            },
+            'y' | 'M' | 'd' | 'H' | 'h' | 'm' | 's' | 'f' | 't' | 'z' | 'K' => {
+                let mut count = 1;
+                while chars.next_if_eq(&c).is_some() {
+                    count += 1;
+                }
+                let max_count = match c {
+                    'f' => 7,
+                    'K' => 1,
+                    _ => usize::MAX,
+                };
+                if count > max_count {
+                    return Err(DscError::Parser(format!("Unsupported date and time format specifier '{0}'", c.to_string().repeat(count))));
+                }
+                result.push_str(&format_specifier(datetime, c, count));
+            },
+            _ => result.push(c),
+        }
+    }
+
+    Ok(result)
+}
+
+fn format_specifier(datetime: &DateTime<FixedOffset>, specifier: char, count: usize) -> String {
+    let offset_seconds = datetime.offset().local_minus_utc();
+    let offset_sign = if offset_seconds < 0 { '-' } else { '+' };
+    let offset_hours = offset_seconds.abs() / 3600;
+    let offset_minutes = offset_seconds.abs() % 3600 / 60;
+    let hour12 = match datetime.hour() % 12 {
+        0 => 12,
+        hour => hour,
+    };
+
+    match (specifier, count) {
+        ('y', 1) => format!("{}", datetime.year() % 100),
+        ('y', 2) => format!("{:02}", datetime.year() % 100),
+        ('y', _) => format!("{:0count$}", datetime.year()),
+        ('M', 1) => format!("{}", datetime.month()),
+        ('M', 2) => format!("{:02}", datetime.month()),
+        ('M', 3) => datetime.format("%b").to_string(),
+        ('M', _) => datetime.format("%B").to_string(),
+        ('d', 1) => format!("{}", datetime.day()),
+        ('d', 2) => format!("{:02}", datetime.day()),
+        ('d', 3) => datetime.format("%a").to_string(),
+        ('d', _) => datetime.format("%A").to_string(),
+        ('H', 1) => format!("{}", datetime.hour()),
+        ('H', _) => format!("{:02}", datetime.hour()),
+        ('h', 1) => format!("{hour12}"),
+        ('h', _) => format!("{hour12:02}"),
+        ('m', 1) => format!("{}", datetime.minute()),
+        ('m', _) => format!("{:02}", datetime.minute()),
+        ('s', 1) => format!("{}", datetime.second()),
+        ('s', _) => format!("{:02}", datetime.second()),
+        ('f', _) => format!("{:09}", datetime.nanosecond() % 1_000_000_000)[..count].to_string(),
+        ('t', 1) => datetime.format("%p").to_string()[..1].to_string(),
+        ('t', _) => datetime.format("%p").to_string(),
+        ('z', 1) => format!("{offset_sign}{offset_hours}"),
+        ('z', 2) => format!("{offset_sign}{offset_hours:02}"),
+        ('K', _) if offset_seconds == 0 => "Z".to_string(),
+        // `zzz` and `K`, the specifiers and counts are checked by `format_datetime`
+        _ => format!("{offset_sign}{offset_hours:02}:{offset_minutes:02}"),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::configure::context::Context;
+    use crate::parser::Statement;
+
+    fn context() -> Context {
+        let mut context = Context::new();
+        context.start_datetime = chrono::DateTime::parse_from_rfc3339("2024-03-05T07:08:09.123+02:00").unwrap().with_timezone(&chrono::Local);
+        context
+    }
+
+    #[test]
+    fn default_format() {
+        let mut parser = Statement::new().unwrap();
+        let result = parser.parse_and_execute("[utcNow()]", &context()).unwrap();
+        assert_eq!(result, "20240305T050809Z");
+    }
+
+    #[test]
+    fn custom_format() {
+        let mut parser = Statement::new().unwrap();
+        let result = parser.parse_and_execute("[utcNow('yyyy-MM-dd hh:mm:ss.fff tt')]", &context()).unwrap();
+        assert_eq!(result, "2024-03-05 05:08:09.123 AM");
+    }
+
+    #[test]
+    fn standard_format() {
+        let mut parser = Statement::new().unwrap();
+        let result = parser.parse_and_execute("[utcNow('u')]", &context()).unwrap();
+        assert_eq!(result, "2024-03-05 05:08:09Z");
+    }
+
+    #[test]
+    fn stable_within_run() {
+        let mut parser = Statement::new().unwrap();
+        let context = Context::new();
+        let first = parser.parse_and_execute("[utcNow('o')]", &context).unwrap();
+        let second = parser.parse_and_execute("[utcNow('o')]", &context).unwrap();
+        assert_eq!(first, second);
+    }
+
+    #[test]
+    fn invalid_format() {
+        let mut parser = Statement::new().unwrap();
+        let result = parser.parse_and_execute("[utcNow('x')]", &context());
+        assert!(result.is_err());
+    }
+
+    #[test]
+    fn invalid_specifier_count() {
+        let mut parser = Statement::new().unwrap();
+        let result = parser.parse_and_execute("[utcNow('ffffffff')]", &context());
+        assert!(result.unwrap_err().to_string().contains("'ffffffff'"));
+        let result = parser.parse_and_execute("[utcNow('KK')]", &context());
+        assert!(result.is_err());
+    }
+}
@@ -3,7 +3,7 @@
 
 use crate::DscError;
 use crate::configure::context::Context;
-use crate::functions::{AcceptedArgKind, Function};
+use crate::functions::{AcceptedArgKind, Function, FunctionCategory};
 use serde_json::Value;
 use tracing::debug;
 
@@ -23,6 +23,22 @@ impl Function for Variables {
         vec![AcceptedArgKind::String]
     }
 
+    fn description(&self) -> String {
+        "Returns the value of a variable.".to_string()
+    }
+
+    fn category(&self) -> FunctionCategory {
+        FunctionCategory::Deployment
+    }
+
+    fn parameter_names(&self) -> Vec<&str> {
+        vec!["variableName"]
+    }
+
+    fn return_types(&self) -> Vec<AcceptedArgKind> {
+        vec![AcceptedArgKind::Array, AcceptedArgKind::Boolean, AcceptedArgKind::Number, AcceptedArgKind::Object, AcceptedArgKind::String]
+    }
+
     fn invoke(&self, args: &[Value], context: &Context) -> Result<Value, DscError> {
         debug!("variables function");
         if let Some(key) = args[0].as_str() {
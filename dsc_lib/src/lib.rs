@@ -4,13 +4,21 @@
 use configure::config_doc::ExecutionKind;
 use dscerror::DscError;
 use dscresources::{dscresource::{DscResource, Invoke}, invoke_result::{GetResult, SetResult, TestResult}};
+use functions::FunctionMetadata;
+use parser::Statement;
+use regex::RegexBuilder;
+use settings::DscSettings;
 
 pub mod configure;
 pub mod discovery;
 pub mod dscerror;
 pub mod dscresources;
+pub mod encryption;
 pub mod functions;
 pub mod parser;
+pub mod policy;
+pub mod secrets;
+pub mod settings;
 pub mod util;
 
 pub struct DscManager {
@@ -20,25 +28,28 @@ pub struct DscManager {
 impl DscManager {
     /// Create a new `DscManager` instance.
     ///
-    /// # Errors
+    /// # Arguments
     ///
-    /// This function will return an error if the underlying discovery fails.
+    /// * `settings` - The settings used to find resources.
     ///
-    pub fn new() -> Result<Self, DscError> {
-        Ok(Self {
-            discovery: discovery::Discovery::new()?,
-        })
+    #[must_use]
+    pub fn new(settings: &DscSettings) -> Self {
+        Self {
+            discovery: discovery::Discovery::new(settings),
+        }
     }
 
-    /// Find a resource by name.
+    /// Get a resource that was found by `find_resources()`.
     ///
     /// # Arguments
     ///
-    /// * `name` - The name of the resource to find, can have wildcards.
+    /// * `name` - The name of the resource, can have a version requirement like `Microsoft/OSInfo@^1.2`.
     ///
-    #[must_use]
-    pub fn find_resource(&self, name: &str) -> Option<&DscResource> {
-        self.discovery.find_resource(name)
+    /// # Errors
+    ///
+    /// This function will return an error if the resource wasn't found or isn't allowed by the policy.
+    pub fn get_resource(&self, name: &str) -> Result<&DscResource, DscError> {
+        self.discovery.get_resource(name)
     }
 
     pub fn list_available_resources(&mut self, type_name_filter: &str, adapter_name_filter: &str) -> Vec<DscResource> {
@@ -48,6 +59,30 @@ impl DscManager {
     pub fn find_resources(&mut self, required_resource_types: &[String]) {
         self.discovery.find_resources(required_resource_types);
     }
+
+    /// List the functions available to expressions, including those from function providers.
+    ///
+    /// # Arguments
+    ///
+    /// * `name_filter` - The filter for the function name, can have wildcards.
+    ///
+    /// # Errors
+    ///
+    /// This function will return an error if the filter or the parser is invalid.
+    pub fn list_functions(&mut self, name_filter: &str) -> Result<Vec<FunctionMetadata>, DscError> {
+        let regex_str = discovery::convert_wildcard_to_regex(name_filter);
+        let mut regex_builder = RegexBuilder::new(&regex_str);
+        regex_builder.case_insensitive(true);
+        let Ok(regex) = regex_builder.build() else {
+            return Err(DscError::Operation("Could not build Regex filter for function name".to_string()));
+        };
+
+        self.discovery.find_resources(&[]);
+        let mut statement = Statement::new()?;
+        statement.add_function_providers(&self.discovery.function_providers);
+        Ok(statement.list_functions().into_iter().filter(|function| regex.is_match(&function.name)).collect())
+    }
+
     /// Invoke the get operation on a resource.
     ///
     /// # Arguments
@@ -94,9 +129,3 @@ impl DscManager {
         resource.test(input)
     }
 }
-
-impl Default for DscManager {
-    fn default() -> Self {
-        Self::new().unwrap()
-    }
-}
@@ -2,12 +2,14 @@
 // Licensed under the MIT License.
 
 use serde_json::Value;
+use std::collections::HashSet;
 use tracing::{debug, trace};
 use tree_sitter::Node;
 
 use crate::configure::context::Context;
-use crate::dscerror::DscError;
-use crate::functions::FunctionDispatcher;
+use crate::dscerror::{DscError, SourceSpan};
+use crate::functions::{AcceptedArgKind, FunctionDispatcher};
+use crate::parser::ValidationScope;
 use crate::parser::functions::Function;
 
 #[derive(Clone)]
@@ -21,6 +23,7 @@ pub enum Accessor {
 pub struct Expression {
     function: Function,
     accessors: Vec<Accessor>,
+    span: SourceSpan,
 }
 
 impl Expression {
@@ -93,6 +96,7 @@ impl Expression {
         Ok(Expression {
             function,
             accessors,
+            span: SourceSpan::from_node(expression),
         })
     }
 
@@ -112,6 +116,58 @@ impl Expression {
     /// This function will return an error if the expression fails to execute.
     pub fn invoke(&self, function_dispatcher: &FunctionDispatcher, context: &Context) -> Result<Value, DscError> {
         let result = self.function.invoke(function_dispatcher, context)?;
+        self.invoke_accessors(result, function_dispatcher, context).map_err(|err| err.with_span(self.span))
+    }
+
+    /// Check the functions of the expression without executing it.
+    ///
+    /// # Returns
+    ///
+    /// The types that the expression can return, `None` if they are only known when executed.
+    ///
+    /// # Errors
+    ///
+    /// This function will return an error if a function is unknown, its arguments are invalid or it references something that isn't declared.
+    pub fn validate(&self, function_dispatcher: &FunctionDispatcher, scope: &ValidationScope) -> Result<Option<Vec<AcceptedArgKind>>, DscError> {
+        let return_types = self.function.validate(function_dispatcher, scope)?;
+        for accessor in &self.accessors {
+            if let Accessor::IndexExpression(expression) = accessor {
+                expression.validate(function_dispatcher, scope).map_err(|err| err.with_span(self.span))?;
+            }
+        }
+
+        // the type of a member or element isn't known until the expression is executed
+        if self.accessors.is_empty() {
+            Ok(Some(return_types))
+        } else {
+            Ok(None)
+        }
+    }
+
+    /// Add the names of the functions called by the expression.
+    ///
+    /// # Arguments
+    ///
+    /// * `names` - The names that are added to.
+    pub fn add_function_names(&self, names: &mut HashSet<String>) {
+        self.function.add_function_names(names);
+        for accessor in &self.accessors {
+            if let Accessor::IndexExpression(expression) = accessor {
+                expression.add_function_names(names);
+            }
+        }
+    }
+
+    /// Check if the expression references a secret or any of the secure parameters or variables.
+    #[must_use]
+    pub fn references_secure(&self, parameters: &HashSet<String>, variables: &HashSet<String>) -> bool {
+        self.function.references_secure(parameters, variables) || self.accessors.iter().any(|accessor| match accessor {
+            Accessor::IndexExpression(expression) => expression.references_secure(parameters, variables),
+            Accessor::Member(_) | Accessor::Index(_) => false,
+        })
+    }
+
+    fn invoke_accessors(&self, result: Value, function_dispatcher: &FunctionDispatcher, context: &Context) -> Result<Value, DscError> {
         trace!("Function result: '{:?}'", result);
         if self.accessors.is_empty() {
             Ok(result)
@@ -2,26 +2,38 @@
 // Licensed under the MIT License.
 
 use serde_json::{Number, Value};
+use std::collections::HashSet;
 use tracing::debug;
 use tree_sitter::Node;
 
 use crate::DscError;
+use crate::dscerror::SourceSpan;
 use crate::configure::context::Context;
+use crate::functions::{AcceptedArgKind, LambdaArg, LazyArg};
 use crate::parser::{
     expressions::Expression,
     FunctionDispatcher,
+    ValidationScope,
 };
 
 #[derive(Clone)]
 pub struct Function {
     name: String,
     args: Option<Vec<FunctionArg>>,
+    span: SourceSpan,
 }
 
 #[derive(Clone)]
 pub enum FunctionArg {
     Value(Value),
     Expression(Expression),
+    Lambda(Lambda),
+}
+
+#[derive(Clone)]
+pub struct Lambda {
+    parameters: Vec<String>,
+    body: Expression,
 }
 
 impl Function {
@@ -56,20 +68,42 @@ impl Function {
         debug!("Function name: {0}", name);
         Ok(Function{
             name: name.to_string(),
-            args})
+            args,
+            span: SourceSpan::from_node(function),
+        })
     }
 
     /// Invoke the function.
     ///
     /// # Errors
     ///
-    /// This function will return an error if the function fails to execute.
+    /// This function will return an error if the function fails to execute, parser errors have the position of the function.
     pub fn invoke(&self, function_dispatcher: &FunctionDispatcher, context: &Context) -> Result<Value, DscError> {
+        // errors from nested expressions already have a more precise position
+        self.invoke_args(function_dispatcher, context).map_err(|err| err.with_span(self.span))
+    }
+
+    fn invoke_args(&self, function_dispatcher: &FunctionDispatcher, context: &Context) -> Result<Value, DscError> {
+        if function_dispatcher.is_lazy(&self.name) {
+            // the function decides which args get evaluated, like the branches of `if()`
+            let mut lazy_args: Vec<LazyArg> = vec![];
+            if let Some(args) = &self.args {
+                for arg in args {
+                    lazy_args.push(Box::new(move || arg.invoke(function_dispatcher, context)));
+                }
+            }
+            return function_dispatcher.invoke_lazy(&self.name, &lazy_args, context);
+        }
+
         // if any args are expressions, we need to invoke those first
         let mut resolved_args: Vec<Value> = vec![];
+        let mut lambdas: Vec<LambdaArg> = vec![];
         if let Some(args) = &self.args {
             for arg in args {
                 match arg {
+                    FunctionArg::Expression(_) | FunctionArg::Value(_) if !lambdas.is_empty() => {
+                        return Err(DscError::Parser(format!("Lambda arguments must be the last arguments of function '{0}'", self.name)));
+                    },
                     FunctionArg::Expression(expression) => {
                         debug!("Arg is expression");
                         let value = expression.invoke(function_dispatcher, context)?;
@@ -78,13 +112,158 @@ impl Function {
                     FunctionArg::Value(value) => {
                         debug!("Arg is value: '{:?}'", value);
                         resolved_args.push(value.clone());
+                    },
+                    FunctionArg::Lambda(lambda) => {
+                        debug!("Arg is lambda with parameters: {:?}", lambda.parameters);
+                        lambdas.push(Box::new(move |values: &[Value]| lambda.invoke(values, function_dispatcher, context)));
                     }
                 }
             }
         }
 
+        if !lambdas.is_empty() {
+            return function_dispatcher.invoke_with_lambdas(&self.name, &resolved_args, &lambdas, context);
+        }
+
         function_dispatcher.invoke(&self.name, &resolved_args, context)
     }
+
+    /// Check the function and its arguments without invoking it.
+    ///
+    /// # Returns
+    ///
+    /// The types that the function can return.
+    ///
+    /// # Errors
+    ///
+    /// This function will return an error if the function is invalid, parser errors have the position of the function.
+    pub fn validate(&self, function_dispatcher: &FunctionDispatcher, scope: &ValidationScope) -> Result<Vec<AcceptedArgKind>, DscError> {
+        self.validate_args(function_dispatcher, scope).map_err(|err| err.with_span(self.span))
+    }
+
+    fn validate_args(&self, function_dispatcher: &FunctionDispatcher, scope: &ValidationScope) -> Result<Vec<AcceptedArgKind>, DscError> {
+        let mut arg_types: Vec<Option<Vec<AcceptedArgKind>>> = vec![];
+        let mut lambda_count = 0;
+        if let Some(args) = &self.args {
+            for arg in args {
+                match arg {
+                    FunctionArg::Expression(_) | FunctionArg::Value(_) if lambda_count > 0 => {
+                        return Err(DscError::Parser(format!("Lambda arguments must be the last arguments of function '{0}'", self.name)));
+                    },
+                    FunctionArg::Expression(expression) => {
+                        arg_types.push(expression.validate(function_dispatcher, scope)?);
+                    },
+                    FunctionArg::Value(value) => {
+                        arg_types.push(AcceptedArgKind::from_value(value).map(|kind| vec![kind]));
+                    },
+                    FunctionArg::Lambda(lambda) => {
+                        let mut lambda_scope = scope.clone();
+                        lambda_scope.lambda_variables.extend(lambda.parameters.iter().cloned());
+                        lambda.body.validate(function_dispatcher, &lambda_scope)?;
+                        lambda_count += 1;
+                    },
+                }
+            }
+
+            // names given as literals can be checked against what is declared
+            if let Some(FunctionArg::Value(Value::String(name))) = args.first() {
+                scope.check_reference(&self.name, name)?;
+            }
+        }
+
+        function_dispatcher.validate(&self.name, &arg_types, lambda_count)
+    }
+
+    /// Add the names of the function and the functions called by its arguments.
+    ///
+    /// # Arguments
+    ///
+    /// * `names` - The names that are added to.
+    pub fn add_function_names(&self, names: &mut HashSet<String>) {
+        names.insert(self.name.clone());
+        for arg in self.args.iter().flatten() {
+            match arg {
+                FunctionArg::Value(_) => {},
+                FunctionArg::Expression(expression) => expression.add_function_names(names),
+                FunctionArg::Lambda(lambda) => lambda.body.add_function_names(names),
+            }
+        }
+    }
+
+    /// Check if the function or its arguments reference a secret or any of the secure parameters or variables.
+    #[must_use]
+    pub fn references_secure(&self, parameters: &HashSet<String>, variables: &HashSet<String>) -> bool {
+        if self.name == "secret" {
+            return true;
+        }
+        let names = match self.name.as_str() {
+            "parameters" => Some(parameters),
+            "variables" => Some(variables),
+            _ => None,
+        };
+        if let Some(names) = names.filter(|names| !names.is_empty()) {
+            match self.args.as_ref().and_then(|args| args.first()) {
+                Some(FunctionArg::Value(Value::String(name))) => {
+                    if names.contains(name) {
+                        return true;
+                    }
+                },
+                // the name is only known when executed, so it could be any of them
+                _ => return true,
+            }
+        }
+
+        self.args.iter().flatten().any(|arg| match arg {
+            FunctionArg::Value(_) => false,
+            FunctionArg::Expression(expression) => expression.references_secure(parameters, variables),
+            FunctionArg::Lambda(lambda) => lambda.body.references_secure(parameters, variables),
+        })
+    }
+}
+
+impl FunctionArg {
+    fn invoke(&self, function_dispatcher: &FunctionDispatcher, context: &Context) -> Result<Value, DscError> {
+        match self {
+            FunctionArg::Expression(expression) => expression.invoke(function_dispatcher, context),
+            FunctionArg::Value(value) => Ok(value.clone()),
+            FunctionArg::Lambda(_) => Err(DscError::Parser("Lambda is not valid as this argument".to_string())),
+        }
+    }
+}
+
+impl Lambda {
+    /// Invoke the lambda body with the parameters set as lambda variables.
+    ///
+    /// # Arguments
+    ///
+    /// * `values` - The values for the lambda parameters, extra values are ignored.
+    ///
+    /// # Errors
+    ///
+    /// This function will return an error if too few values are provided or the body fails to execute.
+    pub fn invoke(&self, values: &[Value], function_dispatcher: &FunctionDispatcher, context: &Context) -> Result<Value, DscError> {
+        if self.parameters.len() > values.len() {
+            return Err(DscError::Parser(format!("Lambda has {0} parameters but only {1} values are available", self.parameters.len(), values.len())));
+        }
+
+        // the parameters are set for the body and the values they replaced are restored afterwards, so nested lambdas
+        // can use the same names without copying the context for every element
+        let replaced = {
+            let mut lambda_variables = context.lambda_variables.borrow_mut();
+            self.parameters.iter().zip(values)
+                .map(|(name, value)| (name.clone(), lambda_variables.insert(name.clone(), value.clone())))
+                .collect::<Vec<(String, Option<Value>)>>()
+        };
+        let result = self.body.invoke(function_dispatcher, context);
+        let mut lambda_variables = context.lambda_variables.borrow_mut();
+        for (name, value) in replaced.into_iter().rev() {
+            match value {
+                Some(value) => lambda_variables.insert(name, value),
+                None => lambda_variables.remove(&name),
+            };
+        }
+        result
+    }
 }
 
 fn convert_args_node(statement_bytes: &[u8], args: &Option<Node>) -> Result<Option<Vec<FunctionArg>>, DscError> {
@@ -102,7 +281,7 @@ fn convert_args_node(statement_bytes: &[u8], args: &Option<Node>) -> Result<Opti
             },
             "number" => {
                 let value = arg.utf8_text(statement_bytes)?;
-                result.push(FunctionArg::Value(Value::Number(Number::from(value.parse::<i32>()?))));
+                result.push(FunctionArg::Value(Value::Number(parse_number(value)?)));
             },
             "boolean" => {
                 let value = arg.utf8_text(statement_bytes)?;
@@ -113,6 +292,18 @@ fn convert_args_node(statement_bytes: &[u8], args: &Option<Node>) -> Result<Opti
                 let expression = Expression::new(statement_bytes, &arg)?;
                 result.push(FunctionArg::Expression(expression));
             },
+            "lambda" => {
+                let mut parameters = vec![];
+                let mut cursor = arg.walk();
+                for parameter in arg.children_by_field_name("parameter", &mut cursor) {
+                    parameters.push(parameter.utf8_text(statement_bytes)?.to_string());
+                }
+                let Some(body) = arg.child_by_field_name("body") else {
+                    return Err(DscError::Parser("Lambda body not found".to_string()));
+                };
+                let body = Expression::new(statement_bytes, &body)?;
+                result.push(FunctionArg::Lambda(Lambda { parameters, body }));
+            },
             _ => {
                 return Err(DscError::Parser(format!("Unknown argument type '{0}'", arg.kind())));
             }
@@ -120,3 +311,15 @@ fn convert_args_node(statement_bytes: &[u8], args: &Option<Node>) -> Result<Opti
     }
     Ok(Some(result))
 }
+
+fn parse_number(value: &str) -> Result<Number, DscError> {
+    // integers are kept exact, only literals with a decimal point become floating point values
+    if value.contains('.') {
+        let Some(number) = value.parse::<f64>().ok().and_then(Number::from_f64) else {
+            return Err(DscError::Parser(format!("Invalid decimal number '{value}'")));
+        };
+        return Ok(number);
+    }
+
+    Ok(Number::from(value.parse::<i64>()?))
+}
@@ -3,12 +3,19 @@
 
 use expressions::Expression;
 use serde_json::Value;
-use tracing::debug;
-use tree_sitter::Parser;
+use std::collections::{HashMap, HashSet};
+use std::sync::Arc;
+use tracing::{debug, trace, warn};
+use tree_sitter::{Node, Parser};
 
+use crate::configure::config_doc::UserFunctionNamespace;
 use crate::configure::context::Context;
-use crate::dscerror::DscError;
-use crate::functions::FunctionDispatcher;
+use crate::dscerror::{DscError, SourceSpan};
+use crate::dscresources::dscresource::DscResource;
+use crate::functions::{FunctionDispatcher, FunctionMetadata};
+use crate::functions::external_function::list_external_functions;
+use crate::functions::user_function::UserFunction;
+use crate::secrets::SecretProvider;
 
 pub mod expressions;
 pub mod functions;
@@ -16,6 +23,10 @@ pub mod functions;
 pub struct Statement {
     parser: Parser,
     function_dispatcher: FunctionDispatcher,
+    /// Parsed statements keyed by the statement text, parsing doesn't depend on the registered functions.
+    cache: HashMap<String, Arc<ParsedStatement>>,
+    /// Function providers whose functions haven't been listed yet.
+    function_providers: Vec<DscResource>,
 }
 
 impl Statement {
@@ -31,6 +42,8 @@ impl Statement {
         Ok(Self {
             parser,
             function_dispatcher,
+            cache: HashMap::new(),
+            function_providers: Vec::new(),
         })
     }
 
@@ -44,32 +57,84 @@ impl Statement {
     ///
     /// This function will return an error if the statement fails to parse or execute.
     pub fn parse_and_execute(&mut self, statement: &str, context: &Context) -> Result<Value, DscError> {
+        let parsed_statement = self.compile(statement)?;
+        self.evaluate(&parsed_statement, context)
+    }
+
+    /// Compile a statement so it can be evaluated multiple times, statements that were already compiled are returned from the cache.
+    ///
+    /// # Arguments
+    ///
+    /// * `statement` - The statement to compile.
+    ///
+    /// # Errors
+    ///
+    /// This function will return an error if the statement fails to parse.
+    pub fn compile(&mut self, statement: &str) -> Result<Arc<ParsedStatement>, DscError> {
+        let parsed_statement = if let Some(parsed_statement) = self.cache.get(statement) {
+            trace!("Using cached statement: {statement}");
+            Arc::clone(parsed_statement)
+        } else {
+            let parsed_statement = Arc::new(self.parse(statement)?);
+            self.cache.insert(statement.to_string(), Arc::clone(&parsed_statement));
+            parsed_statement
+        };
+
+        // the providers are only invoked once a statement calls a function in a namespace, which includes user functions
+        // as their output can call provider functions
+        if !self.function_providers.is_empty() && parsed_statement.function_names().iter().any(|name| name.contains('.')) {
+            self.load_function_providers();
+        }
+        Ok(parsed_statement)
+    }
+
+    /// Evaluate a compiled statement.
+    ///
+    /// # Arguments
+    ///
+    /// * `parsed_statement` - The statement returned by `compile()`.
+    /// * `context` - The context to evaluate the statement in.
+    ///
+    /// # Errors
+    ///
+    /// This function will return an error if the statement fails to execute.
+    pub fn evaluate(&self, parsed_statement: &ParsedStatement, context: &Context) -> Result<Value, DscError> {
+        parsed_statement.execute(&self.function_dispatcher, context)
+    }
+
+    /// Parse a statement without executing it.
+    ///
+    /// # Arguments
+    ///
+    /// * `statement` - The statement to parse.
+    ///
+    /// # Errors
+    ///
+    /// This function will return an error if the statement fails to parse.
+    pub fn parse(&mut self, statement: &str) -> Result<ParsedStatement, DscError> {
         debug!("Parsing statement: {0}", statement);
         let Some(tree) = &mut self.parser.parse(statement, None) else {
             return Err(DscError::Parser(format!("Error parsing statement: {statement}")));
         };
         let root_node = tree.root_node();
-        if root_node.is_error() {
-            return Err(DscError::Parser(format!("Error parsing statement root: {statement}")));
+        // a missing token, like a closing parenthesis at the end, is inserted by the parser so only invalid syntax is an error
+        if let Some(error_node) = find_error_node(&root_node) {
+            return Err(DscError::Parser("Invalid syntax".to_string()).with_span(SourceSpan::from_node(&error_node)).with_statement(statement));
         }
         if root_node.kind() != "statement" {
             return Err(DscError::Parser(format!("Invalid statement: {statement}")));
         }
         let statement_bytes = statement.as_bytes();
         let mut cursor = root_node.walk();
-        let mut return_value = Value::Null;
+        let mut parsed_statement = ParsedStatement::Value(Value::Null);
         for child_node in root_node.named_children(&mut cursor) {
-            if child_node.is_error() {
-                return Err(DscError::Parser(format!("Error parsing statement: {statement}")));
-            }
-
             match child_node.kind() {
                 "stringLiteral" => {
                     let Ok(value) = child_node.utf8_text(statement_bytes) else {
                         return Err(DscError::Parser("Error parsing string literal".to_string()));
                     };
                     debug!("Parsing string literal: {0}", value.to_string());
-                    return_value = Value::String(value.to_string());
+                    parsed_statement = ParsedStatement::Value(Value::String(value.to_string()));
                 },
                 "escapedStringLiteral" => {
                     // need to remove the first character: [[ => [
@@ -77,12 +142,13 @@ impl Statement {
                         return Err(DscError::Parser("Error parsing escaped string literal".to_string()));
                     };
                     debug!("Parsing escaped string literal: {0}", value[1..].to_string());
-                    return_value = Value::String(value[1..].to_string());
+                    parsed_statement = ParsedStatement::Value(Value::String(value[1..].to_string()));
                 },
                 "expression" => {
                     debug!("Parsing expression");
-                    let expression = Expression::new(statement_bytes, &child_node)?;
-                    return_value = expression.invoke(&self.function_dispatcher, context)?;
+                    let expression = Expression::new(statement_bytes, &child_node)
+                        .map_err(|err| err.with_span(SourceSpan::from_node(&child_node)).with_statement(statement))?;
+                    parsed_statement = ParsedStatement::Expression(expression, statement.to_string());
                 },
                 _ => {
                     return Err(DscError::Parser(format!("Unknown expression type {0}", child_node.kind())));
@@ -90,8 +156,231 @@ impl Statement {
             }
         }
 
-        Ok(return_value)
+        Ok(parsed_statement)
+    }
+
+    /// Check the functions and references of a statement without executing it.
+    ///
+    /// # Arguments
+    ///
+    /// * `statement` - The statement to check.
+    /// * `scope` - The names that the statement can reference.
+    ///
+    /// # Errors
+    ///
+    /// This function will return an error if the statement fails to parse, calls an unknown function, has invalid arguments or references an undeclared name.
+    pub fn validate(&mut self, statement: &str, scope: &ValidationScope) -> Result<(), DscError> {
+        let parsed_statement = self.compile(statement)?;
+        parsed_statement.validate(&self.function_dispatcher, scope)
+    }
+
+    /// List the functions that can be used in statements, not including user functions.
+    pub fn list_functions(&mut self) -> Vec<FunctionMetadata> {
+        self.load_function_providers();
+        self.function_dispatcher.list()
+    }
+
+    /// Register the user functions defined in a configuration.
+    ///
+    /// # Arguments
+    ///
+    /// * `namespaces` - The namespaces with the user functions to register.
+    ///
+    /// # Errors
+    ///
+    /// This function will return an error if a function name is invalid, already exists or its output fails to parse.
+    pub fn add_user_functions(&mut self, namespaces: &[UserFunctionNamespace]) -> Result<(), DscError> {
+        for namespace in namespaces {
+            if !is_valid_function_name(&namespace.namespace) {
+                return Err(DscError::Validation(format!("Invalid user function namespace '{0}'", namespace.namespace)));
+            }
+            for (member, definition) in &namespace.members {
+                if !is_valid_function_name(member) {
+                    return Err(DscError::Validation(format!("Invalid user function name '{member}' in namespace '{0}'", namespace.namespace)));
+                }
+                let name = format!("{0}.{member}", namespace.namespace);
+                // only strings can be expressions, other output values are returned as is
+                let body = match definition.output.value.as_str() {
+                    Some(value) => self.parse(value)?,
+                    None => ParsedStatement::Value(definition.output.value.clone()),
+                };
+                debug!("Adding user function '{name}'");
+                self.function_dispatcher.add_user_function(UserFunction::new(&name, definition, body))?;
+            }
+        }
+        Ok(())
+    }
+
+    /// Add a secret provider that `secret()` can get secrets from.
+    ///
+    /// # Arguments
+    ///
+    /// * `vault` - The name of the vault used to refer to the provider.
+    /// * `provider` - The provider.
+    ///
+    /// # Errors
+    ///
+    /// This function will return an error if a provider with the same vault name exists.
+    pub fn add_secret_provider(&mut self, vault: &str, provider: Box<dyn SecretProvider>) -> Result<(), DscError> {
+        self.function_dispatcher.add_secret_provider(vault, provider)
+    }
+
+    /// Add function providers, their functions are registered when a statement first calls a function in a namespace.
+    ///
+    /// # Arguments
+    ///
+    /// * `providers` - The discovered function provider resources.
+    pub fn add_function_providers(&mut self, providers: &[DscResource]) {
+        self.function_providers.extend_from_slice(providers);
+    }
+
+    // providers or functions that fail to load are skipped with a warning
+    fn load_function_providers(&mut self) {
+        for provider in std::mem::take(&mut self.function_providers) {
+            let functions = match list_external_functions(&provider) {
+                Ok(functions) => functions,
+                Err(err) => {
+                    warn!("Failed to list functions of provider '{0}': {err}", provider.type_name);
+                    continue;
+                }
+            };
+            for function in functions {
+                let valid_name = function.name().split_once('.').is_some_and(|(namespace, member)| is_valid_function_name(namespace) && is_valid_function_name(member));
+                if !valid_name {
+                    warn!("Invalid function name '{0}' from provider '{1}', it must be in the form 'namespace.name'", function.name(), provider.type_name);
+                    continue;
+                }
+                debug!("Adding function '{0}' from provider '{1}'", function.name(), provider.type_name);
+                if let Err(err) = self.function_dispatcher.add_external_function(function) {
+                    warn!("{err}");
+                }
+            }
+        }
+    }
+}
+
+/// A parsed statement that can be executed multiple times.
+#[derive(Clone)]
+pub enum ParsedStatement {
+    Value(Value),
+    /// An expression along with the statement text, which is used to show where errors occur.
+    Expression(Expression, String),
+}
+
+impl ParsedStatement {
+    /// Execute the statement.
+    ///
+    /// # Arguments
+    ///
+    /// * `function_dispatcher` - The function dispatcher to use.
+    /// * `context` - The context to execute the statement in.
+    ///
+    /// # Errors
+    ///
+    /// This function will return an error if the expression fails to execute.
+    pub fn execute(&self, function_dispatcher: &FunctionDispatcher, context: &Context) -> Result<Value, DscError> {
+        match self {
+            ParsedStatement::Value(value) => Ok(value.clone()),
+            ParsedStatement::Expression(expression, statement) => expression.invoke(function_dispatcher, context).map_err(|err| err.with_statement(statement)),
+        }
+    }
+
+    /// Get the names of the functions that the statement calls.
+    #[must_use]
+    pub fn function_names(&self) -> HashSet<String> {
+        let mut names = HashSet::new();
+        if let ParsedStatement::Expression(expression, _) = self {
+            expression.add_function_names(&mut names);
+        }
+        names
+    }
+
+    /// Check if the statement references a secret or any of the secure parameters or variables, which is assumed if a name is only known when executed.
+    ///
+    /// # Arguments
+    ///
+    /// * `parameters` - The names of the secure parameters.
+    /// * `variables` - The names of the secure variables.
+    #[must_use]
+    pub fn references_secure(&self, parameters: &HashSet<String>, variables: &HashSet<String>) -> bool {
+        match self {
+            ParsedStatement::Value(_) => false,
+            ParsedStatement::Expression(expression, _) => expression.references_secure(parameters, variables),
+        }
+    }
+
+    /// Check the statement without executing it.
+    ///
+    /// # Arguments
+    ///
+    /// * `function_dispatcher` - The function dispatcher to check the functions with.
+    /// * `scope` - The names that the statement can reference.
+    ///
+    /// # Errors
+    ///
+    /// This function will return an error if the expression is invalid.
+    pub fn validate(&self, function_dispatcher: &FunctionDispatcher, scope: &ValidationScope) -> Result<(), DscError> {
+        match self {
+            ParsedStatement::Value(_) => Ok(()),
+            ParsedStatement::Expression(expression, statement) => expression.validate(function_dispatcher, scope).map(|_| ()).map_err(|err| err.with_statement(statement)),
+        }
+    }
+}
+
+/// The parameters and variables that statements can reference, used to check statements before they are executed.
+#[derive(Debug, Clone, Default)]
+pub struct ValidationScope {
+    pub parameters: HashSet<String>,
+    pub variables: HashSet<String>,
+    pub lambda_variables: HashSet<String>,
+}
+
+impl ValidationScope {
+    /// Check that the name passed to `parameters()`, `variables()` or `lambdaVariables()` is declared.
+    ///
+    /// # Arguments
+    ///
+    /// * `function_name` - The name of the function that is called.
+    /// * `name` - The literal name passed to the function.
+    ///
+    /// # Errors
+    ///
+    /// This function will return an error if the name isn't declared.
+    pub fn check_reference(&self, function_name: &str, name: &str) -> Result<(), DscError> {
+        let (declared, kind) = match function_name {
+            "parameters" => (&self.parameters, "Parameter"),
+            "variables" => (&self.variables, "Variable"),
+            "lambdaVariables" => (&self.lambda_variables, "Lambda variable"),
+            _ => return Ok(()),
+        };
+        if declared.contains(name) {
+            Ok(())
+        } else {
+            Err(DscError::Parser(format!("{kind} '{name}' is not declared")))
+        }
+    }
+}
+
+// the first error node in the tree, which is where parsing failed
+fn find_error_node<'a>(node: &Node<'a>) -> Option<Node<'a>> {
+    if node.is_error() {
+        return Some(*node);
     }
+    let mut cursor = node.walk();
+    for child in node.children(&mut cursor) {
+        if child.has_error() {
+            if let Some(error_node) = find_error_node(&child) {
+                return Some(error_node);
+            }
+        }
+    }
+    None
+}
+
+// user function names must be valid for the `functionName` rule of the grammar
+fn is_valid_function_name(name: &str) -> bool {
+    let mut chars = name.chars();
+    chars.next().is_some_and(|c| c.is_ascii_lowercase()) && chars.all(|c| c.is_ascii_alphanumeric())
 }
 
 #[cfg(test)]
@@ -146,4 +435,125 @@ mod tests {
         let result = parser.parse_and_execute("[concat('abc', , 'def')]", &Context::new());
         assert!(result.is_err());
     }
+
+    #[test]
+    fn compiled_statement_is_cached() {
+        let mut parser = Statement::new().unwrap();
+        let first = parser.compile("[concat('a', 'b')]").unwrap();
+        let second = parser.compile("[concat('a', 'b')]").unwrap();
+        assert!(Arc::ptr_eq(&first, &second));
+        assert_eq!(parser.evaluate(&first, &Context::new()).unwrap(), "ab");
+    }
+
+    #[test]
+    fn compiled_statement_uses_context() {
+        let mut parser = Statement::new().unwrap();
+        let compiled = parser.compile("[parameters('a')]").unwrap();
+        let mut context = Context::new();
+        context.parameters.insert("a".to_string(), (Value::from(1), crate::configure::config_doc::DataType::Int));
+        assert_eq!(parser.evaluate(&compiled, &context).unwrap(), 1);
+        context.parameters.insert("a".to_string(), (Value::from(2), crate::configure::config_doc::DataType::Int));
+        assert_eq!(parser.evaluate(&compiled, &context).unwrap(), 2);
+    }
+
+    #[test]
+    fn failed_compile_is_not_cached() {
+        let mut parser = Statement::new().unwrap();
+        assert!(parser.compile("[concat('abc)]").is_err());
+        assert!(parser.cache.is_empty());
+    }
+
+    fn scope() -> ValidationScope {
+        ValidationScope {
+            parameters: HashSet::from(["name".to_string()]),
+            variables: HashSet::from(["list".to_string()]),
+            lambda_variables: HashSet::new(),
+        }
+    }
+
+    #[test]
+    fn validate_valid_statement() {
+        let mut parser = Statement::new().unwrap();
+        parser.validate("[concat(parameters('name'), string(add(1, 2)))]", &scope()).unwrap();
+        parser.validate("[filter(variables('list'), lambda('x', equals(lambdaVariables('x'), 1)))]", &scope()).unwrap();
+        parser.validate("[parameters('name').first]", &scope()).unwrap();
+        parser.validate("not an expression", &scope()).unwrap();
+    }
+
+    #[test]
+    fn validate_unknown_function() {
+        let mut parser = Statement::new().unwrap();
+        let err = parser.validate("[concat('a', toUpperr('b'))]", &scope()).unwrap_err();
+        let DscError::ParserAt(err) = err else {
+            panic!("Expected parser error with a position");
+        };
+        assert_eq!(err.span.start_byte, 13);
+        assert!(err.to_string().contains("Unknown function 'toUpperr'"));
+    }
+
+    #[test]
+    fn validate_argument_count() {
+        let mut parser = Statement::new().unwrap();
+        assert!(parser.validate("[substring('abc')]", &scope()).is_err());
+        assert!(parser.validate("[if(true(), 1)]", &scope()).is_err());
+    }
+
+    #[test]
+    fn validate_argument_types() {
+        let mut parser = Statement::new().unwrap();
+        assert!(parser.validate("[add('a', 1)]", &scope()).is_err());
+        assert!(parser.validate("[toUpper(add(1, 2))]", &scope()).is_err());
+        // the type of a parameter is only known when executed
+        parser.validate("[add(parameters('name'), 1)]", &scope()).unwrap();
+    }
+
+    #[test]
+    fn validate_undeclared_names() {
+        let mut parser = Statement::new().unwrap();
+        let err = parser.validate("[parameters('nmae')]", &scope()).unwrap_err();
+        assert!(err.to_string().contains("Parameter 'nmae' is not declared"));
+        assert!(parser.validate("[variables('missing')]", &scope()).is_err());
+        assert!(parser.validate("[map(variables('list'), lambda('x', lambdaVariables('y')))]", &scope()).is_err());
+    }
+
+    #[test]
+    fn error_position_of_nested_function() {
+        let mut parser = Statement::new().unwrap();
+        let err = parser.parse_and_execute("[concat('a', toUpperr('b'))]", &Context::new()).unwrap_err();
+        let DscError::ParserAt(err) = err else {
+            panic!("Expected parser error with a position");
+        };
+        assert_eq!(err.span.start_byte, 13);
+        assert_eq!(err.span.end_byte, 26);
+        assert_eq!(err.span.start_column, 13);
+        assert!(err.to_string().ends_with("\n  [concat('a', toUpperr('b'))]\n               ^^^^^^^^^^^^^"));
+    }
+
+    #[test]
+    fn error_position_of_syntax_error() {
+        let mut parser = Statement::new().unwrap();
+        let err = parser.parse_and_execute("[concat('abc', , 'def')]", &Context::new()).unwrap_err();
+        let DscError::ParserAt(err) = err else {
+            panic!("Expected parser error with a position");
+        };
+        assert_eq!(err.statement, "[concat('abc', , 'def')]");
+        assert!(err.span.start_byte >= 13 && err.span.start_byte <= 15);
+    }
+
+    #[test]
+    fn error_location() {
+        let mut parser = Statement::new().unwrap();
+        let err = parser.parse_and_execute("[invalid()]", &Context::new()).unwrap_err().with_location("resource 'Echo' property 'output'");
+        assert!(err.to_string().contains(" in resource 'Echo' property 'output'\n  [invalid()]\n   ^^^^^^^^^"));
+    }
+
+    #[test]
+    fn function_providers_are_loaded_on_first_namespaced_call() {
+        let mut parser = Statement::new().unwrap();
+        parser.add_function_providers(&[DscResource::new()]);
+        parser.parse_and_execute("[concat('a', 'b')]", &Context::new()).unwrap();
+        assert_eq!(parser.function_providers.len(), 1);
+        assert!(parser.parse_and_execute("[contoso.lookup('a')]", &Context::new()).is_err());
+        assert!(parser.function_providers.is_empty());
+    }
 }
@@ -0,0 +1,279 @@
+// Copyright (c) Microsoft Corporation.
+// Licensed under the MIT License.
+
+use crate::discovery::convert_wildcard_to_regex;
+use crate::dscerror::DscError;
+use crate::dscresources::dscresource::{DscResource, ImplementedAs};
+use crate::dscresources::resource_manifest::Kind;
+use regex::{Regex, RegexBuilder};
+use serde::Deserialize;
+use std::path::{Path, PathBuf};
+
+/// Wildcard patterns of resource types, which are compiled once when the policy is loaded.
+#[derive(Debug, Clone, Default, Deserialize)]
+#[serde(from = "Vec<String>")]
+pub struct WildcardPatterns {
+    patterns: Vec<String>,
+    regexes: Vec<Regex>,
+}
+
+impl From<Vec<String>> for WildcardPatterns {
+    fn from(patterns: Vec<String>) -> Self {
+        // an invalid pattern doesn't match anything, so it doesn't allow a resource
+        let regexes = patterns.iter()
+            .filter_map(|pattern| RegexBuilder::new(&convert_wildcard_to_regex(pattern)).case_insensitive(true).build().ok())
+            .collect();
+        Self { patterns, regexes }
+    }
+}
+
+impl PartialEq for WildcardPatterns {
+    fn eq(&self, other: &Self) -> bool {
+        self.patterns == other.patterns
+    }
+}
+
+impl WildcardPatterns {
+    fn is_match(&self, type_name: &str) -> bool {
+        self.regexes.iter().any(|regex| regex.is_match(type_name))
+    }
+}
+
+/// The resource types and adapters that are allowed by the `policy` of a settings file.
+#[derive(Debug, Clone, Default, PartialEq, Deserialize)]
+#[serde(deny_unknown_fields, rename_all = "camelCase")]
+pub struct ResourcePolicy {
+    /// The wildcard patterns of the resource types that are allowed, all are allowed if not set.
+    pub allowed_resources: Option<WildcardPatterns>,
+    /// The wildcard patterns of the resource types that are blocked even if they are allowed.
+    #[serde(default)]
+    pub blocked_resources: WildcardPatterns,
+    /// The wildcard patterns of the adapters that are allowed, all are allowed if not set.
+    pub allowed_adapters: Option<WildcardPatterns>,
+    /// The wildcard patterns of the adapters that are blocked even if they are allowed.
+    #[serde(default)]
+    pub blocked_adapters: WildcardPatterns,
+    /// The directories that resource manifests must be in, they can be anywhere if not set.
+    pub manifest_directories: Option<Vec<String>>,
+}
+
+impl ResourcePolicy {
+    fn get_violation(&self, resource: &DscResource) -> Option<String> {
+        if !is_allowed(&resource.type_name, self.allowed_resources.as_ref(), &self.blocked_resources) {
+            return Some("the resource type is blocked".to_string());
+        }
+        if resource.kind == Kind::Adapter && !is_allowed(&resource.type_name, self.allowed_adapters.as_ref(), &self.blocked_adapters) {
+            return Some("the adapter is blocked".to_string());
+        }
+
+        match &resource.require_adapter {
+            Some(adapter) => {
+                if !is_allowed(adapter, self.allowed_adapters.as_ref(), &self.blocked_adapters) {
+                    return Some(format!("the adapter '{adapter}' is blocked"));
+                }
+            },
+            None => {
+                // adapted resources are checked with their adapter by `get_adapted_violation()`, which has a manifest
+                if let (Some(directories), ImplementedAs::Command) = (&self.manifest_directories, &resource.implemented_as) {
+                    if !is_in_directories(Path::new(&resource.path), directories) {
+                        return Some(format!("the manifest '{}' is not in an allowed directory", resource.path));
+                    }
+                }
+            },
+        }
+        None
+    }
+}
+
+/// The policies from every settings file, a resource must be allowed by all of them so a settings file can only
+/// restrict the resources further.
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct Policy {
+    pub policies: Vec<ResourcePolicy>,
+}
+
+impl Policy {
+    /// Get the reason a resource isn't allowed.
+    ///
+    /// # Arguments
+    ///
+    /// * `resource` - The resource to check.
+    ///
+    /// # Returns
+    ///
+    /// The reason the resource isn't allowed, or `None` if it is allowed.
+    #[must_use]
+    pub fn get_violation(&self, resource: &DscResource) -> Option<String> {
+        self.policies.iter().find_map(|policy| policy.get_violation(resource))
+    }
+
+    /// Get the reason an adapted resource isn't allowed, which includes its adapter not being allowed.
+    ///
+    /// # Arguments
+    ///
+    /// * `resource` - The adapted resource to check.
+    /// * `adapter` - The adapter of the resource.
+    ///
+    /// # Returns
+    ///
+    /// The reason the resource isn't allowed, or `None` if it is allowed.
+    #[must_use]
+    pub fn get_adapted_violation(&self, resource: &DscResource, adapter: &DscResource) -> Option<String> {
+        self.get_violation(resource).or_else(|| {
+            self.get_violation(adapter).map(|reason| format!("the adapter '{0}' is not allowed, {reason}", adapter.type_name))
+        })
+    }
+
+    /// Check if any policy restricts the directories of resource manifests.
+    #[must_use]
+    pub fn has_manifest_directories(&self) -> bool {
+        self.policies.iter().any(|policy| policy.manifest_directories.is_some())
+    }
+
+    /// Check that a resource is allowed.
+    ///
+    /// # Arguments
+    ///
+    /// * `resource` - The resource to check.
+    ///
+    /// # Errors
+    ///
+    /// This function will return an error if the resource isn't allowed.
+    pub fn check(&self, resource: &DscResource) -> Result<(), DscError> {
+        match self.get_violation(resource) {
+            Some(reason) => Err(DscError::PolicyViolation(resource.type_name.clone(), reason)),
+            None => Ok(()),
+        }
+    }
+}
+
+fn is_allowed(type_name: &str, allowed: Option<&WildcardPatterns>, blocked: &WildcardPatterns) -> bool {
+    if blocked.is_match(type_name) {
+        return false;
+    }
+    allowed.is_none_or(|allowed| allowed.is_match(type_name))
+}
+
+// the paths are canonicalized so `..` or a symbolic link can't be used to get out of an allowed directory
+fn is_in_directories(path: &Path, directories: &[String]) -> bool {
+    let Ok(path) = path.canonicalize() else {
+        return false;
+    };
+    directories.iter()
+        .filter_map(|directory| PathBuf::from(directory).canonicalize().ok())
+        .any(|directory| path.starts_with(directory))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn new_resource(type_name: &str, kind: Kind, require_adapter: Option<&str>) -> DscResource {
+        DscResource {
+            type_name: type_name.to_string(),
+            kind,
+            require_adapter: require_adapter.map(ToString::to_string),
+            ..Default::default()
+        }
+    }
+
+    #[test]
+    fn test_no_policy() {
+        let policy = Policy::default();
+        assert!(policy.check(&new_resource("Test/Echo", Kind::Resource, None)).is_ok());
+    }
+
+    #[test]
+    fn test_allowed_and_blocked_resources() {
+        let policy = Policy {
+            policies: vec![ResourcePolicy {
+                allowed_resources: Some(vec!["Microsoft/*".to_string(), "Test/*".to_string()].into()),
+                blocked_resources: vec!["test/blocked*".to_string()].into(),
+                ..Default::default()
+            }],
+        };
+        assert!(policy.check(&new_resource("Microsoft/OSInfo", Kind::Resource, None)).is_ok());
+        assert!(policy.check(&new_resource("Test/Echo", Kind::Resource, None)).is_ok());
+        assert!(policy.check(&new_resource("Test/BlockedEcho", Kind::Resource, None)).is_err());
+        assert!(policy.check(&new_resource("Other/Echo", Kind::Resource, None)).is_err());
+    }
+
+    #[test]
+    fn test_blocked_adapters() {
+        let policy = Policy {
+            policies: vec![ResourcePolicy {
+                blocked_adapters: vec!["Microsoft.*PowerShell".to_string()].into(),
+                ..Default::default()
+            }],
+        };
+        assert!(policy.check(&new_resource("Microsoft.DSC/PowerShell", Kind::Adapter, None)).is_err());
+        assert!(policy.check(&new_resource("Microsoft.DSC/PowerShell", Kind::Resource, None)).is_ok());
+        assert!(policy.check(&new_resource("Test/Class", Kind::Resource, Some("Microsoft.Windows/WindowsPowerShell"))).is_err());
+        assert!(policy.check(&new_resource("Test/Class", Kind::Resource, Some("Test/TestGroup"))).is_ok());
+    }
+
+    #[test]
+    fn test_every_policy_is_checked() {
+        let policy = Policy {
+            policies: vec![
+                ResourcePolicy {
+                    allowed_resources: Some(vec!["Test/*".to_string()].into()),
+                    ..Default::default()
+                },
+                ResourcePolicy {
+                    allowed_resources: Some(vec!["*".to_string()].into()),
+                    blocked_resources: vec!["Test/Echo".to_string()].into(),
+                    ..Default::default()
+                },
+            ],
+        };
+        assert!(policy.check(&new_resource("Test/Other", Kind::Resource, None)).is_ok());
+        assert!(policy.check(&new_resource("Test/Echo", Kind::Resource, None)).is_err());
+        assert!(policy.check(&new_resource("Other/Echo", Kind::Resource, None)).is_err());
+    }
+
+    #[test]
+    fn test_patterns_from_settings() {
+        let resource_policy: ResourcePolicy = serde_json::from_str(r#"{"allowedResources": ["Test/*", "[invalid"], "blockedAdapters": ["*"]}"#).unwrap();
+        let policy = Policy { policies: vec![resource_policy] };
+        assert!(policy.check(&new_resource("Test/Echo", Kind::Resource, None)).is_ok());
+        assert!(policy.check(&new_resource("[invalid", Kind::Resource, None)).is_err());
+        assert!(policy.check(&new_resource("Test/Adapter", Kind::Adapter, None)).is_err());
+        assert!(!policy.has_manifest_directories());
+    }
+
+    #[test]
+    fn test_manifest_directories() {
+        let directory = std::env::temp_dir().join(format!("dsc_policy_test_{}", std::process::id()));
+        let allowed = directory.join("allowed");
+        std::fs::create_dir_all(&allowed).unwrap();
+        let manifest = allowed.join("test.dsc.resource.json");
+        std::fs::write(&manifest, "{}").unwrap();
+        let outside = directory.join("test.dsc.resource.json");
+        std::fs::write(&outside, "{}").unwrap();
+
+        let policy = Policy {
+            policies: vec![ResourcePolicy {
+                manifest_directories: Some(vec![allowed.to_string_lossy().to_string()]),
+                ..Default::default()
+            }],
+        };
+        let mut resource = new_resource("Test/Echo", Kind::Resource, None);
+        resource.path = manifest.to_string_lossy().to_string();
+        assert!(policy.check(&resource).is_ok());
+        resource.path = allowed.join("..").join("test.dsc.resource.json").to_string_lossy().to_string();
+        assert!(policy.check(&resource).is_err());
+        resource.path = outside.to_string_lossy().to_string();
+        assert!(policy.check(&resource).is_err());
+
+        // an adapted resource is checked with the manifest of its adapter
+        let adapted = new_resource("Test/Adapted", Kind::Resource, Some("Test/Adapter"));
+        let mut adapter = new_resource("Test/Adapter", Kind::Adapter, None);
+        adapter.path = manifest.to_string_lossy().to_string();
+        assert!(policy.get_adapted_violation(&adapted, &adapter).is_none());
+        adapter.path = outside.to_string_lossy().to_string();
+        assert!(policy.get_adapted_violation(&adapted, &adapter).is_some());
+
+        std::fs::remove_dir_all(&directory).unwrap();
+    }
+}
@@ -0,0 +1,16 @@
+// Copyright (c) Microsoft Corporation.
+// Licensed under the MIT License.
+
+use crate::DscError;
+use super::SecretProvider;
+use std::env;
+
+/// Secrets from environment variables, the name of the secret is the name of the variable.
+#[derive(Debug, Default)]
+pub struct EnvSecretProvider {}
+
+impl SecretProvider for EnvSecretProvider {
+    fn get_secret(&self, name: &str) -> Result<Option<String>, DscError> {
+        Ok(env::var(name).ok())
+    }
+}
@@ -0,0 +1,98 @@
+// Copyright (c) Microsoft Corporation.
+// Licensed under the MIT License.
+
+use crate::DscError;
+use crate::encryption::{decrypt, get_key_file_path, load_identity};
+use crate::util::get_user_directory;
+use super::SecretProvider;
+use serde_json::{Map, Value};
+use std::path::PathBuf;
+use tracing::debug;
+
+/// The environment variable with the path of the encrypted secrets file.
+pub const DSC_SECRETS_FILE: &str = "DSC_SECRETS_FILE";
+
+/// Secrets from a JSON object of names and values in a file encrypted with age, like `age -r <public key> -a -o secrets.age secrets.json`.
+#[derive(Debug, Default)]
+pub struct EncryptedFileSecretProvider {
+    // the paths are looked up when a secret is requested if not set
+    path: Option<PathBuf>,
+    key_path: Option<PathBuf>,
+}
+
+impl EncryptedFileSecretProvider {
+    /// Create a provider for a specific file and key instead of `DSC_SECRETS_FILE` and the key file of the user.
+    ///
+    /// # Arguments
+    ///
+    /// * `path` - The path of the encrypted secrets file.
+    /// * `key_path` - The path of the key file.
+    #[must_use]
+    pub fn new(path: PathBuf, key_path: PathBuf) -> Self {
+        Self {
+            path: Some(path),
+            key_path: Some(key_path),
+        }
+    }
+
+    fn get_path(&self) -> Result<PathBuf, DscError> {
+        if let Some(path) = &self.path {
+            return Ok(path.clone());
+        }
+        if let Ok(path) = std::env::var(DSC_SECRETS_FILE) {
+            return Ok(PathBuf::from(path));
+        }
+        match get_user_directory() {
+            Some(directory) => Ok(directory.join("secrets.age")),
+            None => Err(DscError::Secret(format!("Secrets file not found, set '{DSC_SECRETS_FILE}' to its path"))),
+        }
+    }
+}
+
+impl SecretProvider for EncryptedFileSecretProvider {
+    fn get_secret(&self, name: &str) -> Result<Option<String>, DscError> {
+        let path = self.get_path()?;
+        let key_path = match &self.key_path {
+            Some(key_path) => key_path.clone(),
+            None => get_key_file_path()?,
+        };
+        debug!("Reading secrets file '{}'", path.display());
+        let encrypted = match std::fs::read(&path) {
+            Ok(encrypted) => encrypted,
+            Err(err) => return Err(DscError::Secret(format!("Failed to read secrets file '{}': {err}", path.display()))),
+        };
+        let decrypted = decrypt(&encrypted, &load_identity(&key_path)?)?;
+        let Ok(secrets) = serde_json::from_slice::<Map<String, Value>>(&decrypted) else {
+            return Err(DscError::Secret(format!("Secrets file '{}' is not a JSON object", path.display())));
+        };
+        match secrets.get(name) {
+            Some(Value::String(secret)) => Ok(Some(secret.clone())),
+            Some(_) => Err(DscError::Secret(format!("Secret '{name}' is not a string"))),
+            None => Ok(None),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::encryption::encrypt;
+    use age::secrecy::ExposeSecret;
+    use age::x25519::Identity;
+
+    #[test]
+    fn get_secret() {
+        let directory = std::env::temp_dir().join(format!("dsc_secrets_{}", std::process::id()));
+        std::fs::create_dir_all(&directory).unwrap();
+        let identity = Identity::generate();
+        let key_path = directory.join("key.txt");
+        std::fs::write(&key_path, format!("# test key\n{}\n", identity.to_string().expose_secret())).unwrap();
+        let path = directory.join("secrets.age");
+        std::fs::write(&path, encrypt(br#"{"password": "hunter2"}"#, &identity.to_public()).unwrap()).unwrap();
+
+        let provider = EncryptedFileSecretProvider::new(path, key_path);
+        assert_eq!(provider.get_secret("password").unwrap(), Some("hunter2".to_string()));
+        assert_eq!(provider.get_secret("missing").unwrap(), None);
+        std::fs::remove_dir_all(&directory).unwrap();
+    }
+}
@@ -0,0 +1,90 @@
+// Copyright (c) Microsoft Corporation.
+// Licensed under the MIT License.
+
+use crate::DscError;
+use std::collections::HashMap;
+use tracing::debug;
+
+pub mod env_provider;
+pub mod file_provider;
+
+/// A source of secrets that are retrieved with `secret()`.
+pub trait SecretProvider {
+    /// Get a secret.
+    ///
+    /// # Arguments
+    ///
+    /// * `name` - The name of the secret.
+    ///
+    /// # Returns
+    ///
+    /// The value of the secret or `None` if the provider doesn't have it.
+    ///
+    /// # Errors
+    ///
+    /// This function will return an error if the provider fails to retrieve the secret.
+    fn get_secret(&self, name: &str) -> Result<Option<String>, DscError>;
+}
+
+/// The secret providers by the name of the vault used to refer to them.
+pub struct SecretStore {
+    providers: HashMap<String, Box<dyn SecretProvider>>,
+}
+
+impl SecretStore {
+    /// Create a new `SecretStore` with the `env` and `local` vaults.
+    #[must_use]
+    pub fn new() -> Self {
+        let mut providers: HashMap<String, Box<dyn SecretProvider>> = HashMap::new();
+        providers.insert("env".to_string(), Box::new(env_provider::EnvSecretProvider{}));
+        providers.insert("local".to_string(), Box::new(file_provider::EncryptedFileSecretProvider::default()));
+        Self {
+            providers,
+        }
+    }
+
+    /// Add a secret provider.
+    ///
+    /// # Arguments
+    ///
+    /// * `vault` - The name of the vault used to refer to the provider.
+    /// * `provider` - The provider.
+    ///
+    /// # Errors
+    ///
+    /// This function will return an error if a provider with the same vault name exists.
+    pub fn add_provider(&mut self, vault: &str, provider: Box<dyn SecretProvider>) -> Result<(), DscError> {
+        if self.providers.contains_key(vault) {
+            return Err(DscError::Secret(format!("Vault '{vault}' is already defined")));
+        }
+        self.providers.insert(vault.to_string(), provider);
+        Ok(())
+    }
+
+    /// Get a secret from a vault.
+    ///
+    /// # Arguments
+    ///
+    /// * `vault` - The name of the vault.
+    /// * `name` - The name of the secret.
+    ///
+    /// # Errors
+    ///
+    /// This function will return an error if the vault is unknown, fails to retrieve the secret or doesn't have it.
+    pub fn get_secret(&self, vault: &str, name: &str) -> Result<String, DscError> {
+        let Some(provider) = self.providers.get(vault) else {
+            return Err(DscError::Secret(format!("Unknown vault '{vault}'")));
+        };
+        debug!("Getting secret '{name}' from vault '{vault}'");
+        match provider.get_secret(name)? {
+            Some(secret) => Ok(secret),
+            None => Err(DscError::Secret(format!("Secret '{name}' not found in vault '{vault}'"))),
+        }
+    }
+}
+
+impl Default for SecretStore {
+    fn default() -> Self {
+        Self::new()
+    }
+}
@@ -0,0 +1,203 @@
+// Copyright (c) Microsoft Corporation.
+// Licensed under the MIT License.
+
+use crate::dscerror::DscError;
+use crate::dscresources::command_resource::{TraceFormat, TraceLevel};
+use crate::policy::{Policy, ResourcePolicy};
+use crate::util::get_user_directory;
+use serde::Deserialize;
+use serde_json::{Map, Value};
+use std::path::{Path, PathBuf};
+use tracing::debug;
+
+/// The environment variable with the path of a settings file that takes precedence over the user settings.
+pub const DSC_SETTINGS_PATH: &str = "DSC_SETTINGS_PATH";
+
+const SETTINGS_FILE_NAME: &str = "dsc.settings.json";
+
+#[derive(Debug, Clone, PartialEq, Deserialize)]
+#[serde(deny_unknown_fields, rename_all = "camelCase")]
+pub struct ResourcePathSettings {
+    /// The directories searched for resource manifests before `PATH`.
+    #[serde(default)]
+    pub directories: Vec<String>,
+    /// Whether the directories in `PATH` are searched for resource manifests.
+    #[serde(default = "default_true")]
+    pub use_path: bool,
+    /// Whether `DSC_RESOURCE_PATH` replaces the directories that are searched.
+    #[serde(default = "default_true")]
+    pub allow_env_override: bool,
+}
+
+impl Default for ResourcePathSettings {
+    fn default() -> Self {
+        Self {
+            directories: Vec::new(),
+            use_path: true,
+            allow_env_override: true,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Default, PartialEq, Deserialize)]
+#[serde(deny_unknown_fields, rename_all = "camelCase")]
+pub struct TracingSettings {
+    /// The trace level used when it isn't specified on the command line or by `DSC_TRACE_LEVEL`.
+    pub level: Option<TraceLevel>,
+    /// The trace format used when it isn't specified on the command line.
+    pub format: Option<TraceFormat>,
+}
+
+/// The settings from `dsc.settings.json` in the system directory, the user directory and `DSC_SETTINGS_PATH`.
+#[derive(Debug, Clone, Default, PartialEq, Deserialize)]
+#[serde(deny_unknown_fields, rename_all = "camelCase")]
+pub struct DscSettings {
+    #[serde(default)]
+    pub resource_path: ResourcePathSettings,
+    #[serde(default)]
+    pub tracing: TracingSettings,
+    /// The path of the file that records which adapter provides each adapted resource.
+    pub adapter_lookup_table: Option<String>,
+    /// The `policy` of every settings file, which isn't merged so a settings file can't relax the policy of another.
+    #[serde(skip)]
+    pub policy: Policy,
+}
+
+impl DscSettings {
+    /// Load the settings, a setting in the `DSC_SETTINGS_PATH` file overrides the user settings and a setting
+    /// in the system settings can't be overridden.
+    ///
+    /// # Errors
+    ///
+    /// This function will return an error if a settings file can't be read or isn't valid.
+    pub fn load() -> Result<Self, DscError> {
+        // the system settings are merged last so an administrator can lock a setting for every user
+        let paths = [
+            get_user_directory().map(|directory| directory.join(SETTINGS_FILE_NAME)),
+            std::env::var_os(DSC_SETTINGS_PATH).map(PathBuf::from),
+            get_system_settings_path(),
+        ];
+        Self::load_files(paths.into_iter().flatten())
+    }
+
+    // a setting in a later file overrides the same setting in an earlier file
+    fn load_files(paths: impl IntoIterator<Item = PathBuf>) -> Result<Self, DscError> {
+        let mut settings = Value::Object(Map::new());
+        let mut policy = Policy::default();
+        for path in paths {
+            if let Some(mut value) = read_settings_file(&path)? {
+                if let Some(resource_policy) = value.as_object_mut().and_then(|value| value.remove("policy")) {
+                    match serde_json::from_value::<ResourcePolicy>(resource_policy) {
+                        Ok(resource_policy) => policy.policies.push(resource_policy),
+                        Err(err) => return Err(DscError::Setting(format!("Invalid policy in '{}': {err}", path.display()))),
+                    }
+                }
+                merge_settings(&mut settings, value);
+            }
+        }
+        let mut settings: Self = serde_json::from_value(settings).map_err(|err| DscError::Setting(err.to_string()))?;
+        settings.policy = policy;
+        Ok(settings)
+    }
+}
+
+fn default_true() -> bool {
+    true
+}
+
+fn read_settings_file(path: &Path) -> Result<Option<Value>, DscError> {
+    let contents = match std::fs::read_to_string(path) {
+        Ok(contents) => contents,
+        Err(err) if err.kind() == std::io::ErrorKind::NotFound => return Ok(None),
+        Err(err) => return Err(DscError::Setting(format!("Failed to read '{}': {err}", path.display()))),
+    };
+    debug!("Reading settings from '{}'", path.display());
+    match serde_json::from_str::<Value>(&contents) {
+        Ok(value @ Value::Object(_)) => Ok(Some(value)),
+        Ok(_) => Err(DscError::Setting(format!("'{}' must contain a JSON object", path.display()))),
+        Err(err) => Err(DscError::Setting(format!("Invalid JSON in '{}': {err}", path.display()))),
+    }
+}
+
+// objects are merged so a file only needs the settings it changes, any other value is replaced
+fn merge_settings(settings: &mut Value, value: Value) {
+    match (settings, value) {
+        (Value::Object(settings), Value::Object(value)) => {
+            for (name, value) in value {
+                match settings.get_mut(&name) {
+                    Some(setting) => merge_settings(setting, value),
+                    None => {
+                        settings.insert(name, value);
+                    },
+                }
+            }
+        },
+        (settings, value) => *settings = value,
+    }
+}
+
+fn get_system_settings_path() -> Option<PathBuf> {
+    // $env:ProgramData+"dsc\dsc.settings.json"
+    #[cfg(target_os = "windows")]
+    let directory = std::env::var_os("ProgramData").map(|path| PathBuf::from(path).join("dsc"));
+    #[cfg(not(target_os = "windows"))]
+    let directory = Some(PathBuf::from("/etc/dsc"));
+    directory.map(|directory| directory.join(SETTINGS_FILE_NAME))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    #[test]
+    fn test_default_settings() {
+        let settings: DscSettings = serde_json::from_value(json!({})).unwrap();
+        assert_eq!(settings, DscSettings::default());
+        assert!(settings.resource_path.use_path);
+        assert!(settings.resource_path.allow_env_override);
+    }
+
+    #[test]
+    fn test_merge_settings() {
+        let mut settings = json!({
+            "resourcePath": {
+                "directories": ["/opt/dsc"],
+                "usePath": false
+            },
+            "tracing": {
+                "level": "debug"
+            }
+        });
+        merge_settings(&mut settings, json!({
+            "resourcePath": {
+                "directories": ["/home/dsc"]
+            },
+            "adapterLookupTable": "/tmp/lookup.json"
+        }));
+        let settings: DscSettings = serde_json::from_value(settings).unwrap();
+        assert_eq!(settings.resource_path.directories, vec!["/home/dsc".to_string()]);
+        assert!(!settings.resource_path.use_path);
+        assert_eq!(settings.tracing.level, Some(TraceLevel::Debug));
+        assert_eq!(settings.adapter_lookup_table, Some("/tmp/lookup.json".to_string()));
+    }
+
+    #[test]
+    fn test_later_file_overrides_setting() {
+        let directory = std::env::temp_dir().join(format!("dsc_settings_{}", std::process::id()));
+        std::fs::create_dir_all(&directory).unwrap();
+        let user_path = directory.join("user.json");
+        let system_path = directory.join("system.json");
+        std::fs::write(&user_path, r#"{"tracing": {"level": "debug", "format": "json"}}"#).unwrap();
+        std::fs::write(&system_path, r#"{"tracing": {"level": "warn"}}"#).unwrap();
+        let settings = DscSettings::load_files([user_path, system_path]).unwrap();
+        std::fs::remove_dir_all(&directory).unwrap();
+        assert_eq!(settings.tracing.level, Some(TraceLevel::Warn));
+        assert_eq!(settings.tracing.format, Some(TraceFormat::Json));
+    }
+
+    #[test]
+    fn test_unknown_setting() {
+        assert!(serde_json::from_value::<DscSettings>(json!({ "resourcePaths": {} })).is_err());
+    }
+}
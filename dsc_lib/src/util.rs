@@ -3,6 +3,7 @@
 
 use crate::dscerror::DscError;
 use serde_json::Value;
+use std::path::{Path, PathBuf};
 
 /// Return JSON string whether the input is JSON or YAML
 ///
@@ -37,3 +38,19 @@ pub fn parse_input_to_json(value: &str) -> Result<String, DscError> {
         }
     }
 }
+
+/// Get the directory for files of the current user, like the key file or the discovery index.
+#[must_use]
+#[cfg(target_os = "windows")]
+pub fn get_user_directory() -> Option<PathBuf> {
+    // $env:LocalAppData+"dsc"
+    std::env::var("LocalAppData").ok().map(|path| Path::new(&path).join("dsc"))
+}
+
+/// Get the directory for files of the current user, like the key file or the discovery index.
+#[must_use]
+#[cfg(not(target_os = "windows"))]
+pub fn get_user_directory() -> Option<PathBuf> {
+    // $env:HOME+".dsc"
+    std::env::var("HOME").ok().map(|path| Path::new(&path).join(".dsc"))
+}
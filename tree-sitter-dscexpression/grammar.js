@@ -20,15 +20,21 @@ module.exports = grammar({
     expression: $ => seq(field('function', $.function), optional(field('accessor',$.accessor))),
     stringLiteral: $ => token(prec(PREC.STRINGLITERAL, /[^\[](.|\n)*?/)),
 
-    function: $ => seq(field('name', $.functionName), '(', field('args', optional($.arguments)), ')'),
-    functionName: $ => /[a-z][a-zA-Z0-9]*/,
+    function: $ => seq(field('name', choice($.functionName, $._booleanFunctionName)), '(', field('args', optional($.arguments)), ')'),
+    // `true()` and `false()` are functions, but otherwise lexed as boolean literals
+    _booleanFunctionName: $ => alias(choice('true', 'false'), $.functionName),
+    // user functions defined in the configuration are called with their namespace, like `contoso.path()`
+    functionName: $ => /[a-z][a-zA-Z0-9]*(\.[a-z][a-zA-Z0-9]*)?/,
     arguments: $ => seq($._argument, repeat(seq(',', $._argument))),
-    _argument: $ => choice($.expression, $._quotedString, $.number, $.boolean),
+    _argument: $ => choice($.expression, $._quotedString, $.number, $.boolean, $.lambda),
+
+    // lambda('x', 'y', <expression>) is only valid as an argument to another function
+    lambda: $ => seq('lambda', '(', repeat1(seq('\'', field('parameter', alias($.string, $.lambdaParameter)), '\'', ',')), field('body', $.expression), ')'),
 
     _quotedString: $ => seq('\'', $.string, '\''),
     // ARM strings are not allowed to contain single-quote characters unless escaped
     string: $ => /([^']|''|\n)*/,
-    number: $ => /-?\d+/,
+    number: $ => /-?\d+(\.\d+)?/,
     boolean: $ => choice('true', 'false'),
 
     accessor: $ => repeat1(choice($.memberAccess, $.index)),